@@ -5,15 +5,19 @@
 //!   Orbit: Middle click
 //!   Pan: Shift + Middle click
 //!   Zoom: Mousewheel
+//!   Roll: Ctrl + Middle click
 
 use bevy::{math::DVec3, prelude::*};
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, TouchControls};
+use bevy_panorbit_camera::{
+    ActionMomentum, ButtonBinding, DragCursor, GamepadControls, KeyboardControls, Modifier,
+    Momentum, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitInputMap, SpeedRamp, TouchControls,
+};
 use std::f64::consts::TAU;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, toggle_camera_controls_system)
         .run();
@@ -62,19 +66,46 @@ fn setup(
             zoom_upper_limit: Some(5.0),
             zoom_lower_limit: 1.0,
             // Adjust sensitivity of controls
-            orbit_sensitivity: 1.5,
+            // (a drag across the whole window turns the camera one and a half times)
+            orbit_sensitivity: PanOrbitCamera::orbit_sensitivity_from_degrees_per_width(540.0),
             pan_sensitivity: 0.5,
             zoom_sensitivity: 0.5,
             // Allow the camera to go upside down
             allow_upside_down: true,
             // Change the controls (these match Blender)
-            button_orbit: MouseButton::Middle,
-            button_pan: MouseButton::Middle,
-            modifier_pan: Some(KeyCode::ShiftLeft),
+            // (each action can have multiple bindings, including modifier keys - see
+            // `PanOrbitInputMap` for how to set up your own)
+            // Ctrl + Middle Mouse rolls the camera by dragging sideways
+            input_map: PanOrbitInputMap {
+                roll: vec![ButtonBinding::new(MouseButton::Middle).with_modifier(Modifier::Control)],
+                ..PanOrbitInputMap::blender()
+            },
+            // Wrap the cursor around at the window's edges while dragging, so long orbits
+            // aren't interrupted
+            drag_cursor: DragCursor::Wrap,
             // Reverse the zoom direction
             reversed_zoom: true,
+            // Zoom towards the cursor instead of the focus point
+            zoom_to_cursor: true,
             // Use alternate touch controls
-            touch_controls: TouchControls::TwoFingerOrbit,
+            touch_controls: TouchControls::two_finger_orbit(),
+            // Enable gamepad controls
+            gamepad_controls: Some(GamepadControls::default()),
+            // Orbit with the arrow keys, speeding up from 20 to 120 degrees per second while held
+            keyboard_controls: Some(KeyboardControls {
+                orbit_speed: SpeedRamp {
+                    start: 20f64.to_radians(),
+                    max: 120f64.to_radians(),
+                    ramp_time: 1.0,
+                },
+                ..default()
+            }),
+            // Keep orbiting after releasing a drag, but stop panning straight away
+            momentum: Momentum {
+                enabled: true,
+                pan: ActionMomentum::disabled(),
+                ..default()
+            },
             ..default()
         },
     ));
@@ -82,6 +113,7 @@ fn setup(
 
 // This is how you can change config at runtime.
 // Press 'T' to toggle the camera controls.
+// Press 'H' to jump back to the home view (double-click to go back smoothly).
 fn toggle_camera_controls_system(
     key_input: Res<ButtonInput<KeyCode>>,
     mut pan_orbit_query: Query<&mut PanOrbitCamera>,
@@ -91,4 +123,9 @@ fn toggle_camera_controls_system(
             pan_orbit.enabled = !pan_orbit.enabled;
         }
     }
+    if key_input.just_pressed(KeyCode::KeyH) {
+        for mut pan_orbit in pan_orbit_query.iter_mut() {
+            pan_orbit.reset_to_home(false);
+        }
+    }
 }
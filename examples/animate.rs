@@ -7,7 +7,7 @@ use std::f64::consts::TAU;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, animate)
         .run();
@@ -0,0 +1,87 @@
+//! Demonstrates how to animate the camera to preset viewpoints over a fixed duration
+//!
+//! Controls:
+//!   1, 2, 3: Fly to a preset viewpoint
+
+use bevy::{math::DVec3, prelude::*};
+use bevy_panorbit_camera::{CameraPose, PanOrbitCamera, PanOrbitCameraPlugin};
+use std::time::Duration;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, fly_to_viewpoint)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
+        PanOrbitCamera::default(),
+    ));
+}
+
+fn fly_to_viewpoint(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    let pose = if key_input.just_pressed(KeyCode::Digit1) {
+        // Front
+        CameraPose {
+            focus: DVec3::new(0.0, 0.5, 0.0),
+            yaw: 0.0,
+            pitch: 0.2,
+            radius: 5.0,
+            ..default()
+        }
+    } else if key_input.just_pressed(KeyCode::Digit2) {
+        // Top down
+        CameraPose {
+            focus: DVec3::ZERO,
+            yaw: 0.0,
+            pitch: 89f64.to_radians(),
+            radius: 8.0,
+            ..default()
+        }
+    } else if key_input.just_pressed(KeyCode::Digit3) {
+        // Close up of the cube's corner
+        CameraPose {
+            focus: DVec3::new(0.5, 1.0, 0.5),
+            yaw: 45f64.to_radians(),
+            pitch: 30f64.to_radians(),
+            radius: 2.0,
+            ..default()
+        }
+    } else {
+        return;
+    };
+    for mut pan_orbit in pan_orbit_query.iter_mut() {
+        pan_orbit.animate_to(pose, Duration::from_secs_f32(0.8), EaseFunction::CubicInOut);
+    }
+}
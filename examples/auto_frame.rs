@@ -0,0 +1,46 @@
+//! Demonstrates automatically framing the scene once it has loaded. The scene here is much
+//! bigger than the camera's initial radius, but the camera zooms out to fit it.
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{PanOrbitAutoFrame, PanOrbitCamera, PanOrbitCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(200.0, 200.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+        Transform::from_xyz(50.0, 0.0, 0.0),
+    ));
+    // Tower
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(20.0, 80.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(50.0, 40.0, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera::default(),
+        PanOrbitAutoFrame::default(),
+    ));
+}
@@ -0,0 +1,56 @@
+//! Demonstrates a turntable style product viewer, which spins the model while the user isn't
+//! interacting with it, and drifts back onto the scene if they left it at an awkward view
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{AutoRotate, IdleDrift, PanOrbitCamera, PanOrbitCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera {
+            // Spin at about 20 degrees per second, starting two seconds after the last interaction
+            auto_rotate: Some(AutoRotate {
+                speed: 20f64.to_radians(),
+                delay: 2.0,
+            }),
+            // Recentre on the scene after five seconds without interaction
+            idle_drift: Some(IdleDrift {
+                delay: 5.0,
+                ..default()
+            }),
+            ..default()
+        },
+    ));
+}
@@ -0,0 +1,101 @@
+//! Demonstrates box zoom, where you drag a rectangle and the camera zooms to fit it
+//!
+//! Controls:
+//!   Orbit: Left click
+//!   Pan: Right click
+//!   Zoom: Mousewheel
+//!   Box zoom: Ctrl + Left click drag
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{
+    ButtonBinding, Modifier, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitInputMap,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, draw_box)
+        .run();
+}
+
+#[derive(Component)]
+struct ZoomBox;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cubes
+    let cube = meshes.add(Cuboid::new(0.5, 0.5, 0.5));
+    let cube_material = materials.add(Color::srgb(0.8, 0.7, 0.6));
+    for x in -2..=2 {
+        for z in -2..=2 {
+            commands.spawn((
+                Mesh3d(cube.clone()),
+                MeshMaterial3d(cube_material.clone()),
+                Transform::from_xyz(x as f32, 0.25, z as f32),
+            ));
+        }
+    }
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_translation(Vec3::new(0.0, 4.0, 6.0)),
+        PanOrbitCamera {
+            input_map: PanOrbitInputMap {
+                box_zoom: vec![
+                    ButtonBinding::new(MouseButton::Left).with_modifier(Modifier::Control)
+                ],
+                ..default()
+            },
+            ..default()
+        },
+    ));
+    // The rectangle being drawn
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BorderColor(Color::WHITE),
+        Visibility::Hidden,
+        ZoomBox,
+    ));
+}
+
+fn draw_box(
+    pan_orbit_query: Query<&PanOrbitCamera>,
+    mut box_query: Query<(&mut Node, &mut Visibility), With<ZoomBox>>,
+) {
+    let (Ok(pan_orbit), Ok((mut node, mut visibility))) =
+        (pan_orbit_query.get_single(), box_query.get_single_mut())
+    else {
+        return;
+    };
+    match pan_orbit.box_zoom_rect {
+        Some(rect) => {
+            node.left = Val::Px(rect.min.x);
+            node.top = Val::Px(rect.min.y);
+            node.width = Val::Px(rect.width());
+            node.height = Val::Px(rect.height());
+            *visibility = Visibility::Visible;
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
@@ -0,0 +1,84 @@
+//! Demonstrates reacting to camera events, e.g. to refresh something expensive only once the
+//! camera has stopped moving
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{
+    CameraIdle, OrbitEnded, OrbitStarted, PanEnded, PanOrbitCamera, PanOrbitCameraPlugin,
+    ZoomChanged,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, log_events)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera::default(),
+    ));
+}
+
+fn log_events(
+    mut orbit_started: EventReader<OrbitStarted>,
+    mut orbit_ended: EventReader<OrbitEnded>,
+    mut pan_ended: EventReader<PanEnded>,
+    mut zoom_changed: EventReader<ZoomChanged>,
+    mut camera_idle: EventReader<CameraIdle>,
+) {
+    for event in orbit_started.read() {
+        info!(
+            "Orbit started at yaw {:.2}, pitch {:.2}",
+            event.yaw, event.pitch
+        );
+    }
+    for event in orbit_ended.read() {
+        info!(
+            "Orbit ended, yaw {:.2} -> {:.2}, pitch {:.2} -> {:.2}",
+            event.old_yaw, event.new_yaw, event.old_pitch, event.new_pitch
+        );
+    }
+    for event in pan_ended.read() {
+        info!(
+            "Pan ended, focus {} -> {}",
+            event.old_focus, event.new_focus
+        );
+    }
+    for event in zoom_changed.read() {
+        info!(
+            "Zoom changed, radius {:.2} -> {:.2}",
+            event.old_radius, event.new_radius
+        );
+    }
+    for _ in camera_idle.read() {
+        info!("Camera idle");
+    }
+}
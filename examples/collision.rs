@@ -0,0 +1,63 @@
+//! Demonstrates how to stop the camera from passing through meshes. Orbit around the cube and the
+//! camera will move in front of the walls rather than clipping through them.
+//! Requires the `bevy_picking` feature.
+
+use bevy::{math::DVec3, prelude::*};
+use bevy_panorbit_camera::{
+    IgnoreCameraCollision, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitCollision,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(10.0, 10.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube. The focus is inside it, so it must be ignored or the camera would always collide
+    // with it.
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+        IgnoreCameraCollision,
+    ));
+    // Walls
+    let wall = meshes.add(Cuboid::new(4.0, 2.0, 0.2));
+    let wall_material = materials.add(Color::srgb(0.6, 0.6, 0.7));
+    for (x, z, angle) in [(0.0, -2.5, 0.0), (2.5, 0.0, 90f32.to_radians())] {
+        commands.spawn((
+            Mesh3d(wall.clone()),
+            MeshMaterial3d(wall_material.clone()),
+            Transform::from_xyz(x, 1.0, z).with_rotation(Quat::from_rotation_y(angle)),
+        ));
+    }
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera {
+            focus: DVec3::new(0.0, 0.5, 0.0),
+            ..default()
+        },
+        PanOrbitCollision::default(),
+    ));
+}
@@ -0,0 +1,50 @@
+//! Demonstrates panning and zooming at a speed that matches the depth of whatever is under the
+//! cursor. Zoom in close to one of the small cubes and pan: the camera moves slowly, so the cube
+//! stays under the cursor. Pan over the distant ground and it moves quickly.
+//! Requires the `bevy_picking` feature.
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitCursorDepth};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // A large ground, so there's geometry at very different depths
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(200.0, 200.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // A grid of small cubes
+    let mesh = meshes.add(Cuboid::new(0.2, 0.2, 0.2));
+    let material = materials.add(Color::srgb(0.8, 0.7, 0.6));
+    for x in -5..=5 {
+        for z in -5..=5 {
+            commands.spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(x as f32 * 2.0, 0.1, z as f32 * 2.0),
+            ));
+        }
+    }
+    // Light
+    commands.spawn((
+        DirectionalLight::default(),
+        Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 10.0, 20.0),
+        PanOrbitCamera::default(),
+        PanOrbitCursorDepth,
+    ));
+}
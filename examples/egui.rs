@@ -1,15 +1,18 @@
 //! Demonstrates the bevy_egui feature which allows bevy_panorbit_camera to ignore input events in
-//! egui windows
+//! egui windows, except for a transparent overlay that the camera can be controlled through. Also
+//! shows a toolbar of view commands.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPlugin};
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{
+    view_menu_ui, ApplyViewCommand, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitEguiBlocking,
+};
 
 fn main() {
     let mut app = App::new();
     app.add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, ui_example_system);
 
@@ -44,10 +47,24 @@ fn setup(
     commands.spawn((
         Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
         PanOrbitCamera::default(),
+        PanOrbitEguiBlocking::with_filter(egui_blocks_camera),
     ));
 }
 
-fn ui_example_system(mut contexts: EguiContexts) {
+fn ui_example_system(
+    mut contexts: EguiContexts,
+    cameras: Query<(Entity, &PanOrbitCamera, &Projection)>,
+    mut view_commands: EventWriter<ApplyViewCommand>,
+) {
+    if let Ok((camera, pan_orbit, projection)) = cameras.get_single() {
+        egui::TopBottomPanel::top("views").show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                if let Some(command) = view_menu_ui(ui, &pan_orbit.view_menu(projection)) {
+                    view_commands.send(ApplyViewCommand { camera, command });
+                }
+            });
+        });
+    }
     egui::SidePanel::left("left_panel")
         .resizable(true)
         .show(contexts.ctx_mut(), |ui| {
@@ -63,4 +80,18 @@ fn ui_example_system(mut contexts: EguiContexts) {
         .show(contexts.ctx_mut(), |ui| {
             ui.label("Hello world");
         });
+    egui::Window::new("Overlay")
+        .frame(egui::Frame::NONE)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.label("The camera can be controlled through this text");
+        });
+}
+
+/// Block the camera while egui is using the input, except over the overlay
+fn egui_blocks_camera(ctx: &egui::Context) -> bool {
+    let over_overlay = ctx
+        .pointer_latest_pos()
+        .and_then(|pos| ctx.layer_id_at(pos))
+        .is_some_and(|layer| layer.id == egui::Id::new("Overlay"));
+    !over_overlay && (ctx.wants_pointer_input() || ctx.wants_keyboard_input())
 }
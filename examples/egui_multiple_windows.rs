@@ -12,7 +12,7 @@ fn main() {
     let mut app = App::new();
     app.add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, ui_example_system);
 
@@ -0,0 +1,56 @@
+//! Demonstrates switching between orbiting and first person fly controls
+//!
+//! Controls:
+//!   Toggle fly mode: F
+//!   Orbit / look around: Left Mouse
+//!   Pan: Right Mouse
+//!   Move (fly mode only): WASD, E/Q for up/down
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cubes
+    for x in -2..=2 {
+        for z in -2..=2 {
+            commands.spawn((
+                Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+                MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+                Transform::from_xyz(x as f32 * 3.0, 0.5, z as f32 * 3.0),
+            ));
+        }
+    }
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 10.0),
+        PanOrbitCamera {
+            key_toggle_fly: Some(KeyCode::KeyF),
+            ..default()
+        },
+    ));
+}
@@ -1,15 +1,15 @@
 //! Demonstrates how to have the camera follow a target object
 
 use bevy::prelude::*;
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitFollow};
 use std::f32::consts::TAU;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
-        .add_systems(Update, (animate_cube, cam_follow).chain())
+        .add_systems(Update, animate_cube)
         .run();
 }
 
@@ -27,13 +27,14 @@ fn setup(
         MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
     ));
     // Cube
-    commands
+    let cube = commands
         .spawn((
             Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
             MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
             Transform::from_xyz(0.0, 0.5, 0.0),
         ))
-        .insert(Cube);
+        .insert(Cube)
+        .id();
     // Light
     commands.spawn((
         PointLight {
@@ -45,16 +46,15 @@ fn setup(
     // Camera
     commands.spawn((
         Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
-        PanOrbitCamera {
-            // Panning the camera changes the focus, and so you most likely want to disable
-            // panning when setting the focus manually
-            pan_sensitivity: 0.0,
-            // If you want to fully control the camera's focus, set smoothness to 0 so it
-            // immediately snaps to that location. If you want the 'follow' to be smoothed,
-            // leave this at default or set it to something between 0 and 1.
-            pan_smoothness: 0.0,
-            ..default()
-        },
+        PanOrbitCamera::default(),
+        // Keep the camera's focus on the cube. You can still orbit and zoom, and panning will
+        // offset the focus from the cube.
+        // Smoothness makes the camera lag behind the cube a little. Leave it out for a rigid
+        // follow.
+        // Look ahead keeps the focus a little in front of the cube, in the direction it's moving.
+        PanOrbitFollow::new(cube)
+            .with_smoothness(0.3)
+            .with_look_ahead(0.5),
     ));
 }
 
@@ -72,16 +72,3 @@ fn animate_cube(
         cube_tfm.translation = pos;
     }
 }
-
-/// Set the camera's focus to the cube's position
-fn cam_follow(mut pan_orbit_q: Query<&mut PanOrbitCamera>, cube_q: Query<&Transform, With<Cube>>) {
-    if let Ok(mut pan_orbit) = pan_orbit_q.get_single_mut() {
-        if let Ok(cube_tfm) = cube_q.get_single() {
-            pan_orbit.target_focus = cube_tfm.translation.as_dvec3();
-            // Whenever changing properties manually like this, it's necessary to force
-            // PanOrbitCamera to update this frame (by default it only updates when there are
-            // input events).
-            pan_orbit.force_update = true;
-        }
-    }
-}
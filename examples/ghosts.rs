@@ -0,0 +1,102 @@
+//! Demonstrates drawing other users' cameras in a multi-user session, with simulated users
+//! standing in for cameras received over the network.
+//! Requires the `bevy_gizmos` feature.
+//!
+//! Controls:
+//!   1, 2: Jump to another user's view (hold Shift to cut instead)
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy_panorbit_camera::{CameraPose, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitGhost};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_remote_users, jump_to_ghost))
+        .run();
+}
+
+/// Stands in for another user's camera, which would normally be received over the network
+#[derive(Component)]
+struct RemoteUser {
+    key: KeyCode,
+    speed: f64,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_translation(Vec3::new(0.0, 6.0, 12.0)),
+        PanOrbitCamera::default(),
+    ));
+    // Other users
+    commands.spawn((
+        PanOrbitGhost::default().with_color(Color::srgb(1.0, 0.3, 0.3)),
+        RemoteUser {
+            key: KeyCode::Digit1,
+            speed: 0.3,
+        },
+    ));
+    commands.spawn((
+        PanOrbitGhost::default().with_color(Color::srgb(0.3, 0.6, 1.0)),
+        RemoteUser {
+            key: KeyCode::Digit2,
+            speed: -0.2,
+        },
+    ));
+}
+
+/// Update the ghosts as if new poses had arrived from the other users
+fn move_remote_users(mut ghosts: Query<(&mut PanOrbitGhost, &RemoteUser)>, time: Res<Time>) {
+    for (mut ghost, user) in ghosts.iter_mut() {
+        let t = time.elapsed_secs_f64() * user.speed;
+        ghost.pose = CameraPose {
+            focus: DVec3::new(0.0, 0.5, 0.0),
+            yaw: t,
+            pitch: 0.4 + (t * 2.0).sin() * 0.2,
+            radius: 4.0,
+            ..default()
+        };
+    }
+}
+
+fn jump_to_ghost(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+    ghosts: Query<(&PanOrbitGhost, &RemoteUser)>,
+) {
+    let animated = !key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    for (ghost, user) in ghosts.iter() {
+        if !key_input.just_pressed(user.key) {
+            continue;
+        }
+        for mut pan_orbit in pan_orbit_query.iter_mut() {
+            ghost.jump_to(&mut pan_orbit, animated);
+        }
+    }
+}
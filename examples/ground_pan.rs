@@ -0,0 +1,52 @@
+//! Demonstrates a map style camera, where panning drags the ground around under the cursor, and
+//! moving the cursor to the edge of the window scrolls the map
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{EdgePan, PanMode, PanOrbitCamera, PanOrbitCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(50.0, 50.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 5.0, 8.0),
+        PanOrbitCamera {
+            // Pan along the ground instead of parallel to the screen
+            pan_mode: PanMode::ground(0.0),
+            // Scroll across the ground when the cursor is near the edge of the window
+            edge_pan: Some(EdgePan::default()),
+            // Keep the camera above the ground
+            pitch_lower_limit: Some(0.2),
+            ..default()
+        },
+    ));
+}
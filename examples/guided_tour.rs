@@ -0,0 +1,112 @@
+//! Demonstrates guided mode, where a scripted tour plays in a letterboxed view and the user can
+//! only look around a little until it's over
+//!
+//! Controls:
+//!   T: Start the tour
+//!   Escape: Leave the tour early
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{
+    GuidedMode, NavigationScript, NavigationStep, PanOrbitCamera, PanOrbitCameraPlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        // End the tour before starting it, so the script has been added by the time it's checked
+        .add_systems(Update, (end_tour, start_tour).chain())
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // help
+    commands.spawn(Text::new("Press T to start the tour".to_string()));
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
+        PanOrbitCamera::default(),
+    ));
+}
+
+fn start_tour(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut camera_query: Query<(Entity, &mut PanOrbitCamera)>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+    let Ok((entity, mut pan_orbit)) = camera_query.get_single_mut() else {
+        return;
+    };
+    pan_orbit.enter_guided_mode(GuidedMode {
+        letterbox: Some(2.39),
+        ..default()
+    });
+    // In the waits, the user can look around a little
+    commands.entity(entity).insert(NavigationScript::new(vec![
+        NavigationStep::OrbitBy {
+            yaw: 1.5,
+            pitch: 0.3,
+            duration: 3.0,
+        },
+        NavigationStep::Wait { duration: 2.0 },
+        NavigationStep::ZoomTo {
+            radius: 2.5,
+            duration: 1.5,
+        },
+        NavigationStep::Wait { duration: 2.0 },
+        NavigationStep::OrbitBy {
+            yaw: -1.5,
+            pitch: -0.3,
+            duration: 3.0,
+        },
+    ]));
+}
+
+/// Leave guided mode once the tour has finished, or when Escape is pressed
+fn end_tour(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut camera_query: Query<(Entity, &mut PanOrbitCamera, Has<NavigationScript>)>,
+) {
+    let Ok((entity, mut pan_orbit, touring)) = camera_query.get_single_mut() else {
+        return;
+    };
+    if !pan_orbit.is_guided() {
+        return;
+    }
+    if key_input.just_pressed(KeyCode::Escape) {
+        pan_orbit.stop_motion();
+        pan_orbit.animation = None;
+        commands.entity(entity).remove::<NavigationScript>();
+        pan_orbit.exit_guided_mode();
+    } else if !touring && pan_orbit.animation.is_none() {
+        pan_orbit.exit_guided_mode();
+    }
+}
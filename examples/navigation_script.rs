@@ -0,0 +1,81 @@
+//! Demonstrates scripting a camera tour as a list of navigation steps, which loops forever
+
+use std::f64::consts::PI;
+
+use bevy::{math::DVec3, prelude::*};
+use bevy_panorbit_camera::{
+    NavigationScript, NavigationStep, PanOrbitCamera, PanOrbitCameraPlugin, ScriptProjection,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera::default(),
+        NavigationScript::new(vec![
+            NavigationStep::OrbitBy {
+                yaw: PI / 2.0,
+                pitch: 0.3,
+                duration: 2.0,
+            },
+            NavigationStep::ZoomTo {
+                radius: 3.0,
+                duration: 1.0,
+            },
+            NavigationStep::Wait { duration: 1.0 },
+            NavigationStep::PanTo {
+                focus: DVec3::new(0.0, 0.5, 0.0),
+                duration: 1.0,
+            },
+            NavigationStep::SetProjection(ScriptProjection::Orthographic),
+            NavigationStep::OrbitBy {
+                yaw: PI / 2.0,
+                pitch: -0.3,
+                duration: 2.0,
+            },
+            NavigationStep::SetProjection(ScriptProjection::Perspective {
+                fov: std::f32::consts::FRAC_PI_4,
+            }),
+            NavigationStep::PanTo {
+                focus: DVec3::ZERO,
+                duration: 1.0,
+            },
+            NavigationStep::ZoomTo {
+                radius: 5.0,
+                duration: 1.0,
+            },
+        ])
+        .with_looping(true),
+    ));
+}
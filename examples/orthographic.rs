@@ -2,12 +2,14 @@
 
 use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitCameraSystemSet};
+use bevy_panorbit_camera::{
+    PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitCameraSystemSet, ProjectionMode,
+};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, switch_projection.before(PanOrbitCameraSystemSet))
         .run();
@@ -53,15 +55,24 @@ fn setup(
 }
 
 fn switch_projection(
-    mut next_projection: Local<Projection>,
     key_input: Res<ButtonInput<KeyCode>>,
-    mut camera_query: Query<(&mut PanOrbitCamera, &mut Projection)>,
+    mut camera_query: Query<(&mut PanOrbitCamera, &Projection)>,
 ) {
     if key_input.just_pressed(KeyCode::KeyR) {
-        let Ok((mut camera, mut projection)) = camera_query.get_single_mut() else {
+        let Ok((mut camera, projection)) = camera_query.get_single_mut() else {
             return;
         };
-        std::mem::swap(&mut *next_projection, &mut *projection);
-        camera.force_update = true;
+        // Switch to whichever projection the camera isn't heading towards
+        let heading_to = match camera.projection_switch {
+            Some(switch) => switch.to,
+            None => match projection {
+                Projection::Perspective(_) => ProjectionMode::Perspective,
+                Projection::Orthographic(_) => ProjectionMode::Orthographic,
+            },
+        };
+        camera.set_projection_mode(match heading_to {
+            ProjectionMode::Perspective => ProjectionMode::Orthographic,
+            ProjectionMode::Orthographic => ProjectionMode::Perspective,
+        });
     }
 }
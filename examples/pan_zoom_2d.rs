@@ -0,0 +1,45 @@
+//! Demonstrates `PanZoomCamera2d`, which pans and zooms a 2D camera, e.g. for a schematic view
+//!
+//! Controls:
+//!   Left or Right Mouse: Pan
+//!   Scroll or pinch: Zoom towards the cursor
+//!   Double-click Left Mouse: Reset
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{
+    FocusBoundsShape, PanOrbitCamera, PanOrbitCameraPlugin, PanZoomCamera2d,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    // A grid of squares to move around
+    let square = meshes.add(Rectangle::new(40.0, 40.0));
+    for x in -5..=5 {
+        for y in -5..=5 {
+            let color = Color::hsl((x + y + 10) as f32 * 18.0, 0.6, 0.5);
+            commands.spawn((
+                Mesh2d(square.clone()),
+                MeshMaterial2d(materials.add(color)),
+                Transform::from_xyz(x as f32 * 60.0, y as f32 * 60.0, 0.0),
+            ));
+        }
+    }
+    // Camera
+    commands.spawn(PanZoomCamera2d(PanOrbitCamera {
+        zoom_upper_limit: Some(5.0),
+        zoom_lower_limit: 0.1,
+        focus_bounds_shape: Some(FocusBoundsShape::Cuboid(Cuboid::new(600.0, 600.0, 0.0))),
+        ..PanZoomCamera2d::default_settings()
+    }));
+}
@@ -0,0 +1,54 @@
+//! Demonstrates orbiting around a point picked with the mouse. Double-click any of the shapes to
+//! orbit around the point you clicked, and double-click the background to reset the camera.
+//! Requires the `bevy_picking` feature.
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitPickPivot};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(10.0, 10.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // A few shapes spread out, so it's clear which one the camera orbits around
+    let material = materials.add(Color::srgb(0.8, 0.7, 0.6));
+    for (i, x) in [-3.0, 0.0, 3.0].into_iter().enumerate() {
+        let mesh = match i {
+            0 => meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
+            1 => meshes.add(Sphere::new(0.6)),
+            _ => meshes.add(Cylinder::new(0.5, 1.5)),
+        };
+        commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(x, 0.75, 0.0),
+        ));
+    }
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 3.0, 8.0),
+        PanOrbitCamera::default(),
+        PanOrbitPickPivot::default(),
+    ));
+}
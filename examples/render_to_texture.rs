@@ -1,5 +1,5 @@
-//! Demonstrates the ability to manually override which instance of PanOrbitCamera receives input
-//! events, which is necessary when rendering to a texture/image instead of a window/viewport.
+//! Demonstrates the ability to override which instance of PanOrbitCamera receives input events,
+//! which is necessary when rendering to a texture/image instead of a window/viewport.
 //!
 //! In this example, input controls the camera that is rendering the texture applied to the cube,
 //! rather than the main window camera.
@@ -8,7 +8,6 @@
 
 use std::f32::consts::PI;
 
-use bevy::window::PrimaryWindow;
 use bevy::{
     prelude::*,
     render::{
@@ -19,12 +18,12 @@ use bevy::{
         view::RenderLayers,
     },
 };
-use bevy_panorbit_camera::{ActiveCameraData, PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{ActivePanOrbitCamera, PanOrbitCamera, PanOrbitCameraPlugin};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, cube_rotator_system)
         .run();
@@ -43,8 +42,7 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut images: ResMut<Assets<Image>>,
-    mut active_cam: ResMut<ActiveCameraData>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    mut active_cam: ResMut<ActivePanOrbitCamera>,
 ) {
     let size = Extent3d {
         width: 512,
@@ -143,25 +141,9 @@ fn setup(
         Transform::from_xyz(0.0, 0.0, 15.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
 
-    // Set up manual override of PanOrbitCamera. Note that this must run after PanOrbitCameraPlugin
-    // is added, otherwise ActiveCameraData will be overwritten.
-    // Note: you probably want to update the `viewport_size` and `window_size` whenever they change,
-    // I haven't done this here for simplicity.
-    let primary_window = windows
-        .get_single()
-        .expect("There is only ever one primary window");
-    active_cam.set_if_neq(ActiveCameraData {
-        // Set the entity to the entity ID of the camera you want to control. In this case, it's
-        // the inner (first pass) cube that is rendered to the texture/image.
-        entity: Some(pan_orbit_id),
-        // What you set these values to will depend on your use case, but generally you want the
-        // viewport size to match the size of the render target (image, viewport), and the window
-        // size to match the size of the window that you are interacting with.
-        viewport_size: Some(Vec2::new(size.width as f32, size.height as f32)),
-        window_size: Some(Vec2::new(primary_window.width(), primary_window.height())),
-        // Setting manual to true ensures PanOrbitCameraPlugin will not overwrite this resource
-        manual: true,
-    });
+    // Send all input to the camera that renders the inner (first pass) cube to the texture/image.
+    // The viewport and window sizes used to scale input are filled in automatically.
+    active_cam.entity = Some(pan_orbit_id);
 }
 
 /// Rotates the outer cube (main pass)
@@ -0,0 +1,95 @@
+//! Demonstrates saving camera views and animating back to them
+//!
+//! Controls:
+//!   Ctrl + 1, 2, 3: Save the current view
+//!   1, 2, 3: Recall a saved view
+//!   Ctrl + A, B: Store a before/after comparison snapshot
+//!   Space: Blend between the snapshots (hold Shift to cut instead)
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, SnapshotSlot};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (save_and_recall_views, compare_snapshots))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
+        PanOrbitCamera::default(),
+    ));
+}
+
+fn save_and_recall_views(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    let saving = key_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    for (key, name) in [
+        (KeyCode::Digit1, "1"),
+        (KeyCode::Digit2, "2"),
+        (KeyCode::Digit3, "3"),
+    ] {
+        if !key_input.just_pressed(key) {
+            continue;
+        }
+        for mut pan_orbit in pan_orbit_query.iter_mut() {
+            if saving {
+                pan_orbit.save_view(name);
+                info!("Saved view {name}");
+            } else if !pan_orbit.recall_view(name) {
+                info!("No view saved as {name}, press Ctrl + {name} to save one");
+            }
+        }
+    }
+}
+
+fn compare_snapshots(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    let ctrl = key_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    for mut pan_orbit in pan_orbit_query.iter_mut() {
+        if ctrl && key_input.just_pressed(KeyCode::KeyA) {
+            pan_orbit.store_snapshot(SnapshotSlot::A);
+            info!("Stored snapshot A");
+        }
+        if ctrl && key_input.just_pressed(KeyCode::KeyB) {
+            pan_orbit.store_snapshot(SnapshotSlot::B);
+            info!("Stored snapshot B");
+        }
+        if key_input.just_pressed(KeyCode::Space) && !pan_orbit.flip_snapshot(!shift) {
+            info!("Store both snapshots with Ctrl + A and Ctrl + B first");
+        }
+    }
+}
@@ -1,13 +1,20 @@
-//! Demonstrates the simplest usage
+//! Demonstrates using Z as up instead of Y
+//!
+//! Controls:
+//!   T: Top view
+//!   F: Front view
+//!   R: Right view
+//!   I: Isometric view
 
 use bevy::{math::DVec3, prelude::*};
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, StandardView};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
+        .add_systems(Update, standard_views)
         .run();
 }
 
@@ -43,8 +50,29 @@ fn setup(
     let swapped_axis = [DVec3::X, DVec3::Z, DVec3::Y];
     let camera = PanOrbitCamera {
         axis: swapped_axis,
-        pitch: Some(-45f64.to_radians()),
+        pitch: Some(45f64.to_radians()),
         ..default()
     };
     commands.spawn((Transform::from_xyz(0.0, 1.5, 5.0), camera));
 }
+
+// Standard views are relative to the camera's axes, so Top looks down the Z axis here
+fn standard_views(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    let view = if key_input.just_pressed(KeyCode::KeyT) {
+        StandardView::Top
+    } else if key_input.just_pressed(KeyCode::KeyF) {
+        StandardView::Front
+    } else if key_input.just_pressed(KeyCode::KeyR) {
+        StandardView::Right
+    } else if key_input.just_pressed(KeyCode::KeyI) {
+        StandardView::Isometric
+    } else {
+        return;
+    };
+    for mut pan_orbit in pan_orbit_query.iter_mut() {
+        pan_orbit.set_view(view, true);
+    }
+}
@@ -0,0 +1,114 @@
+//! Demonstrates linking cameras with `SyncGroup`, in a CAD style layout with top, front, and side
+//! views that share their focus and zoom, and a perspective view that shares only the focus
+
+use std::f64::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::window::WindowResized;
+use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, SyncChannels, SyncGroup};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, set_camera_viewports)
+        .run();
+}
+
+/// Which quarter of the window a camera renders to
+#[derive(Component)]
+struct Pane(UVec2);
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    // Cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+    // Orthographic top, front, and side views, as yaw and pitch
+    let panes = [
+        (0.0, FRAC_PI_2, UVec2::new(0, 0)),
+        (0.0, 0.0, UVec2::new(0, 1)),
+        (FRAC_PI_2, 0.0, UVec2::new(1, 1)),
+    ];
+    for (order, (yaw, pitch, pane)) in panes.into_iter().enumerate() {
+        commands.spawn((
+            Camera {
+                // Each camera needs its own order, even though their viewports don't overlap
+                order: order as isize + 1,
+                ..default()
+            },
+            Projection::from(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: 1.0,
+                },
+                ..OrthographicProjection::default_3d()
+            }),
+            PanOrbitCamera {
+                yaw: Some(yaw),
+                pitch: Some(pitch),
+                radius: Some(5.0),
+                // Keep looking along the axis
+                yaw_upper_limit: Some(yaw),
+                yaw_lower_limit: Some(yaw),
+                pitch_upper_limit: Some(pitch),
+                pitch_lower_limit: Some(pitch),
+                ..default()
+            },
+            SyncGroup::new(0).with_channels(SyncChannels {
+                rotation: false,
+                ..default()
+            }),
+            Pane(pane),
+        ));
+    }
+    // Perspective view, which orbits freely around the shared focus
+    commands.spawn((
+        Transform::from_translation(Vec3::new(4.0, 3.0, 4.0)),
+        PanOrbitCamera::default(),
+        SyncGroup::new(0).with_channels(SyncChannels {
+            rotation: false,
+            zoom: false,
+            ..default()
+        }),
+        Pane(UVec2::new(1, 0)),
+    ));
+}
+
+fn set_camera_viewports(
+    windows: Query<&Window>,
+    mut resize_events: EventReader<WindowResized>,
+    mut cameras: Query<(&mut Camera, &Pane)>,
+) {
+    for resize_event in resize_events.read() {
+        let window = windows.get(resize_event.window).unwrap();
+        let size = window.physical_size() / 2;
+        for (mut camera, pane) in cameras.iter_mut() {
+            camera.viewport = Some(Viewport {
+                physical_position: pane.0 * size,
+                physical_size: size,
+                ..default()
+            });
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Demonstrates trackball orbiting, where the camera tumbles freely around the model instead of
+//! staying upright. Press T to switch between trackball and turntable orbiting, and R to reset
+//! the view (which also removes any roll).
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{OrbitMode, PanOrbitCamera, PanOrbitCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_orbit_mode)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Torus, with a cube on top so it's clear which way is up
+    commands.spawn((
+        Mesh3d(meshes.add(Torus::new(0.6, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+    ));
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(0.4, 0.4, 0.4))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+        Transform::from_xyz(0.0, 0.8, 0.0),
+    ));
+    // Light
+    commands.spawn((
+        DirectionalLight::default(),
+        Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+    // Camera
+    commands.spawn((
+        Transform::from_xyz(0.0, 1.5, 5.0),
+        PanOrbitCamera {
+            orbit_mode: OrbitMode::Trackball,
+            ..default()
+        },
+    ));
+}
+
+fn toggle_orbit_mode(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut pan_orbit_query: Query<&mut PanOrbitCamera>,
+) {
+    for mut pan_orbit in pan_orbit_query.iter_mut() {
+        if key_input.just_pressed(KeyCode::KeyT) {
+            pan_orbit.orbit_mode = match pan_orbit.orbit_mode {
+                OrbitMode::Turntable => OrbitMode::Trackball,
+                OrbitMode::Trackball => OrbitMode::Turntable,
+            };
+            info!("Orbit mode: {:?}", pan_orbit.orbit_mode);
+        }
+        if key_input.just_pressed(KeyCode::KeyR) {
+            pan_orbit.reset_to_home(true);
+        }
+    }
+}
@@ -6,13 +6,15 @@
 //!   Zoom: Mousewheel, ControlLeft + trackpad scroll
 
 use bevy::{math::DVec3, prelude::*};
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, TrackpadBehavior};
+use bevy_panorbit_camera::{
+    PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitInputMap, TrackpadBehavior,
+};
 use std::f64::consts::TAU;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PanOrbitCameraPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -55,13 +57,15 @@ fn setup(
             // Allow the camera to go upside down
             allow_upside_down: true,
             // Change the controls (these match Blender)
-            button_orbit: MouseButton::Middle,
-            button_pan: MouseButton::Middle,
-            modifier_pan: Some(KeyCode::ShiftLeft),
+            // (each action can have multiple bindings, including modifier keys - see
+            // `PanOrbitInputMap` for how to set up your own)
+            input_map: PanOrbitInputMap::blender(),
             // camera responds to trackpad as in Blender
             // if you don't want to write out both modifiers,
             // you can use this helper method:
             // `trackpad_behavior: TrackpadBehavior::blender_default(),`
+            // Alternatively, use `TrackpadBehavior::scroll_pans_default()` to make scrolling pan
+            // and Alt + scrolling orbit, like most map viewers.
             trackpad_behavior: TrackpadBehavior::BlenderLike {
                 modifier_pan: Some(KeyCode::ShiftLeft),
                 modifier_zoom: Some(KeyCode::ControlLeft),
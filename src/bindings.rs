@@ -0,0 +1,318 @@
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+
+/// A key that must be held for a binding to be active.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Modifier {
+    /// Either shift key.
+    Shift,
+    /// Either control key.
+    Control,
+    /// Either alt key.
+    Alt,
+    /// Either super key (Windows/Command).
+    Super,
+    /// A specific key.
+    Key(KeyCode),
+}
+
+impl Modifier {
+    /// Whether this modifier is currently held.
+    pub fn pressed(&self, key_input: &ButtonInput<KeyCode>) -> bool {
+        match *self {
+            Modifier::Shift => key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            Modifier::Control => {
+                key_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+            }
+            Modifier::Alt => key_input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            Modifier::Super => key_input.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+            Modifier::Key(key) => key_input.pressed(key),
+        }
+    }
+}
+
+impl From<KeyCode> for Modifier {
+    fn from(value: KeyCode) -> Self {
+        Self::Key(value)
+    }
+}
+
+/// A mouse button, optionally combined with modifier keys, e.g. Alt + Left Mouse.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ButtonBinding {
+    /// The mouse button to drag with.
+    pub button: MouseButton,
+    /// Keys that must all be held for this binding to be active.
+    pub modifiers: Vec<Modifier>,
+}
+
+impl ButtonBinding {
+    /// A binding with no modifiers.
+    pub fn new(button: MouseButton) -> Self {
+        Self {
+            button,
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Add a modifier that must be held for this binding to be active.
+    pub fn with_modifier(mut self, modifier: impl Into<Modifier>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+
+    /// Whether all of this binding's modifiers are held.
+    pub fn modifiers_pressed(&self, key_input: &ButtonInput<KeyCode>) -> bool {
+        self.modifiers
+            .iter()
+            .all(|modifier| modifier.pressed(key_input))
+    }
+}
+
+impl From<MouseButton> for ButtonBinding {
+    fn from(value: MouseButton) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A direction of the scroll wheel (or trackpad scroll).
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollAxis {
+    /// Regular scrolling, i.e. up/down.
+    Vertical,
+    /// Sideways scrolling, e.g. tilting the wheel or swiping sideways on a trackpad.
+    Horizontal,
+}
+
+impl ScrollAxis {
+    /// The amount this axis scrolled in `event`.
+    pub fn value(&self, event: &MouseWheel) -> f32 {
+        match self {
+            ScrollAxis::Vertical => event.y,
+            ScrollAxis::Horizontal => event.x,
+        }
+    }
+}
+
+/// A scroll axis, optionally combined with modifier keys, e.g. Shift + Vertical scroll.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollBinding {
+    /// The scroll axis.
+    pub axis: ScrollAxis,
+    /// Keys that must all be held for this binding to be active.
+    pub modifiers: Vec<Modifier>,
+}
+
+impl ScrollBinding {
+    /// A binding with no modifiers.
+    pub fn new(axis: ScrollAxis) -> Self {
+        Self {
+            axis,
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Add a modifier that must be held for this binding to be active.
+    pub fn with_modifier(mut self, modifier: impl Into<Modifier>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+
+    /// Whether all of this binding's modifiers are held.
+    pub fn modifiers_pressed(&self, key_input: &ButtonInput<KeyCode>) -> bool {
+        self.modifiers
+            .iter()
+            .all(|modifier| modifier.pressed(key_input))
+    }
+}
+
+/// What scrolling along a `ScrollAxis` does. See `PanOrbitInputMap` for the bindings of each
+/// action.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollAction {
+    /// Zoom the camera.
+    Zoom,
+    /// Orbit the camera left and right.
+    Yaw,
+    /// Orbit the camera up and down.
+    Pitch,
+    /// Pan the camera, sideways for horizontal scrolling and up and down for vertical scrolling.
+    Pan,
+    /// Change the field of view of a perspective camera.
+    Fov,
+}
+
+impl ScrollAction {
+    const ALL: [Self; 5] = [Self::Zoom, Self::Yaw, Self::Pitch, Self::Pan, Self::Fov];
+}
+
+/// The mouse controls for a `PanOrbitCamera`. Each action can have any number of bindings, and
+/// the action is triggered if any of them are active.
+///
+/// When bindings for different actions are active at the same time, the one with the most
+/// modifiers wins. For example, if orbit is bound to Middle Mouse and pan to Shift + Middle Mouse,
+/// holding Shift while dragging with the middle mouse button will pan. If both have the same
+/// number of modifiers, orbit wins, then pan. The same goes for scrolling, so plain scrolling can
+/// zoom while Shift + scrolling pitches, and ties are won in the order of `ScrollAction`.
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{ButtonBinding, Modifier, PanOrbitInputMap, ScrollAxis, ScrollBinding};
+/// // Maya style controls
+/// let input_map = PanOrbitInputMap {
+///     orbit: vec![ButtonBinding::new(MouseButton::Left).with_modifier(Modifier::Alt)],
+///     pan: vec![ButtonBinding::new(MouseButton::Middle).with_modifier(Modifier::Alt)],
+///     ..default()
+/// };
+/// // Scroll to zoom, Shift + scroll to pitch, Ctrl + scroll to change the field of view, and
+/// // scroll sideways to yaw
+/// let input_map = PanOrbitInputMap {
+///     scroll_pitch: vec![ScrollBinding::new(ScrollAxis::Vertical).with_modifier(Modifier::Shift)],
+///     scroll_fov: vec![ScrollBinding::new(ScrollAxis::Vertical).with_modifier(Modifier::Control)],
+///     scroll_yaw: vec![ScrollBinding::new(ScrollAxis::Horizontal)],
+///     ..default()
+/// };
+/// ```
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanOrbitInputMap {
+    /// Bindings that orbit the camera when dragging.
+    /// Defaults to Left Mouse.
+    pub orbit: Vec<ButtonBinding>,
+    /// Bindings that pan the camera when dragging.
+    /// Defaults to Right Mouse.
+    pub pan: Vec<ButtonBinding>,
+    /// Scroll bindings that zoom the camera.
+    /// Defaults to vertical scrolling.
+    pub zoom: Vec<ScrollBinding>,
+    /// Bindings that draw a rectangle when dragging, which the camera zooms to fit when the
+    /// button is released.
+    /// Defaults to none.
+    pub box_zoom: Vec<ButtonBinding>,
+    /// Bindings that roll the camera around its view direction when dragging horizontally, e.g.
+    /// Ctrl + Left Mouse.
+    /// Defaults to none.
+    pub roll: Vec<ButtonBinding>,
+    /// Bindings that zoom the camera when dragging, like Maya's dolly: dragging right or up moves
+    /// closer, and left or down moves away. The zoom is proportional to the radius, so it feels
+    /// the same at any distance, and it's towards the point that was under the cursor when the
+    /// drag started, which stays put on screen.
+    /// Defaults to none.
+    pub zoom_drag: Vec<ButtonBinding>,
+    /// Scroll bindings that orbit the camera left and right.
+    /// Defaults to none.
+    pub scroll_yaw: Vec<ScrollBinding>,
+    /// Scroll bindings that orbit the camera up and down.
+    /// Defaults to none.
+    pub scroll_pitch: Vec<ScrollBinding>,
+    /// Scroll bindings that pan the camera, sideways for horizontal scrolling and up and down for
+    /// vertical scrolling.
+    /// Defaults to none.
+    pub scroll_pan: Vec<ScrollBinding>,
+    /// Scroll bindings that change the field of view of a perspective camera, within
+    /// `PanOrbitCamera::fov_lower_limit` and `PanOrbitCamera::fov_upper_limit`.
+    /// Defaults to none.
+    pub scroll_fov: Vec<ScrollBinding>,
+}
+
+impl Default for PanOrbitInputMap {
+    fn default() -> Self {
+        Self {
+            orbit: vec![ButtonBinding::new(MouseButton::Left)],
+            pan: vec![ButtonBinding::new(MouseButton::Right)],
+            zoom: vec![ScrollBinding::new(ScrollAxis::Vertical)],
+            box_zoom: Vec::new(),
+            roll: Vec::new(),
+            zoom_drag: Vec::new(),
+            scroll_yaw: Vec::new(),
+            scroll_pitch: Vec::new(),
+            scroll_pan: Vec::new(),
+            scroll_fov: Vec::new(),
+        }
+    }
+}
+
+impl PanOrbitInputMap {
+    /// Blender style controls: Middle Mouse orbits, Shift + Middle Mouse pans.
+    pub fn blender() -> Self {
+        Self {
+            orbit: vec![ButtonBinding::new(MouseButton::Middle)],
+            pan: vec![ButtonBinding::new(MouseButton::Middle).with_modifier(Modifier::Shift)],
+            ..default()
+        }
+    }
+
+    /// Maya style controls: Alt + Left Mouse orbits, Alt + Middle Mouse pans, and Alt + Right
+    /// Mouse zooms.
+    pub fn maya() -> Self {
+        Self {
+            orbit: vec![ButtonBinding::new(MouseButton::Left).with_modifier(Modifier::Alt)],
+            pan: vec![ButtonBinding::new(MouseButton::Middle).with_modifier(Modifier::Alt)],
+            zoom_drag: vec![ButtonBinding::new(MouseButton::Right).with_modifier(Modifier::Alt)],
+            ..default()
+        }
+    }
+
+    /// Whether any modifier used by the orbit, pan, box zoom, roll, or zoom drag bindings is held.
+    pub fn any_drag_modifier_pressed(&self, key_input: &ButtonInput<KeyCode>) -> bool {
+        self.orbit
+            .iter()
+            .chain(self.pan.iter())
+            .chain(self.box_zoom.iter())
+            .chain(self.roll.iter())
+            .chain(self.zoom_drag.iter())
+            .flat_map(|binding| binding.modifiers.iter())
+            .any(|modifier| modifier.pressed(key_input))
+    }
+
+    /// The scroll bindings for `action`.
+    pub fn scroll_bindings(&self, action: ScrollAction) -> &[ScrollBinding] {
+        match action {
+            ScrollAction::Zoom => &self.zoom,
+            ScrollAction::Yaw => &self.scroll_yaw,
+            ScrollAction::Pitch => &self.scroll_pitch,
+            ScrollAction::Pan => &self.scroll_pan,
+            ScrollAction::Fov => &self.scroll_fov,
+        }
+    }
+
+    /// The action that scrolling along `axis` performs with the modifiers currently held, if any.
+    /// The active binding with the most modifiers wins, then the earliest action in
+    /// `ScrollAction`.
+    pub fn scroll_action(
+        &self,
+        axis: ScrollAxis,
+        key_input: &ButtonInput<KeyCode>,
+    ) -> Option<ScrollAction> {
+        ScrollAction::ALL
+            .into_iter()
+            .filter_map(|action| {
+                let specificity = self
+                    .scroll_bindings(action)
+                    .iter()
+                    .filter(|binding| binding.axis == axis && binding.modifiers_pressed(key_input))
+                    .map(|binding| binding.modifiers.len())
+                    .max()?;
+                Some((action, specificity))
+            })
+            // The first of the most specific, as `max_by_key` would return the last
+            .min_by_key(|&(_, specificity)| std::cmp::Reverse(specificity))
+            .map(|(action, _)| action)
+    }
+
+    /// The total scroll amount of `event` that should zoom the camera. Each axis is counted once,
+    /// even if multiple bindings for it are active, and not at all if a more specific binding for
+    /// another action takes it, see `scroll_action`.
+    pub fn zoom_amount(&self, event: &MouseWheel, key_input: &ButtonInput<KeyCode>) -> f32 {
+        [ScrollAxis::Vertical, ScrollAxis::Horizontal]
+            .into_iter()
+            .filter(|axis| self.scroll_action(*axis, key_input) == Some(ScrollAction::Zoom))
+            .map(|axis| axis.value(event))
+            .sum()
+    }
+}
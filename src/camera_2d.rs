@@ -0,0 +1,394 @@
+use bevy::math::{DVec2, DVec3};
+use bevy::prelude::*;
+
+use crate::clock::CameraTime;
+use crate::input::MouseKeyTracker;
+use crate::touch::TouchTracker;
+use crate::traits::OptionalClamp;
+use crate::{
+    apply_focus_bounds, ActiveCameraData, ButtonBinding, CameraGesture, DragAction,
+    FocusLossPolicy, InputDevice, PanOrbitCamera, PanOrbitInputMap, TimeSinceInput, TouchAction,
+    TouchControls,
+};
+
+/// Pans and zooms a `Camera2d`: drag to pan, and scroll or pinch to zoom, towards the cursor by
+/// default. The settings are a `PanOrbitCamera`'s, and the camera gets its input in the same way
+/// as a 3D `PanOrbitCamera`, so `input_region`, touch, trackpad, gesture conflict, focus loss, and
+/// cursor grab settings all apply, and only the camera under the cursor responds where 2D and 3D
+/// viewports overlap.
+///
+/// The focus is the point in the middle of the view, and the radius is the orthographic scale.
+/// The pan, zoom, and focus bounds settings work as they do in 3D, while the orbit, fly, keyboard,
+/// and gamepad settings are ignored. Defaults to `PanZoomCamera2d::default_settings`.
+/// Handled by `PanOrbitCameraPlugin`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin, PanZoomCamera2d};
+/// # fn main() {
+/// #     App::new()
+/// #         .add_plugins(DefaultPlugins)
+/// #         .add_plugins(PanOrbitCameraPlugin::default())
+/// #         .add_systems(Startup, setup)
+/// #         .run();
+/// # }
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(PanZoomCamera2d(PanOrbitCamera {
+///         zoom_upper_limit: Some(5.0),
+///         ..PanZoomCamera2d::default_settings()
+///     }));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq, Deref, DerefMut)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+#[require(Camera2d, TimeSinceInput)]
+pub struct PanZoomCamera2d(pub PanOrbitCamera);
+
+impl Default for PanZoomCamera2d {
+    fn default() -> Self {
+        Self(Self::default_settings())
+    }
+}
+
+impl PanZoomCamera2d {
+    /// The `PanOrbitCamera` settings suited to 2D: Left or Right Mouse to pan, one or two fingers
+    /// to pan on touch screens, trackpad pinch to zoom, and zooming towards the cursor.
+    pub fn default_settings() -> PanOrbitCamera {
+        PanOrbitCamera {
+            input_map: PanOrbitInputMap {
+                orbit: Vec::new(),
+                pan: vec![
+                    ButtonBinding::new(MouseButton::Left),
+                    ButtonBinding::new(MouseButton::Right),
+                ],
+                ..default()
+            },
+            touch_controls: TouchControls {
+                one_finger: TouchAction::Pan.into(),
+                ..TouchControls::one_finger_orbit()
+            },
+            trackpad_pinch_to_zoom_enabled: true,
+            zoom_to_cursor: true,
+            ..default()
+        }
+    }
+}
+
+/// Pan and zoom each `PanZoomCamera2d` with the input gathered for the active camera
+pub fn pan_zoom_camera_2d(
+    active_cam: Res<ActiveCameraData>,
+    mouse_key_tracker: Res<MouseKeyTracker>,
+    touch_tracker: Res<TouchTracker>,
+    mut cameras: Query<(
+        Entity,
+        &mut PanZoomCamera2d,
+        &mut TimeSinceInput,
+        &Camera,
+        &mut Transform,
+        &mut OrthographicProjection,
+    )>,
+    time: CameraTime,
+) {
+    for (entity, mut pan_zoom, mut time_since_input, camera, mut transform, mut projection) in
+        cameras.iter_mut()
+    {
+        let pan_orbit = &mut pan_zoom.0;
+        let apply_zoom_limits = {
+            let zoom_upper_limit = pan_orbit.zoom_upper_limit;
+            let zoom_lower_limit = pan_orbit.zoom_lower_limit;
+            move |zoom: f64| zoom.clamp_optional(Some(zoom_lower_limit), zoom_upper_limit)
+        };
+        let apply_focus_limits = {
+            let origin = pan_orbit.focus_bounds_origin;
+            let shape = pan_orbit.focus_bounds_shape;
+            move |focus: DVec3| apply_focus_bounds(focus, origin, shape)
+        };
+
+        if !pan_orbit.initialized {
+            let zoom = apply_zoom_limits(pan_orbit.radius.unwrap_or(projection.scale as f64));
+            let focus = apply_focus_limits(pan_orbit.focus.truncate().extend(0.0));
+            pan_orbit.yaw = Some(0.0);
+            pan_orbit.pitch = Some(0.0);
+            pan_orbit.radius = Some(zoom);
+            pan_orbit.focus = focus;
+            pan_orbit.target_radius = zoom;
+            pan_orbit.target_focus = focus;
+            if pan_orbit.home_view.is_none() {
+                pan_orbit.save_home_view();
+            }
+            pan_orbit.initialized = true;
+            pan_orbit.force_update = true;
+        }
+        let Some(zoom) = pan_orbit.radius else {
+            continue;
+        };
+
+        // 1 - Get input, in logical pixels for panning and in lines for zooming
+
+        let mut pan = DVec2::ZERO;
+        let mut scroll_line = 0.0;
+        let mut scroll_pixel = 0.0;
+        let mut cursor_position = None;
+        let mut reset_requested = false;
+        let mut press_started = false;
+        let mut gesture = CameraGesture::None;
+        let mut input_device = None;
+        if pan_orbit.accepts_input() && active_cam.entity == Some(entity) {
+            let zoom_direction = match pan_orbit.reversed_zoom {
+                true => -1.0,
+                false => 1.0,
+            };
+            // Mouse motion is in physical pixels
+            let scale_factor = active_cam.scale_factor.unwrap_or(1.0) as f64;
+            pan = mouse_key_tracker.pan.as_dvec2() / scale_factor * pan_orbit.pan_sensitivity;
+            scroll_line =
+                mouse_key_tracker.scroll_line as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+            scroll_pixel =
+                mouse_key_tracker.scroll_pixel as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+            cursor_position = mouse_key_tracker.cursor_position;
+            reset_requested = mouse_key_tracker.reset_requested;
+            press_started = mouse_key_tracker.button_just_pressed;
+            if mouse_key_tracker.drag.is_some()
+                || pan != DVec2::ZERO
+                || scroll_line != 0.0
+                || scroll_pixel != 0.0
+            {
+                input_device = Some(InputDevice::Mouse);
+            }
+            // A press without moving is a click, not a drag
+            let drag_gesture = mouse_key_tracker
+                .drag
+                .filter(|drag| drag.moved)
+                .map(|drag| drag.action);
+            gesture = match drag_gesture {
+                Some(DragAction::Pan) => CameraGesture::Pan,
+                None if scroll_line != 0.0 || scroll_pixel != 0.0 => CameraGesture::Zoom,
+                _ => CameraGesture::None,
+            };
+
+            if pan_orbit.touch_enabled {
+                let touch = pan_orbit
+                    .touch_controls
+                    .process(&touch_tracker.get_touch_gestures());
+                // Touch positions are already in logical pixels
+                pan += touch.pan.as_dvec2() * pan_orbit.pan_sensitivity;
+                scroll_pixel += touch.zoom as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+                if touch_tracker.is_touching() {
+                    input_device = Some(InputDevice::Touch);
+                    // Keep showing the last gesture while the fingers are still
+                    gesture = if touch.zoom != 0.0 {
+                        CameraGesture::Zoom
+                    } else if touch.pan != Vec2::ZERO {
+                        CameraGesture::Pan
+                    } else {
+                        pan_orbit.gesture
+                    };
+                }
+                press_started |= touch_tracker.touch_just_started();
+            }
+        }
+        if !pan_orbit.pan_enabled {
+            pan = DVec2::ZERO;
+        }
+        if !pan_orbit.zoom_enabled {
+            (scroll_line, scroll_pixel) = (0.0, 0.0);
+        }
+        if pan_orbit.gesture != gesture {
+            pan_orbit.gesture = gesture;
+        }
+        if let Some(device) = input_device.filter(|device| *device != pan_orbit.input_device) {
+            pan_orbit.input_device = device;
+        }
+        let interacted = gesture != CameraGesture::None
+            || press_started
+            || reset_requested
+            || pan != DVec2::ZERO
+            || scroll_line != 0.0
+            || scroll_pixel != 0.0;
+        time_since_input.0 = match interacted {
+            true => 0.0,
+            false => time_since_input.0 + time.delta_secs(),
+        };
+
+        // 2 - Process input into the target focus and zoom
+
+        if mouse_key_tracker.focus_lost
+            && active_cam.entity == Some(entity)
+            && pan_orbit.focus_loss_policy == FocusLossPolicy::Freeze
+        {
+            pan_orbit.stop_motion();
+        }
+        if reset_requested {
+            pan_orbit.reset_to_home(true);
+        }
+
+        // World units per logical pixel at the current zoom
+        let units_per_pixel = camera
+            .logical_viewport_size()
+            .filter(|size| size.x > 0.0)
+            .map(|size| (projection.area.width() / size.x) as f64);
+        if let (true, Some(units_per_pixel)) = (pan != DVec2::ZERO, units_per_pixel) {
+            // Dragging moves the view with the cursor, and window coordinates have y down
+            pan_orbit.target_focus += DVec3::new(-pan.x, pan.y, 0.0) * units_per_pixel;
+        }
+
+        if scroll_line + scroll_pixel != 0.0 {
+            let step = pan_orbit.target_radius;
+            let line_delta = -scroll_line * step * 0.2;
+            let pixel_delta = -scroll_pixel * step * 0.2;
+
+            // Find the point under the cursor before the zoom changes anything, as it will be once
+            // the camera reaches its targets
+            let zoom_point = cursor_position
+                .filter(|_| pan_orbit.zoom_to_cursor)
+                .and_then(|cursor| {
+                    camera
+                        .viewport_to_world_2d(&GlobalTransform::from(*transform), cursor)
+                        .ok()
+                })
+                .map(|point| {
+                    let offset = point.as_dvec2() - pan_orbit.focus.truncate();
+                    pan_orbit.target_focus + (offset * pan_orbit.target_radius / zoom).extend(0.0)
+                });
+            let old_target_radius = pan_orbit.target_radius;
+
+            pan_orbit.target_radius += line_delta + pixel_delta;
+            // If it is pixel-based scrolling, add it directly to the current value
+            pan_orbit.radius = pan_orbit
+                .radius
+                .map(|value| apply_zoom_limits(value + pixel_delta));
+
+            // Move the focus towards the point under the cursor by the same proportion as the
+            // zoom changed, which keeps that point stationary on screen
+            if let Some(zoom_point) = zoom_point {
+                let new_target_radius = apply_zoom_limits(pan_orbit.target_radius);
+                let factor = 1.0 - new_target_radius / old_target_radius;
+                let offset = (zoom_point - pan_orbit.target_focus) * factor;
+                pan_orbit.target_focus += offset;
+                if pixel_delta != 0.0 {
+                    pan_orbit.focus += offset;
+                }
+            }
+        }
+
+        // 3 - Apply constraints
+
+        pan_orbit.target_radius = apply_zoom_limits(pan_orbit.target_radius);
+        pan_orbit.target_focus = apply_focus_limits(pan_orbit.target_focus);
+
+        // 4 - Update the camera's transform based on current values
+
+        let Some(zoom) = pan_orbit.radius else {
+            continue;
+        };
+        if pan_orbit.force_update
+            || pan_orbit.target_radius != zoom
+            || pan_orbit.target_focus != pan_orbit.focus
+        {
+            let dt = time.delta_secs_f64();
+            let smoothing = pan_orbit.smoothing();
+            let curves = pan_orbit.smoothing_curves;
+            let mut velocity = pan_orbit.spring_velocity;
+            let new_zoom = curves.zoom.step_f64(
+                zoom,
+                pan_orbit.target_radius,
+                &mut velocity.radius,
+                smoothing.zoom,
+                dt,
+            );
+            let new_focus = curves.pan.step_dvec3(
+                pan_orbit.focus,
+                pan_orbit.target_focus,
+                &mut velocity.focus,
+                smoothing.pan,
+                dt,
+            );
+            pan_orbit.spring_velocity = velocity;
+
+            transform.translation.x = new_focus.x as f32;
+            transform.translation.y = new_focus.y as f32;
+            projection.scale = new_zoom as f32;
+            pan_orbit.position = new_focus.truncate().extend(transform.translation.z as f64);
+            pan_orbit.radius = Some(new_zoom);
+            pan_orbit.focus = new_focus;
+            pan_orbit.force_update = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+    use bevy::input::InputPlugin;
+
+    use super::*;
+    use crate::{ActivePanOrbitCamera, PanOrbitCameraPlugin};
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            WindowPlugin {
+                primary_window: None,
+                ..default()
+            },
+            PanOrbitCameraPlugin::default(),
+        ));
+        app
+    }
+
+    fn scroll(app: &mut App) {
+        app.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: 1.0,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+    }
+
+    #[test]
+    fn scrolling_zooms_the_active_2d_camera() {
+        let mut app = app();
+        let camera = app.world_mut().spawn(PanZoomCamera2d::default()).id();
+        app.world_mut()
+            .resource_mut::<ActivePanOrbitCamera>()
+            .entity = Some(camera);
+        app.update();
+        scroll(&mut app);
+
+        let pan_orbit = app.world().get::<PanZoomCamera2d>(camera).unwrap();
+        assert!(pan_orbit.target_radius < 1.0);
+        assert_eq!(pan_orbit.gesture, CameraGesture::Zoom);
+    }
+
+    #[test]
+    fn inactive_2d_camera_ignores_input() {
+        let mut app = app();
+        let camera_2d = app.world_mut().spawn(PanZoomCamera2d::default()).id();
+        let camera_3d = app
+            .world_mut()
+            .spawn(PanOrbitCamera {
+                radius: Some(5.0),
+                ..default()
+            })
+            .id();
+        app.world_mut()
+            .resource_mut::<ActivePanOrbitCamera>()
+            .entity = Some(camera_3d);
+        app.update();
+        let radius_3d = app
+            .world()
+            .get::<PanOrbitCamera>(camera_3d)
+            .unwrap()
+            .target_radius;
+        scroll(&mut app);
+
+        let pan_orbit = app.world().get::<PanZoomCamera2d>(camera_2d).unwrap();
+        assert_eq!(pan_orbit.target_radius, 1.0);
+        let pan_orbit = app.world().get::<PanOrbitCamera>(camera_3d).unwrap();
+        assert!(pan_orbit.target_radius < radius_3d);
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use bevy::core::FrameCount;
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::clock::CameraTime;
+use crate::PanOrbitCamera;
+
+/// Streams a `PanOrbitCamera`'s view and projection matrices every frame, e.g. to drive an offline
+/// path tracer or video exporter running alongside Bevy from the interactive camera.
+///
+/// The matrices are captured in `PostUpdate`, after transforms have propagated and the camera's
+/// projection has been updated, so they're exactly what the frame is rendered with. Each
+/// subscriber gets its own channel, which can be read from any thread, and subscribers that have
+/// dropped their receiver are forgotten. The latest capture is also kept in `latest` for systems
+/// in the app.
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{MatrixCapture, PanOrbitCamera};
+/// fn setup(mut commands: Commands) {
+///     let mut capture = MatrixCapture::default();
+///     let matrices = capture.subscribe();
+///     std::thread::spawn(move || {
+///         // Runs until the camera is despawned
+///         for frame in matrices {
+///             println!("{}: {} {}", frame.frame, frame.view_from_world, frame.clip_from_view);
+///         }
+///     });
+///     commands.spawn((PanOrbitCamera::default(), capture));
+/// }
+/// ```
+#[derive(Component, Debug, Default)]
+pub struct MatrixCapture {
+    subscribers: Vec<Sender<CameraMatrices>>,
+    latest: Option<CameraMatrices>,
+}
+
+impl MatrixCapture {
+    /// Start receiving this camera's matrices, from the next frame on.
+    pub fn subscribe(&mut self) -> Receiver<CameraMatrices> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// The matrices of the last frame that was captured, if any.
+    pub fn latest(&self) -> Option<&CameraMatrices> {
+        self.latest.as_ref()
+    }
+
+    /// How many subscribers are still listening.
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+}
+
+/// A camera's matrices for one frame, see `MatrixCapture`.
+#[derive(Clone, Debug)]
+pub struct CameraMatrices {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The number of the frame, from Bevy's `FrameCount`, or `0` if it isn't available.
+    pub frame: u32,
+    /// The time of the frame, in seconds, from the clock the camera runs on (see
+    /// `PanOrbitCameraPlugin::use_real_time`).
+    pub elapsed: f64,
+    /// The camera's transform, i.e. the matrix from view space to world space.
+    pub world_from_view: Mat4,
+    /// The view matrix, from world space to view space. Bevy's view space looks along -Z, with Y
+    /// up.
+    pub view_from_world: Mat4,
+    /// The projection matrix, from view space to clip space. Bevy uses reversed depth, and an
+    /// infinite far plane for perspective projections, so an offline renderer may prefer to
+    /// rebuild its projection from `projection`.
+    pub clip_from_view: Mat4,
+    /// The camera's projection, with its field of view, aspect ratio, and clipping planes.
+    pub projection: Projection,
+    /// The size of the camera's viewport in physical pixels, if it's known yet.
+    pub viewport_size: Option<UVec2>,
+    /// The camera's `PanOrbitCamera::world_offset`, which the matrices are relative to. Add it to
+    /// positions from `world_from_view` to get positions in the full precision world.
+    pub world_offset: DVec3,
+}
+
+/// Send the matrices of every camera with `MatrixCapture` to its subscribers
+pub fn capture_matrices(
+    mut cameras: Query<(
+        Entity,
+        &mut MatrixCapture,
+        &PanOrbitCamera,
+        &Camera,
+        &GlobalTransform,
+        &Projection,
+    )>,
+    frame_count: Option<Res<FrameCount>>,
+    time: CameraTime,
+) {
+    let frame = frame_count.map_or(0, |frame_count| frame_count.0);
+    let elapsed = time.elapsed_secs_f64();
+    for (entity, mut capture, pan_orbit, camera, transform, projection) in cameras.iter_mut() {
+        let world_from_view = transform.compute_matrix();
+        let matrices = CameraMatrices {
+            entity,
+            frame,
+            elapsed,
+            world_from_view,
+            view_from_world: world_from_view.inverse(),
+            clip_from_view: camera.clip_from_view(),
+            projection: projection.clone(),
+            viewport_size: camera.physical_viewport_size(),
+            world_offset: pan_orbit.world_offset,
+        };
+        capture
+            .subscribers
+            .retain(|subscriber| subscriber.send(matrices.clone()).is_ok());
+        capture.latest = Some(matrices);
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Which clock the camera systems advance with, set by `PanOrbitCameraPlugin::use_real_time`
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub(crate) struct PanOrbitClock {
+    pub(crate) real_time: bool,
+}
+
+/// The time the camera systems run on: the schedule's own `Time` (virtual time, or fixed time in
+/// `FixedUpdate`), or `Time<Real>`, which keeps going while virtual time is paused or slowed down
+#[derive(SystemParam)]
+pub(crate) struct CameraTime<'w> {
+    clock: Res<'w, PanOrbitClock>,
+    time: Res<'w, Time>,
+    real_time: Res<'w, Time<Real>>,
+}
+
+impl CameraTime<'_> {
+    /// How much time has advanced since the last update, in seconds
+    pub(crate) fn delta_secs(&self) -> f32 {
+        match self.clock.real_time {
+            true => self.real_time.delta_secs(),
+            false => self.time.delta_secs(),
+        }
+    }
+
+    /// How much time has advanced since the last update, in seconds
+    pub(crate) fn delta_secs_f64(&self) -> f64 {
+        match self.clock.real_time {
+            true => self.real_time.delta_secs_f64(),
+            false => self.time.delta_secs_f64(),
+        }
+    }
+
+    /// How much time has advanced since startup, in seconds
+    pub(crate) fn elapsed_secs_f64(&self) -> f64 {
+        match self.clock.real_time {
+            true => self.real_time.elapsed_secs_f64(),
+            false => self.time.elapsed_secs_f64(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, RayCastSettings, RayCastVisibility};
+use bevy::prelude::*;
+
+use crate::{util, PanOrbitCamera};
+
+/// Stops a `PanOrbitCamera` from passing through meshes. Each frame, a ray is cast from the focus
+/// towards the camera, and if it hits a mesh the camera is pulled in front of it. The camera's
+/// `target_radius` is left alone, so it moves back out once the way is clear.
+///
+/// Add this alongside `PanOrbitCamera`. Requires the `bevy_picking` feature. Has no effect on
+/// orthographic cameras.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitCollision {
+    /// How far in front of the hit point to keep the camera, so the near plane doesn't clip into
+    /// the geometry.
+    /// Defaults to `0.2`.
+    pub margin: f64,
+}
+
+impl Default for PanOrbitCollision {
+    fn default() -> Self {
+        Self { margin: 0.2 }
+    }
+}
+
+/// Add this to meshes that the camera shouldn't collide with, e.g. the player character in a
+/// third person game.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct IgnoreCameraCollision;
+
+/// Cast a ray from each colliding camera's focus towards where it wants to be, and save the
+/// distance to the nearest hit in `PanOrbitCamera::obstruction_distance`
+pub fn camera_collision(
+    mut cameras: Query<(&mut PanOrbitCamera, &PanOrbitCollision, &Projection)>,
+    ignored: Query<(), With<IgnoreCameraCollision>>,
+    mut ray_cast: MeshRayCast,
+) {
+    for (mut pan_orbit, collision, projection) in cameras.iter_mut() {
+        let (Some(yaw), Some(pitch)) = (pan_orbit.yaw, pan_orbit.pitch) else {
+            continue;
+        };
+        if !matches!(projection, Projection::Perspective(_)) {
+            pan_orbit.obstruction_distance = None;
+            continue;
+        }
+        let direction = util::orbit_offset(yaw, pitch, pan_orbit.orbit_axis());
+        let Ok(direction) = Dir3::new(direction.as_vec3()) else {
+            continue;
+        };
+        let ray = Ray3d::new(
+            pan_orbit.to_transform_space(pan_orbit.focus).as_vec3(),
+            direction,
+        );
+        let filter = |entity| !ignored.contains(entity);
+        let settings = RayCastSettings::default()
+            .with_visibility(RayCastVisibility::Visible)
+            .with_filter(&filter);
+        let max_distance = pan_orbit.target_radius + collision.margin;
+        let obstruction_distance = ray_cast
+            .cast_ray(ray, &settings)
+            .first()
+            .map(|(_, hit)| hit.distance as f64)
+            .filter(|distance| *distance < max_distance)
+            .map(|distance| (distance - collision.margin).max(0.0));
+        pan_orbit.obstruction_distance = obstruction_distance;
+    }
+}
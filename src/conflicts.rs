@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use crate::input::DragAction;
+use crate::util;
+
+/// A two finger gesture on a touch screen or trackpad, for `GestureConflicts`.
+#[derive(Reflect, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwoFingerGesture {
+    /// Moving two fingers together. On a trackpad, this is two finger scrolling.
+    Drag,
+    /// Moving two fingers closer together or further apart.
+    Pinch,
+    /// Rotating two fingers around each other. Trackpads don't report this.
+    Twist,
+}
+
+impl TwoFingerGesture {
+    pub(crate) const ALL: [Self; 3] = [Self::Drag, Self::Pinch, Self::Twist];
+}
+
+/// How a `PanOrbitCamera` decides between gestures that could match the same input.
+///
+/// Mouse drags are decided once, when the button is pressed, and then keep the same action until
+/// it's released. Two finger gestures are, by default, recognised independently, so dragging,
+/// pinching, and twisting can all happen at once. Set `exclusive_two_finger` to only allow one
+/// at a time, e.g. so that panning with two fingers never zooms slightly.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{DragAction, GestureConflicts, TwoFingerGesture};
+/// // Pan rather than orbit when both are bound to the same button, and let a pinch take over
+/// // from a two finger pan, but not the other way round
+/// let gesture_conflicts = GestureConflicts {
+///     drag_priority: vec![DragAction::Pan, DragAction::Orbit],
+///     exclusive_two_finger: true,
+///     two_finger_priority: vec![TwoFingerGesture::Pinch, TwoFingerGesture::Drag],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GestureConflicts {
+    /// Which drag action wins when the bindings of more than one match the buttons and
+    /// modifiers being held, earliest first. Actions that are left out rank after the listed
+    /// ones, in the default order.
+    /// Defaults to orbit, pan, box zoom, then roll.
+    pub drag_priority: Vec<DragAction>,
+    /// Whether a binding with more modifiers beats one with fewer before `drag_priority` is
+    /// considered, so that e.g. Shift + Middle Mouse pans when Middle Mouse alone orbits.
+    /// Defaults to `true`.
+    pub prefer_specific_bindings: bool,
+    /// How far, in pixels, the fingers must move in a particular way before a two finger drag,
+    /// pinch, or twist is recognised. Twists are measured along the arc the fingers move on.
+    /// Defaults to `8.0`.
+    pub recognition_threshold: f32,
+    /// Whether only one two finger gesture can be recognised at a time, on both touch screens
+    /// and trackpads. Gestures whose touch action is `TouchAction::None` are never recognised.
+    /// Defaults to `false`.
+    pub exclusive_two_finger: bool,
+    /// With `exclusive_two_finger`, which gesture wins when more than one is recognised in the
+    /// same frame, earliest first, and which one a trackpad starts with when both scroll and pinch
+    /// events arrive together. Gestures that are left out rank after the listed ones.
+    /// Defaults to drag, pinch, then twist.
+    pub two_finger_priority: Vec<TwoFingerGesture>,
+    /// With `exclusive_two_finger`, how many times `recognition_threshold` the fingers must move in
+    /// another way before that gesture takes over from the current one. Higher values make the
+    /// current gesture harder to leave, and `f32::INFINITY` keeps it until the fingers are lifted.
+    /// Defaults to `3.0`.
+    pub hysteresis: f32,
+    /// With `exclusive_two_finger`, how long, in seconds, a trackpad keeps ignoring pinch events
+    /// after the last scroll event, or scroll events after the last pinch event. Trackpads don't
+    /// report when the fingers are lifted, so this is how a trackpad gesture ends.
+    /// Defaults to `0.2`.
+    pub trackpad_hold_time: f32,
+}
+
+impl Default for GestureConflicts {
+    fn default() -> Self {
+        Self {
+            drag_priority: DragAction::ALL.to_vec(),
+            prefer_specific_bindings: true,
+            recognition_threshold: 8.0,
+            exclusive_two_finger: false,
+            two_finger_priority: TwoFingerGesture::ALL.to_vec(),
+            hysteresis: 3.0,
+            trackpad_hold_time: 0.2,
+        }
+    }
+}
+
+impl GestureConflicts {
+    /// Which of `candidates`, each a drag action and the number of modifiers of its most specific
+    /// active binding, should start a drag
+    pub(crate) fn drag_winner(
+        &self,
+        candidates: impl IntoIterator<Item = (DragAction, Option<usize>)>,
+    ) -> Option<DragAction> {
+        candidates
+            .into_iter()
+            .filter_map(|(action, specificity)| Some((action, specificity?)))
+            .min_by_key(|&(action, specificity)| {
+                let specificity = if self.prefer_specific_bindings {
+                    specificity
+                } else {
+                    0
+                };
+                let rank = util::priority_rank(&self.drag_priority, &DragAction::ALL, action);
+                (std::cmp::Reverse(specificity), rank)
+            })
+            .map(|(action, _)| action)
+    }
+
+    /// Which of `candidates` wins under `two_finger_priority`
+    pub(crate) fn two_finger_winner(
+        &self,
+        candidates: impl IntoIterator<Item = TwoFingerGesture>,
+    ) -> Option<TwoFingerGesture> {
+        candidates.into_iter().min_by_key(|&gesture| {
+            util::priority_rank(&self.two_finger_priority, &TwoFingerGesture::ALL, gesture)
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::utils::{HashMap, HashSet};
+use bevy::window::{PrimaryWindow, SystemCursorIcon};
+use bevy::winit::cursor::CursorIcon;
+
+use crate::{CameraGesture, PanOrbitCamera};
+
+/// Sets the window's cursor icon to match what the user is doing with a `PanOrbitCamera`. Add
+/// this alongside `PanOrbitCamera`. Requires the `cursor_icon` feature.
+///
+/// Set an icon to `None` to leave the cursor alone in that state. When none of the states apply,
+/// the cursor goes back to the default icon.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct PanOrbitCursorIcons {
+    /// The icon shown while the cursor is over the camera's viewport.
+    /// Defaults to `Some(SystemCursorIcon::Grab)`.
+    pub hover: Option<CursorIcon>,
+    /// The icon shown while orbiting.
+    /// Defaults to `Some(SystemCursorIcon::Grabbing)`.
+    pub orbit: Option<CursorIcon>,
+    /// The icon shown while panning.
+    /// Defaults to `Some(SystemCursorIcon::Move)`.
+    pub pan: Option<CursorIcon>,
+    /// The icon shown while zooming.
+    /// Defaults to `Some(SystemCursorIcon::ZoomIn)`.
+    pub zoom: Option<CursorIcon>,
+}
+
+impl Default for PanOrbitCursorIcons {
+    fn default() -> Self {
+        Self {
+            hover: Some(SystemCursorIcon::Grab.into()),
+            orbit: Some(SystemCursorIcon::Grabbing.into()),
+            pan: Some(SystemCursorIcon::Move.into()),
+            zoom: Some(SystemCursorIcon::ZoomIn.into()),
+        }
+    }
+}
+
+/// Set the cursor icon of each window that has a camera with `PanOrbitCursorIcons`. A gesture in
+/// progress takes priority over hovering, so a drag that leaves its viewport keeps its icon.
+pub fn update_cursor_icons(
+    mut commands: Commands,
+    cameras: Query<(&PanOrbitCamera, &PanOrbitCursorIcons, &Camera)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    current_icons: Query<&CursorIcon>,
+    mut changed_windows: Local<HashSet<Entity>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    let mut gesture_icons = HashMap::new();
+    let mut hover_icons = HashMap::new();
+    for (pan_orbit, icons, camera) in cameras.iter() {
+        let Some(window) = (match camera.target {
+            RenderTarget::Window(window) => window.normalize(primary_window),
+            _ => None,
+        }) else {
+            continue;
+        };
+        let gesture_icon = match pan_orbit.gesture {
+            CameraGesture::None => None,
+            CameraGesture::Orbit => icons.orbit.as_ref(),
+            CameraGesture::Pan => icons.pan.as_ref(),
+            CameraGesture::Zoom => icons.zoom.as_ref(),
+        };
+        if let Some(icon) = gesture_icon {
+            gesture_icons.insert(window.entity(), icon);
+        } else if let Some(icon) = icons.hover.as_ref().filter(|_| pan_orbit.hovered) {
+            hover_icons.insert(window.entity(), icon);
+        }
+    }
+    hover_icons.extend(gesture_icons);
+
+    for (&window, &icon) in hover_icons.iter() {
+        if current_icons.get(window).ok() != Some(icon) {
+            commands.entity(window).insert(icon.clone());
+        }
+        changed_windows.insert(window);
+    }
+    // Put back the default icon once the cameras are done with a window
+    changed_windows.retain(|window| {
+        if hover_icons.contains_key(window) {
+            return true;
+        }
+        if let Some(mut window) = commands.get_entity(*window) {
+            window.insert(CursorIcon::default());
+        }
+        false
+    });
+}
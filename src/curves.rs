@@ -0,0 +1,177 @@
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::math::curve::cores::UnevenCoreError;
+use bevy::math::curve::sample_curves::UnevenSampleAutoCurve;
+use bevy::math::curve::Curve;
+use bevy::prelude::*;
+
+use crate::input::MouseKeyTracker;
+use crate::{util, ActiveCameraData};
+
+/// A response curve for tuning how strongly a `PanOrbitCamera` reacts to input, see
+/// `SensitivityCurves`. The curve maps the size of the input to a multiplier for it, linearly
+/// interpolating between points, and keeping the first or last point's multiplier for inputs
+/// outside them.
+///
+/// Curves can be loaded from `.curve` files, so they can be tuned without recompiling, and are
+/// reloaded when the file changes if Bevy's `file_watcher` feature is enabled. Each line of the
+/// file holds an input and its multiplier, separated by whitespace, in order of input. Blank lines
+/// and anything after a `#` are ignored:
+/// ```text
+/// # Slow down small stick movements, for precise control
+/// 0.0  0.2
+/// 0.5  0.5
+/// 1.0  1.0
+/// ```
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct ResponseCurve {
+    curve: UnevenSampleAutoCurve<f32>,
+}
+
+impl ResponseCurve {
+    /// Create a curve from `(input, multiplier)` points. Fails if there are fewer than two
+    /// points with different inputs.
+    pub fn new(points: impl IntoIterator<Item = (f32, f32)>) -> Result<Self, UnevenCoreError> {
+        Ok(Self {
+            curve: UnevenSampleAutoCurve::new(points)?,
+        })
+    }
+
+    /// Scale `value` by the multiplier for its length.
+    pub fn apply(&self, value: Vec2) -> Vec2 {
+        value * self.curve.sample_clamped(value.length())
+    }
+
+    /// Scale `value` by the multiplier for its size.
+    pub fn apply_scalar(&self, value: f32) -> f32 {
+        value * self.curve.sample_clamped(value.abs())
+    }
+}
+
+impl Curve<f32> for ResponseCurve {
+    fn domain(&self) -> Interval {
+        self.curve.domain()
+    }
+
+    fn sample_unchecked(&self, t: f32) -> f32 {
+        self.curve.sample_unchecked(t)
+    }
+}
+
+/// Response curves that shape a `PanOrbitCamera`'s input, so designers can tune the camera's feel
+/// without recompiling. Add this alongside `PanOrbitCamera`. Each curve is optional, and input is
+/// used as it is while a curve is still loading. Curve assets need `AssetPlugin`, part of
+/// `DefaultPlugins`.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, SensitivityCurves};
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         PanOrbitCamera::default(),
+///         SensitivityCurves {
+///             gamepad: Some(asset_server.load("camera/gamepad.curve")),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct SensitivityCurves {
+    /// Shapes zooming, by the amount scrolled or pinched each frame, in lines (or pixels scaled
+    /// to roughly match).
+    /// Defaults to `None`.
+    pub zoom: Option<Handle<ResponseCurve>>,
+    /// Shapes mouse drag panning, by the distance the mouse moved each frame, in physical
+    /// pixels.
+    /// Defaults to `None`.
+    pub pan: Option<Handle<ResponseCurve>>,
+    /// Shapes both gamepad sticks, by how far each is pushed, from `0.0` to `1.0`, before the
+    /// dead zone is applied.
+    /// Defaults to `None`.
+    pub gamepad: Option<Handle<ResponseCurve>>,
+}
+
+/// Loads `ResponseCurve`s from `.curve` files.
+#[derive(Default)]
+pub struct ResponseCurveLoader;
+
+/// An error from loading a `ResponseCurve`.
+#[derive(Debug)]
+pub enum ResponseCurveLoaderError {
+    /// The file couldn't be read.
+    Io(std::io::Error),
+    /// The given line (counting from 1) isn't a pair of numbers.
+    Parse(usize),
+    /// The points don't make a valid curve.
+    Curve(UnevenCoreError),
+}
+
+impl fmt::Display for ResponseCurveLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponseCurveLoaderError::Io(error) => write!(f, "could not read curve: {error}"),
+            ResponseCurveLoaderError::Parse(line) => {
+                write!(f, "expected an input and a multiplier on line {line}")
+            }
+            ResponseCurveLoaderError::Curve(error) => write!(f, "invalid curve: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ResponseCurveLoaderError {}
+
+impl AssetLoader for ResponseCurveLoader {
+    type Asset = ResponseCurve;
+    type Settings = ();
+    type Error = ResponseCurveLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<ResponseCurve, ResponseCurveLoaderError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(ResponseCurveLoaderError::Io)?;
+        let text = String::from_utf8_lossy(&bytes);
+        let points = util::parse_curve_points(&text).map_err(ResponseCurveLoaderError::Parse)?;
+        ResponseCurve::new(points).map_err(ResponseCurveLoaderError::Curve)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["curve"]
+    }
+}
+
+/// Shape the mouse input for the active camera with its `SensitivityCurves`. Gamepad input drives
+/// every camera, so each one applies its own gamepad curve as it reads the sticks.
+pub fn apply_sensitivity_curves(
+    active_cam: Res<ActiveCameraData>,
+    cameras: Query<&SensitivityCurves>,
+    curves: Option<Res<Assets<ResponseCurve>>>,
+    mut mouse_key_tracker: ResMut<MouseKeyTracker>,
+) {
+    let (Some(sensitivity_curves), Some(curves)) = (
+        active_cam
+            .entity
+            .and_then(|entity| cameras.get(entity).ok()),
+        curves,
+    ) else {
+        return;
+    };
+    let curve =
+        |handle: &Option<Handle<ResponseCurve>>| handle.as_ref().and_then(|h| curves.get(h));
+    if let Some(curve) = curve(&sensitivity_curves.zoom) {
+        mouse_key_tracker.scroll_line = curve.apply_scalar(mouse_key_tracker.scroll_line);
+        mouse_key_tracker.scroll_pixel = curve.apply_scalar(mouse_key_tracker.scroll_pixel);
+    }
+    if let Some(curve) = curve(&sensitivity_curves.pan) {
+        mouse_key_tracker.pan = curve.apply(mouse_key_tracker.pan);
+    }
+}
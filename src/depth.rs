@@ -0,0 +1,57 @@
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, RayCastSettings, RayCastVisibility};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::input::viewport_cursor_position;
+use crate::PanOrbitCamera;
+
+/// Scales a `PanOrbitCamera`'s panning and zooming speed by the depth of the mesh under the
+/// cursor, rather than by the radius alone. Each frame, a ray is cast through the cursor and the
+/// depth of the nearest hit is saved in `PanOrbitCamera::cursor_depth`. When there's nothing under
+/// the cursor, the radius is used as usual.
+///
+/// Add this alongside `PanOrbitCamera`. Requires the `bevy_picking` feature. Has no effect on
+/// orthographic cameras.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitCursorDepth;
+
+/// Cast a ray through the cursor for each camera with `PanOrbitCursorDepth`, and save the depth
+/// of the nearest hit in `PanOrbitCamera::cursor_depth`
+pub fn update_cursor_depth(
+    mut cameras: Query<
+        (&mut PanOrbitCamera, &Camera, &Transform, &Projection),
+        With<PanOrbitCursorDepth>,
+    >,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    mut ray_cast: MeshRayCast,
+) {
+    for (mut pan_orbit, camera, transform, projection) in cameras.iter_mut() {
+        if !matches!(projection, Projection::Perspective(_)) {
+            pan_orbit.cursor_depth = None;
+            continue;
+        }
+        // The global transform isn't propagated until after the camera has been updated
+        let global_transform = GlobalTransform::from(*transform);
+        let Some(ray) = viewport_cursor_position(
+            camera,
+            pan_orbit.input_region.as_ref(),
+            &primary_windows,
+            &other_windows,
+        )
+        .and_then(|cursor| camera.viewport_to_world(&global_transform, cursor).ok()) else {
+            pan_orbit.cursor_depth = None;
+            continue;
+        };
+        let settings = RayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+        let forward = transform.forward().as_dvec3();
+        let cursor_depth = ray_cast
+            .cast_ray(ray, &settings)
+            .first()
+            .map(|(_, hit)| (hit.point - transform.translation).as_dvec3().dot(forward))
+            .filter(|depth| *depth > 0.0);
+        pan_orbit.cursor_depth = cursor_depth;
+    }
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+
+use crate::clock::CameraTime;
+use crate::framing::world_bounds;
+use crate::{util, ControlMode, PanOrbitCamera, PanOrbitFollow, TimeSinceInput};
+
+/// Settings for gently reframing the camera while it's idle, e.g. for a kiosk or demo where
+/// users may walk away leaving the camera zoomed into empty space or right up against a model.
+///
+/// Once the user has left the camera alone for `delay` seconds, the focus slowly drifts onto the
+/// centre of the nearest significant mesh, and the radius eases into a comfortable range for the
+/// mesh's size. Any interaction stops the drift straight away. Cameras that are following a
+/// target, flying, animating, or in guided mode don't drift.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdleDrift {
+    /// How long to wait after the last interaction before drifting, in seconds.
+    /// Defaults to `10.0`.
+    pub delay: f32,
+    /// How much smoothing is applied to the drift. A value of `0.0` jumps straight to the new
+    /// view, and values close to `1.0` drift very slowly.
+    /// Defaults to `0.97`.
+    pub smoothness: f64,
+    /// Meshes smaller than this, as a multiple of the camera's radius, are ignored, so the camera
+    /// doesn't settle on small details. A mesh's size is the radius of a sphere around its
+    /// bounds.
+    /// Defaults to `0.05`.
+    pub min_size: f64,
+    /// The range the radius eases into, as multiples of the size of the mesh being drifted to.
+    /// Defaults to `(2.0, 6.0)`.
+    pub radius_range: (f64, f64),
+}
+
+impl Default for IdleDrift {
+    fn default() -> Self {
+        Self {
+            delay: 10.0,
+            smoothness: 0.97,
+            min_size: 0.05,
+            radius_range: (2.0, 6.0),
+        }
+    }
+}
+
+/// Drift idle cameras with `idle_drift` towards the nearest significant mesh
+pub fn idle_drift(
+    mut cameras: Query<(&mut PanOrbitCamera, &TimeSinceInput, Has<PanOrbitFollow>)>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform), With<Mesh3d>>,
+    time: CameraTime,
+) {
+    let dt = time.delta_secs_f64();
+    for (mut pan_orbit, time_since_input, following) in cameras.iter_mut() {
+        let Some(drift) = pan_orbit.idle_drift else {
+            continue;
+        };
+        if time_since_input.0 < drift.delay
+            || !pan_orbit.initialized
+            || !pan_orbit.enabled
+            || following
+            || pan_orbit.animation.is_some()
+            || pan_orbit.is_guided()
+            || pan_orbit.control_mode == ControlMode::Fly
+        {
+            continue;
+        }
+        let focus = pan_orbit.target_focus;
+        let radius = pan_orbit.target_radius;
+        let nearest = mesh_bounds
+            .iter()
+            .map(|(aabb, transform)| {
+                let (min, max) = world_bounds(aabb, transform);
+                (
+                    pan_orbit.from_transform_space((min + max) / 2.0),
+                    ((max - min) / 2.0).length() as f64,
+                )
+            })
+            .filter(|(_, size)| *size > 0.0 && *size >= drift.min_size * radius)
+            .min_by(|(a, _), (b, _)| {
+                a.distance_squared(focus)
+                    .total_cmp(&b.distance_squared(focus))
+            });
+        let Some((center, size)) = nearest else {
+            continue;
+        };
+        let (lower, upper) = drift.radius_range;
+        let comfortable_radius = radius.max(size * lower).min(size * upper);
+        let new_focus = util::lerp_and_snap_dvec3(focus, center, drift.smoothness, dt);
+        let new_radius = util::lerp_and_snap_f64(radius, comfortable_radius, drift.smoothness, dt);
+        // Only write when something changes, so settled cameras don't trigger change detection
+        if new_focus != focus {
+            pan_orbit.target_focus = new_focus;
+        }
+        if new_radius != radius {
+            pan_orbit.target_radius = new_radius;
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::math::{DQuat, DVec2, DVec3};
+use bevy::prelude::*;
+
+use crate::{util, PanOrbitCamera, TimeSinceInput};
+
+/// Input for a `PanOrbitCamera` that doesn't come from a window or input device, e.g. from
+/// integration tests running with `MinimalPlugins`, or scripted and replay systems. Add this
+/// alongside `PanOrbitCamera`, and write to it whenever the camera should move. It's applied and
+/// reset to zero in `PanOrbitCameraSystemSet`, once the camera has been initialized, and then
+/// smoothed like any other input. Like user input, it cancels any animation in progress, and is
+/// ignored while the camera doesn't accept input, e.g. when it isn't `enabled`.
+///
+/// Unlike `InjectedInput`, this skips Bevy's input handling and the camera's bindings altogether,
+/// so it doesn't need a window or cursor. Use `PanOrbitCamera::apply_input` to apply input
+/// straight away instead.
+/// # Example
+/// ```
+/// # use bevy::input::InputPlugin;
+/// # use bevy::math::DVec2;
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{CameraInput, PanOrbitCamera, PanOrbitCameraPlugin};
+/// // No window, just the input and window events the plugin reads
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     InputPlugin,
+///     WindowPlugin {
+///         primary_window: None,
+///         ..default()
+///     },
+///     PanOrbitCameraPlugin::default(),
+/// ));
+/// let camera = app
+///     .world_mut()
+///     .spawn((PanOrbitCamera::default(), CameraInput::default()))
+///     .id();
+/// app.update();
+///
+/// // Orbit a quarter turn to the side
+/// app.world_mut().get_mut::<CameraInput>(camera).unwrap().orbit = DVec2::new(1.5, 0.0);
+/// app.update();
+/// let pan_orbit = app.world().get::<PanOrbitCamera>(camera).unwrap();
+/// assert_eq!(pan_orbit.target_yaw, 1.5);
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct CameraInput {
+    /// How far to orbit, in radians, added to `target_yaw` and `target_pitch`.
+    pub orbit: DVec2,
+    /// How far to pan, along the camera's right and up directions, in multiples of the radius.
+    pub pan: DVec2,
+    /// How far to zoom in, as a proportion of the radius, e.g. `0.1` moves 10% closer. Negative
+    /// values zoom out.
+    pub zoom: f64,
+}
+
+impl PanOrbitCamera {
+    /// Move the camera's targets by `input`, as if the user had orbited, panned, and zoomed. The
+    /// camera then moves there with its usual smoothing and limits, and actions the user isn't
+    /// allowed to do, like orbiting when `orbit_enabled` is `false`, are ignored. Unlike
+    /// `CameraInput`, this doesn't reset the camera's `TimeSinceInput`.
+    pub fn apply_input(&mut self, input: &CameraInput) {
+        if *input == CameraInput::default() {
+            return;
+        }
+        self.animation = None;
+        let orbit = input.orbit * self.orbit_mask();
+        self.target_yaw += orbit.x;
+        self.target_pitch += orbit.y;
+        if input.pan != DVec2::ZERO && self.pan_enabled {
+            let rotation = self.target_orientation.unwrap_or_else(|| {
+                util::orbit_rotation(self.target_yaw, self.target_pitch, self.orbit_axis())
+                    * DQuat::from_rotation_z(self.target_roll)
+            });
+            let offset = rotation * DVec3::new(input.pan.x, input.pan.y, 0.0);
+            self.target_focus += offset * self.target_radius;
+        }
+        if self.zoom_enabled {
+            self.target_radius -= input.zoom * self.target_radius;
+        }
+    }
+}
+
+/// Apply and reset each camera's `CameraInput`
+pub fn apply_camera_input(
+    mut cameras: Query<(&mut PanOrbitCamera, &mut CameraInput, &mut TimeSinceInput)>,
+) {
+    for (mut pan_orbit, mut input, mut time_since_input) in cameras.iter_mut() {
+        if *input == CameraInput::default() || !pan_orbit.initialized {
+            continue;
+        }
+        if pan_orbit.accepts_input() {
+            pan_orbit.apply_input(&input);
+            time_since_input.0 = 0.0;
+        }
+        *input = CameraInput::default();
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use crate::input::{camera_window, viewport_cursor_position, MouseKeyTracker};
+use crate::PanOrbitCamera;
+
+/// Configuration for RTS style panning, where moving the cursor near the edge of the viewport
+/// pans the camera in that direction. Panning speeds up the closer the cursor gets to the edge,
+/// and only happens while the window is focused and no mouse drag is in progress.
+///
+/// The focus moves parallel to the screen, or along the plane of a `PanMode::Plane`, in which
+/// case the top and bottom edges move it forward and back across the plane.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgePan {
+    /// How close to the edge of the viewport the cursor has to be to start panning, in logical
+    /// pixels.
+    /// Defaults to `20.0`.
+    pub margin: f32,
+    /// Pan speed with the cursor right at the edge, in multiples of the radius per second.
+    /// Defaults to `1.0`.
+    pub speed: f64,
+}
+
+impl Default for EdgePan {
+    fn default() -> Self {
+        Self {
+            margin: 20.0,
+            speed: 1.0,
+        }
+    }
+}
+
+/// Stores the edge panning direction of each camera for this frame
+#[derive(Resource, Default, Debug)]
+pub struct EdgePanTracker {
+    /// How strongly each camera should pan, from `-1.0` to `1.0` on each axis, with x to the
+    /// right and y up. Cameras that shouldn't pan are left out.
+    pub directions: HashMap<Entity, Vec2>,
+}
+
+/// Work out how each hovered camera with `PanOrbitCamera::edge_pan` should pan, from how close
+/// the cursor is to the edges of its viewport
+pub fn edge_pan_tracker(
+    mut tracker: ResMut<EdgePanTracker>,
+    mouse_key_tracker: Res<MouseKeyTracker>,
+    orbit_cameras: Query<(Entity, &PanOrbitCamera, &Camera)>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+) {
+    tracker.directions.clear();
+    if mouse_key_tracker.drag.is_some() {
+        return;
+    }
+    for (entity, pan_orbit, camera) in orbit_cameras.iter() {
+        let Some(edge_pan) = pan_orbit.edge_pan.filter(|_| pan_orbit.hovered) else {
+            continue;
+        };
+        let focused = camera_window(
+            camera,
+            pan_orbit.input_region.as_ref(),
+            &primary_windows,
+            &other_windows,
+        )
+        .is_some_and(|window| window.focused);
+        let (Some(size), Some(cursor), true) = (
+            camera.logical_viewport_size(),
+            viewport_cursor_position(
+                camera,
+                pan_orbit.input_region.as_ref(),
+                &primary_windows,
+                &other_windows,
+            ),
+            focused,
+        ) else {
+            continue;
+        };
+        // How far into the margin the cursor is, from 0 at its inner edge to 1 at the viewport's
+        // edge
+        let ramp = |distance: f32| match edge_pan.margin > 0.0 {
+            true => (1.0 - distance / edge_pan.margin).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        let direction = Vec2::new(
+            ramp(size.x - cursor.x) - ramp(cursor.x),
+            ramp(cursor.y) - ramp(size.y - cursor.y),
+        );
+        if direction != Vec2::ZERO {
+            tracker.directions.insert(entity, direction);
+        }
+    }
+}
@@ -1,4 +1,9 @@
 use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::window::PrimaryWindow;
+use bevy_egui::egui;
+
+use crate::{PanOrbitCamera, ViewCommand, ViewMenuItem};
 
 /// A resource that tracks whether egui wants focus on the current and previous frames,
 /// in order to determine whether PanOrbitCamera should react to input events.
@@ -10,7 +15,7 @@ use bevy::prelude::*;
 ///
 /// This is re-exported in case it's useful. I recommend only using input events if both
 /// `prev` and `curr` are false.
-#[derive(Resource, PartialEq, Eq, Default)]
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub struct EguiWantsFocus {
     /// Whether egui wanted focus on the previous frame
     pub prev: bool,
@@ -28,30 +33,130 @@ pub struct EguiWantsFocus {
 #[derive(Resource, PartialEq, Eq, Default)]
 pub struct EguiFocusIncludesHover(pub bool);
 
+/// Per-camera control over when egui blocks a `PanOrbitCamera`'s input. Add this alongside
+/// `PanOrbitCamera`. Requires the `bevy_egui` feature.
+///
+/// Without this, egui blocks every camera whenever any window's egui context wants input (see
+/// `EguiWantsFocus`). With it, only the egui context of the camera's own window is checked, and
+/// `filter` can replace the check entirely, e.g. to let input through transparent panels, or to
+/// only block when a particular area is hovered. This is useful for editors with several
+/// viewports and docked panels.
+/// ```
+/// # use bevy_egui::egui;
+/// # use bevy_panorbit_camera::PanOrbitEguiBlocking;
+/// // Only block while the pointer is over the "Inspector" window
+/// fn over_inspector(ctx: &egui::Context) -> bool {
+///     ctx.pointer_latest_pos()
+///         .and_then(|pos| ctx.layer_id_at(pos))
+///         .is_some_and(|layer| layer.id == egui::Id::new("Inspector"))
+/// }
+///
+/// let blocking = PanOrbitEguiBlocking::with_filter(over_inspector);
+/// ```
+#[derive(Component, Copy, Clone, Debug)]
+pub struct PanOrbitEguiBlocking {
+    /// Whether egui can block this camera's input at all.
+    /// Defaults to `true`.
+    pub enabled: bool,
+    /// Decides whether egui is using the input, given the egui context of the camera's window.
+    /// Return `true` to block the camera. `None` blocks when egui wants pointer or keyboard input,
+    /// or when the pointer is over an egui area if `EguiFocusIncludesHover` is set.
+    /// Defaults to `None`.
+    pub filter: Option<fn(&egui::Context) -> bool>,
+    /// Whether egui wants focus for this camera, on the current and previous frames
+    wants_focus: EguiWantsFocus,
+}
+
+impl Default for PanOrbitEguiBlocking {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            filter: None,
+            wants_focus: EguiWantsFocus::default(),
+        }
+    }
+}
+
+impl PanOrbitEguiBlocking {
+    /// Block the camera whenever `filter` returns `true`.
+    pub fn with_filter(filter: fn(&egui::Context) -> bool) -> Self {
+        Self {
+            filter: Some(filter),
+            ..default()
+        }
+    }
+
+    /// Whether egui is currently blocking this camera's input. Like `EguiWantsFocus`, this
+    /// includes the previous frame.
+    pub fn is_blocking(&self) -> bool {
+        self.enabled && (self.wants_focus.prev || self.wants_focus.curr)
+    }
+}
+
+/// Whether egui wants the input, by default
+fn egui_wants_input(ctx: &egui::Context, include_hover: bool) -> bool {
+    ctx.wants_pointer_input()
+        || ctx.wants_keyboard_input()
+        || (include_hover && ctx.is_pointer_over_area())
+}
+
 pub fn check_egui_wants_focus(
     mut contexts: bevy_egui::EguiContexts,
     mut wants_focus: ResMut<EguiWantsFocus>,
     include_hover: Res<EguiFocusIncludesHover>,
     windows: Query<Entity, With<Window>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut cameras: Query<(&Camera, &PanOrbitCamera, &mut PanOrbitEguiBlocking)>,
 ) {
     // The window that the user is interacting with and the window that contains the egui context
     // that the user is interacting with are always going to be the same. Therefore, we can assume
     // that if any of the egui contexts want focus, then it must be the one that the user is
     // interacting with.
     let new_wants_focus = windows.iter().any(|window| {
-        if let Some(ctx) = contexts.try_ctx_for_entity_mut(window) {
-            let mut value = ctx.wants_pointer_input() || ctx.wants_keyboard_input();
-            if include_hover.0 {
-                value |= ctx.is_pointer_over_area()
-            }
-            value
-        } else {
-            false
-        }
+        contexts
+            .try_ctx_for_entity_mut(window)
+            .is_some_and(|ctx| egui_wants_input(ctx, include_hover.0))
     });
     let new_res = EguiWantsFocus {
         prev: wants_focus.curr,
         curr: new_wants_focus,
     };
     wants_focus.set_if_neq(new_res);
+
+    // Cameras with their own blocking settings only check the egui context of their window, or
+    // the window their image is shown in
+    let primary_window = primary_window.get_single().ok();
+    for (camera, pan_orbit, mut blocking) in cameras.iter_mut() {
+        let window = match (&pan_orbit.input_region, &camera.target) {
+            (Some(region), _) => region.window.normalize(primary_window),
+            (None, RenderTarget::Window(window_ref)) => window_ref.normalize(primary_window),
+            (None, _) => None,
+        };
+        let curr = window
+            .and_then(|window| contexts.try_ctx_for_entity_mut(window.entity()))
+            .is_some_and(|ctx| match blocking.filter {
+                Some(filter) => filter(ctx),
+                None => egui_wants_input(ctx, include_hover.0),
+            });
+        let new_wants_focus = EguiWantsFocus {
+            prev: blocking.wants_focus.curr,
+            curr,
+        };
+        if blocking.wants_focus != new_wants_focus {
+            blocking.wants_focus = new_wants_focus;
+        }
+    }
+}
+
+/// Show a button for each of `items`, and return the command of the one that was clicked, if any.
+/// A simple default for showing `PanOrbitCamera::view_menu` with egui. The buttons follow `ui`'s
+/// layout, so wrap this in e.g. `ui.horizontal` for a toolbar. Requires the `bevy_egui` feature.
+pub fn view_menu_ui(ui: &mut egui::Ui, items: &[ViewMenuItem]) -> Option<ViewCommand> {
+    let mut clicked = None;
+    for item in items {
+        if ui.button(&item.label).clicked() {
+            clicked = Some(item.command.clone());
+        }
+    }
+    clicked
 }
@@ -0,0 +1,183 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{CameraGesture, PanOrbitCamera, StandardView};
+
+/// Sent when the user starts orbiting a `PanOrbitCamera`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct OrbitStarted {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The target yaw when the orbit started.
+    pub yaw: f64,
+    /// The target pitch when the orbit started.
+    pub pitch: f64,
+}
+
+/// Sent when the user stops orbiting a `PanOrbitCamera`. The camera may still be moving towards
+/// the new values due to smoothing or momentum, see `CameraIdle`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct OrbitEnded {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The target yaw when the orbit started.
+    pub old_yaw: f64,
+    /// The target pitch when the orbit started.
+    pub old_pitch: f64,
+    /// The target yaw when the orbit ended.
+    pub new_yaw: f64,
+    /// The target pitch when the orbit ended.
+    pub new_pitch: f64,
+}
+
+/// Sent when the user starts panning a `PanOrbitCamera`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PanStarted {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The target focus when the pan started.
+    pub focus: DVec3,
+}
+
+/// Sent when the user stops panning a `PanOrbitCamera`. The camera may still be moving towards
+/// the new focus due to smoothing or momentum, see `CameraIdle`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PanEnded {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The target focus when the pan started.
+    pub old_focus: DVec3,
+    /// The target focus when the pan ended.
+    pub new_focus: DVec3,
+}
+
+/// Sent when a `PanOrbitCamera`'s target radius changes, whether from user input or code.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct ZoomChanged {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The target radius last frame.
+    pub old_radius: f64,
+    /// The new target radius.
+    pub new_radius: f64,
+}
+
+/// Sent once a `PanOrbitCamera` comes to rest, i.e. the user isn't controlling it and its
+/// transform has stopped changing, after smoothing, momentum, and animations have finished.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraIdle {
+    /// The camera entity.
+    pub entity: Entity,
+}
+
+/// Sent when a `PanOrbitCamera` with `sticky_views` snaps onto an axis-aligned view. The camera
+/// may still be turning onto the view due to smoothing.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct SnappedToView {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The view the camera snapped onto.
+    pub view: StandardView,
+}
+
+/// What a camera was doing last frame, used to detect changes
+#[derive(Default)]
+pub struct CameraEventState {
+    gesture: CameraGesture,
+    gesture_start: Option<(f64, f64, DVec3)>,
+    target_radius: Option<f64>,
+    transform: Option<Transform>,
+    moving: bool,
+}
+
+/// Compare each camera with last frame and send events for what changed
+#[allow(clippy::too_many_arguments)]
+pub fn send_camera_events(
+    cameras: Query<(Entity, &PanOrbitCamera, &Transform)>,
+    mut states: Local<HashMap<Entity, CameraEventState>>,
+    mut orbit_started: EventWriter<OrbitStarted>,
+    mut orbit_ended: EventWriter<OrbitEnded>,
+    mut pan_started: EventWriter<PanStarted>,
+    mut pan_ended: EventWriter<PanEnded>,
+    mut zoom_changed: EventWriter<ZoomChanged>,
+    mut camera_idle: EventWriter<CameraIdle>,
+) {
+    states.retain(|entity, _| cameras.contains(*entity));
+    for (entity, pan_orbit, transform) in cameras.iter() {
+        if !pan_orbit.initialized {
+            continue;
+        }
+        let state = states.entry(entity).or_default();
+
+        if pan_orbit.gesture != state.gesture {
+            let (old_yaw, old_pitch, old_focus) = state.gesture_start.take().unwrap_or((
+                pan_orbit.target_yaw,
+                pan_orbit.target_pitch,
+                pan_orbit.target_focus,
+            ));
+            match state.gesture {
+                CameraGesture::Orbit => {
+                    orbit_ended.send(OrbitEnded {
+                        entity,
+                        old_yaw,
+                        old_pitch,
+                        new_yaw: pan_orbit.target_yaw,
+                        new_pitch: pan_orbit.target_pitch,
+                    });
+                }
+                CameraGesture::Pan => {
+                    pan_ended.send(PanEnded {
+                        entity,
+                        old_focus,
+                        new_focus: pan_orbit.target_focus,
+                    });
+                }
+                CameraGesture::None | CameraGesture::Zoom => {}
+            }
+            match pan_orbit.gesture {
+                CameraGesture::Orbit => {
+                    orbit_started.send(OrbitStarted {
+                        entity,
+                        yaw: pan_orbit.target_yaw,
+                        pitch: pan_orbit.target_pitch,
+                    });
+                }
+                CameraGesture::Pan => {
+                    pan_started.send(PanStarted {
+                        entity,
+                        focus: pan_orbit.target_focus,
+                    });
+                }
+                CameraGesture::None | CameraGesture::Zoom => {}
+            }
+            state.gesture = pan_orbit.gesture;
+            state.gesture_start = Some((
+                pan_orbit.target_yaw,
+                pan_orbit.target_pitch,
+                pan_orbit.target_focus,
+            ));
+        }
+
+        if let Some(old_radius) = state
+            .target_radius
+            .filter(|radius| *radius != pan_orbit.target_radius)
+        {
+            zoom_changed.send(ZoomChanged {
+                entity,
+                old_radius,
+                new_radius: pan_orbit.target_radius,
+            });
+        }
+        state.target_radius = Some(pan_orbit.target_radius);
+
+        let transform_changed = state.transform.is_some_and(|last| last != *transform);
+        state.transform = Some(*transform);
+        if transform_changed || pan_orbit.gesture != CameraGesture::None {
+            state.moving = true;
+        } else if state.moving {
+            state.moving = false;
+            camera_idle.send(CameraIdle { entity });
+        }
+    }
+}
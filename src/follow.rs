@@ -0,0 +1,166 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+
+use crate::clock::CameraTime;
+use crate::{util, PanOrbitCamera};
+
+/// Makes a `PanOrbitCamera`'s focus follow another entity. Add this component to the camera
+/// entity.
+///
+/// Each frame, the camera's focus is moved by however much the target moved since the previous
+/// frame, so the user can still orbit, zoom, and pan while following. Panning offsets the focus
+/// from the target, and the offset is kept as the target moves.
+///
+/// When the component is first added, the camera smoothly moves its focus to the target.
+///
+/// Set `look_ahead` to keep the focus a little ahead of the target in the direction it's moving,
+/// so that vehicles and characters have space in front of them on screen.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitFollow};
+/// fn setup(mut commands: Commands) {
+///     let player = commands.spawn(Transform::default()).id();
+///     commands.spawn((PanOrbitCamera::default(), PanOrbitFollow::new(player)));
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitFollow {
+    /// The entity to follow. Its `GlobalTransform` translation is tracked.
+    pub target: Entity,
+    /// How much smoothing is applied to the target's movement. A value of `0.0` disables
+    /// smoothing, so the camera moves exactly with the target. A value of `1.0` is infinite
+    /// smoothing.
+    /// Defaults to `0.0`.
+    pub smoothness: f64,
+    /// Moves the focus ahead of the target while it's moving. See `LookAhead`.
+    /// Defaults to `None`.
+    pub look_ahead: Option<LookAhead>,
+    /// How far the focus is currently ahead of the target. Updated automatically.
+    /// Defaults to `DVec3::ZERO`.
+    pub look_ahead_offset: DVec3,
+    /// The smoothed position of the target that the camera is currently following. Updated
+    /// automatically.
+    /// Defaults to `None`.
+    pub tracked_position: Option<DVec3>,
+}
+
+impl PanOrbitFollow {
+    /// Follow `target` with no smoothing.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            smoothness: 0.0,
+            look_ahead: None,
+            look_ahead_offset: DVec3::ZERO,
+            tracked_position: None,
+        }
+    }
+
+    /// Set the smoothness of the follow motion.
+    pub fn with_smoothness(mut self, smoothness: f64) -> Self {
+        self.smoothness = smoothness;
+        self
+    }
+
+    /// Keep the focus `distance` ahead of the target while it's moving.
+    pub fn with_look_ahead(mut self, distance: f64) -> Self {
+        self.look_ahead = Some(LookAhead {
+            distance,
+            ..default()
+        });
+        self
+    }
+}
+
+/// Settings for keeping a followed target's direction of travel in view. While the target moves,
+/// the focus eases out to `distance` ahead of it along its velocity, and it eases back onto the
+/// target once it stops.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookAhead {
+    /// How far ahead of the target to put the focus.
+    /// Defaults to `1.0`.
+    pub distance: f64,
+    /// How much smoothing is applied as the focus moves ahead of the target or back onto it, e.g.
+    /// when the target starts, stops, or turns. A value of `0.0` disables smoothing, which makes
+    /// the camera jump. A value of `1.0` is infinite smoothing.
+    /// Defaults to `0.8`.
+    pub smoothness: f64,
+    /// The speed below which the target counts as stationary, in units per second.
+    /// Defaults to `0.1`.
+    pub min_speed: f64,
+}
+
+impl Default for LookAhead {
+    fn default() -> Self {
+        Self {
+            distance: 1.0,
+            smoothness: 0.8,
+            min_speed: 0.1,
+        }
+    }
+}
+
+/// Move the focus of each following camera by the movement of its target
+pub fn follow_target(
+    mut cameras: Query<(&mut PanOrbitCamera, &mut PanOrbitFollow)>,
+    transform_helper: TransformHelper,
+    time: CameraTime,
+) {
+    for (mut pan_orbit, mut follow) in cameras.iter_mut() {
+        // Compute the global transform now rather than reading `GlobalTransform`, which would be
+        // one frame behind because propagation runs after the camera is updated
+        let Ok(target_transform) = transform_helper.compute_global_transform(follow.target) else {
+            continue;
+        };
+        let target_position = pan_orbit.from_transform_space(target_transform.translation());
+
+        let Some(tracked_position) = follow.tracked_position else {
+            follow.tracked_position = Some(target_position);
+            pan_orbit.target_focus = target_position;
+            if !pan_orbit.initialized {
+                pan_orbit.focus = target_position;
+            }
+            continue;
+        };
+
+        let dt = time.delta_secs_f64();
+        let new_tracked_position =
+            util::lerp_and_snap_dvec3(tracked_position, target_position, follow.smoothness, dt);
+        let mut delta = new_tracked_position - tracked_position;
+        follow.tracked_position = Some(new_tracked_position);
+
+        if let Some(look_ahead) = follow.look_ahead.filter(|_| dt > 0.0) {
+            let velocity = delta / dt;
+            let target_offset = if velocity.length() > look_ahead.min_speed {
+                velocity.normalize() * look_ahead.distance
+            } else {
+                DVec3::ZERO
+            };
+            let offset = util::lerp_and_snap_dvec3(
+                follow.look_ahead_offset,
+                target_offset,
+                look_ahead.smoothness,
+                dt,
+            );
+            delta += offset - follow.look_ahead_offset;
+            follow.look_ahead_offset = offset;
+        } else if follow.look_ahead.is_none() && follow.look_ahead_offset != DVec3::ZERO {
+            // Look ahead was turned off, so move back onto the target
+            delta -= follow.look_ahead_offset;
+            follow.look_ahead_offset = DVec3::ZERO;
+        }
+
+        if delta != DVec3::ZERO {
+            // Move both current and target focus so the camera follows rigidly, rather than
+            // lagging behind by `pan_smoothness`
+            pan_orbit.focus += delta;
+            pan_orbit.target_focus += delta;
+            pan_orbit.force_update = true;
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+
+use crate::PanOrbitCamera;
+
+/// Frames the whole scene once its meshes have loaded. The camera's focus is moved to the centre
+/// of the scene's bounds and its radius is set so that the bounds fill the view, keeping the
+/// current yaw and pitch. The framed view also becomes the camera's `home_view`. Set the camera's
+/// `framing_fit` to choose whether the bounds fit the viewport's width, height, or both, and its
+/// `framing_insets` to keep them clear of UI.
+///
+/// Add this alongside `PanOrbitCamera`. It is removed once the scene has been framed, so add it
+/// again to re-frame, e.g. after loading a different model.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitAutoFrame {
+    /// Extra space around the scene, as a fraction of its size.
+    /// Defaults to `0.1`.
+    pub padding: f64,
+    /// If set, `zoom_lower_limit` and `zoom_upper_limit` are set to these multiples of the
+    /// framed radius, so that zooming is proportional to the size of the scene.
+    /// Defaults to `Some((0.01, 10.0))`.
+    pub zoom_limit_factors: Option<(f64, f64)>,
+    /// The scene's bounds last frame. The scene is only framed once its bounds stop changing.
+    last_bounds: Option<(Vec3, Vec3)>,
+}
+
+impl Default for PanOrbitAutoFrame {
+    fn default() -> Self {
+        Self {
+            padding: 0.1,
+            zoom_limit_factors: Some((0.01, 10.0)),
+            last_bounds: None,
+        }
+    }
+}
+
+/// Frame cameras with `PanOrbitAutoFrame` once all meshes have loaded and their bounds have been
+/// calculated
+pub fn auto_frame(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &mut PanOrbitCamera,
+        &mut PanOrbitAutoFrame,
+        &Camera,
+        &Projection,
+    )>,
+    mesh_bounds: Query<(&Aabb, &GlobalTransform), With<Mesh3d>>,
+    all_meshes: Query<&Mesh3d>,
+    meshes: Option<Res<Assets<Mesh>>>,
+) {
+    // Without mesh assets, e.g. in a headless app, there's nothing to frame
+    let Some(meshes) = meshes else {
+        return;
+    };
+    if cameras.is_empty() {
+        return;
+    }
+    // Wait until every mesh has loaded and has bounds
+    let loaded = all_meshes.iter().all(|mesh| meshes.contains(&mesh.0));
+    if !loaded || all_meshes.iter().len() != mesh_bounds.iter().len() {
+        return;
+    }
+    let Some(bounds) = mesh_bounds
+        .iter()
+        .map(|(aabb, transform)| world_bounds(aabb, transform))
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    else {
+        return;
+    };
+
+    for (entity, mut pan_orbit, mut auto_frame, camera, projection) in cameras.iter_mut() {
+        // Wait for the scene to stop changing, and for the camera to be initialised
+        let settled = auto_frame.last_bounds == Some(bounds);
+        auto_frame.last_bounds = Some(bounds);
+        if !settled || !pan_orbit.initialized {
+            continue;
+        }
+        let (min, max) = bounds;
+        let center = pan_orbit.from_transform_space((min + max) / 2.0);
+        let sphere_radius = ((max - min) / 2.0).length() as f64 * (1.0 + auto_frame.padding);
+        let Some(pose) = pan_orbit.framing_pose(center, sphere_radius, camera, projection) else {
+            continue;
+        };
+        if let Some((lower, upper)) = auto_frame.zoom_limit_factors {
+            pan_orbit.zoom_lower_limit = pose.radius * lower;
+            pan_orbit.zoom_upper_limit = Some(pose.radius * upper);
+        }
+        pan_orbit.set_target_pose(pose);
+        pan_orbit.focus = pose.focus;
+        pan_orbit.radius = Some(pose.radius);
+        pan_orbit.home_view = Some(pose);
+        pan_orbit.force_update = true;
+        commands.entity(entity).remove::<PanOrbitAutoFrame>();
+    }
+}
+
+/// The minimum and maximum corners of a mesh's bounding box once it's been transformed into world
+/// space
+pub fn world_bounds(aabb: &Aabb, transform: &GlobalTransform) -> (Vec3, Vec3) {
+    let center = transform.transform_point(aabb.center.into());
+    let matrix = transform.affine().matrix3;
+    let half_extents = Vec3::from(aabb.half_extents);
+    let half_extents = Vec3::from(
+        matrix.x_axis.abs() * half_extents.x
+            + matrix.y_axis.abs() * half_extents.y
+            + matrix.z_axis.abs() * half_extents.z,
+    );
+    (center - half_extents, center + half_extents)
+}
@@ -0,0 +1,66 @@
+use bevy::input::gamepad::{Gamepad, GamepadButton};
+use bevy::math::DVec2;
+use bevy::prelude::*;
+
+/// Configuration for controlling a `PanOrbitCamera` with a gamepad:
+///  - Right stick: orbit
+///  - Left stick: pan (or move, in `ControlMode::Fly`)
+///  - Right trigger: zoom in
+///  - Left trigger: zoom out
+///
+/// Sticks and triggers control the speed of the camera rather than moving it by a fixed amount,
+/// and the camera's smoothing settings still apply. All connected gamepads are used.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadControls {
+    /// Orbit speed in radians per second at full stick deflection, for the horizontal (x) and
+    /// vertical (y) axes. Use negative values to invert an axis.
+    /// Defaults to `(PI, PI / 2.0)`.
+    pub orbit_sensitivity: DVec2,
+    /// Pan speed at full stick deflection, in multiples of the radius per second, for the
+    /// horizontal (x) and vertical (y) axes. Use negative values to invert an axis.
+    /// Defaults to `(1.0, 1.0)`.
+    pub pan_sensitivity: DVec2,
+    /// Zoom speed at full trigger pressure, in multiples of the radius per second.
+    /// Defaults to `1.5`.
+    pub zoom_sensitivity: f64,
+    /// Stick deflection below which input is ignored, from `0.0` to `1.0`. Input above the dead
+    /// zone is rescaled so that movement starts smoothly from zero.
+    /// Defaults to `0.15`.
+    pub dead_zone: f32,
+}
+
+impl Default for GamepadControls {
+    fn default() -> Self {
+        Self {
+            orbit_sensitivity: DVec2::new(std::f64::consts::PI, std::f64::consts::PI / 2.0),
+            pan_sensitivity: DVec2::ONE,
+            zoom_sensitivity: 1.5,
+            dead_zone: 0.15,
+        }
+    }
+}
+
+/// Stores the combined state of all connected gamepads for this frame
+#[derive(Resource, Default, Debug)]
+pub struct GamepadTracker {
+    /// Right stick
+    pub orbit: Vec2,
+    /// Left stick
+    pub pan: Vec2,
+    /// Right trigger minus left trigger, so positive values zoom in
+    pub zoom: f32,
+}
+
+/// Read gamepad input and save it in GamepadTracker resource for easy consumption by the main
+/// system
+pub fn gamepad_tracker(gamepads: Query<&Gamepad>, mut gamepad_tracker: ResMut<GamepadTracker>) {
+    let mut tracker = GamepadTracker::default();
+    for gamepad in gamepads.iter() {
+        tracker.orbit += gamepad.right_stick();
+        tracker.pan += gamepad.left_stick();
+        tracker.zoom += gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.0)
+            - gamepad.get(GamepadButton::LeftTrigger2).unwrap_or(0.0);
+    }
+    *gamepad_tracker = tracker;
+}
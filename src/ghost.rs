@@ -0,0 +1,166 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::{util, ActiveCameraData, CameraPose, PanOrbitCamera, PanOrbitGizmoConfigGroup};
+
+/// Another user's camera in a multi-user session, drawn as a frustum with a line to the point it
+/// orbits around. Requires the `bevy_gizmos` feature.
+///
+/// Spawn one of these for each remote user, and keep `pose` up to date with whatever your
+/// networking layer receives, e.g. each user sending `PanOrbitGhost::from_camera` for their own
+/// camera. Use `jump_to` to take another user's view. `pose` is in world space, and ghosts are
+/// drawn shifted by the `world_offset` of the active `PanOrbitCamera`, or of any camera if none
+/// is active, like the camera's own `Transform`.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitGhost};
+/// // Take the view of the first other user when G is pressed
+/// fn jump_to_ghost(
+///     key_input: Res<ButtonInput<KeyCode>>,
+///     mut cameras: Query<&mut PanOrbitCamera>,
+///     ghosts: Query<&PanOrbitGhost>,
+/// ) {
+///     if !key_input.just_pressed(KeyCode::KeyG) {
+///         return;
+///     }
+///     if let (Ok(mut pan_orbit), Some(ghost)) = (cameras.get_single_mut(), ghosts.iter().next()) {
+///         ghost.jump_to(&mut pan_orbit, true);
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitGhost {
+    /// The other camera's view.
+    pub pose: CameraPose,
+    /// The other camera's `axis`, which `pose` is relative to.
+    /// Defaults to `[DVec3::X, DVec3::Y, DVec3::Z]`.
+    pub axis: [DVec3; 3],
+    /// The other camera's vertical field of view, in radians.
+    /// Defaults to `PI / 4.0`.
+    pub fov: f32,
+    /// The other camera's aspect ratio (width / height).
+    /// Defaults to `16.0 / 9.0`.
+    pub aspect_ratio: f32,
+    /// How far the drawn frustum reaches out from the camera, in world units.
+    /// Defaults to `0.5`.
+    pub size: f32,
+    /// The color the frustum is drawn in, e.g. to tell users apart.
+    /// Defaults to `Color::WHITE`.
+    pub color: Color,
+}
+
+impl Default for PanOrbitGhost {
+    fn default() -> Self {
+        Self {
+            pose: CameraPose::default(),
+            axis: [DVec3::X, DVec3::Y, DVec3::Z],
+            fov: FRAC_PI_4,
+            aspect_ratio: 16.0 / 9.0,
+            size: 0.5,
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl PanOrbitGhost {
+    /// A ghost of `pan_orbit`, for sending to other users. Uses the view the camera is moving
+    /// towards, and the field of view and aspect ratio of `projection` if it's a perspective
+    /// projection. Other settings are left at their defaults.
+    pub fn from_camera(pan_orbit: &PanOrbitCamera, projection: &Projection) -> Self {
+        let mut ghost = Self {
+            pose: pan_orbit.target_pose(),
+            axis: pan_orbit.orbit_axis(),
+            ..default()
+        };
+        if let Projection::Perspective(perspective) = projection {
+            ghost.fov = perspective.fov;
+            ghost.aspect_ratio = perspective.aspect_ratio;
+        }
+        ghost
+    }
+
+    /// Set the color the frustum is drawn in.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Where the other camera is.
+    pub fn position(&self) -> DVec3 {
+        let pose = &self.pose;
+        pose.focus + util::orbit_offset(pose.yaw, pose.pitch, self.axis) * pose.radius
+    }
+
+    /// Move `pan_orbit` to this ghost's view. If `animated` is `true`, the camera blends there
+    /// using the transition settings in its `saved_views`, otherwise it jumps straight there.
+    /// Works even if the cameras use different axes.
+    pub fn jump_to(&self, pan_orbit: &mut PanOrbitCamera, animated: bool) {
+        let mut pose = self.pose;
+        if self.axis != pan_orbit.orbit_axis() {
+            (pose.yaw, pose.pitch, pose.radius) = util::calculate_from_translation_and_focus(
+                self.position(),
+                pose.focus,
+                pan_orbit.orbit_axis(),
+            );
+        }
+        if animated {
+            pan_orbit.transition_to(pose);
+        } else {
+            pan_orbit.animation = None;
+            pan_orbit.move_to_pose(pose, false);
+        }
+    }
+}
+
+/// Draw the frustum of each `PanOrbitGhost`
+pub fn draw_ghosts(
+    ghosts: Query<&PanOrbitGhost>,
+    cameras: Query<&PanOrbitCamera>,
+    active_cam: Res<ActiveCameraData>,
+    mut gizmos: Gizmos<PanOrbitGizmoConfigGroup>,
+) {
+    // Gizmos are drawn in the same space as the cameras' transforms
+    let Some(viewer) = active_cam
+        .entity
+        .and_then(|entity| cameras.get(entity).ok())
+        .or_else(|| cameras.iter().next())
+    else {
+        return;
+    };
+    for ghost in ghosts.iter() {
+        let pose = &ghost.pose;
+        let position = viewer.to_transform_space(ghost.position()).as_vec3();
+        let rotation = pose.rotation(ghost.axis).as_quat();
+        let half_height = ghost.size * (ghost.fov * 0.5).tan();
+        let half_width = half_height * ghost.aspect_ratio;
+        let corner = |x: f32, y: f32| {
+            position + rotation * Vec3::new(x * half_width, y * half_height, -ghost.size)
+        };
+        let corners = [
+            corner(-1.0, -1.0),
+            corner(1.0, -1.0),
+            corner(1.0, 1.0),
+            corner(-1.0, 1.0),
+        ];
+        for corner in corners {
+            gizmos.line(position, corner, ghost.color);
+        }
+        gizmos.linestrip(corners.into_iter().chain([corners[0]]), ghost.color);
+        // A triangle above the top edge shows which way is up
+        gizmos.linestrip(
+            [
+                corner(-0.5, 1.1),
+                corner(0.0, 1.5),
+                corner(0.5, 1.1),
+                corner(-0.5, 1.1),
+            ],
+            ghost.color,
+        );
+        let focus = viewer.to_transform_space(pose.focus).as_vec3();
+        gizmos.line(position, focus, ghost.color.with_alpha(0.3));
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::{util, FocusBoundsShape, PanOrbitCamera};
+
+/// Debug visuals for a `PanOrbitCamera`'s state. Add this alongside `PanOrbitCamera` to choose
+/// which visuals are drawn for that camera. Requires the `bevy_gizmos` feature.
+///
+/// The visuals are drawn with `PanOrbitGizmoConfigGroup`, so they can be limited to certain
+/// cameras with render layers. The config group is global, so its render layers apply to the
+/// visuals of every `PanOrbitCamera`, not just one. For example, to only show them in a debug
+/// camera:
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::view::RenderLayers;
+/// # use bevy_panorbit_camera::PanOrbitGizmoConfigGroup;
+/// fn setup(mut commands: Commands, mut config_store: ResMut<GizmoConfigStore>) {
+///     let (config, _) = config_store.config_mut::<PanOrbitGizmoConfigGroup>();
+///     config.render_layers = RenderLayers::layer(1);
+///     // The debug camera sees both the scene and the visuals, while other cameras only see the
+///     // default layer
+///     commands.spawn((Camera3d::default(), RenderLayers::from_layers(&[0, 1])));
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitGizmos {
+    /// Draw a marker at the focus.
+    /// Defaults to `true`.
+    pub focus: bool,
+    /// Draw the yaw and pitch limits as arcs around the focus, and the focus bounds.
+    /// Defaults to `true`.
+    pub limits: bool,
+    /// Draw a grid through the focus, perpendicular to the up axis.
+    /// Defaults to `false`.
+    pub grid: bool,
+}
+
+impl Default for PanOrbitGizmos {
+    fn default() -> Self {
+        Self {
+            focus: true,
+            limits: true,
+            grid: false,
+        }
+    }
+}
+
+/// The gizmo config group used to draw `PanOrbitGizmos`. Its settings, including render layers,
+/// are shared by the visuals of every camera.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct PanOrbitGizmoConfigGroup;
+
+const FOCUS_COLOR: Color = Color::WHITE;
+const LIMITS_COLOR: Color = Color::srgb(1.0, 0.6, 0.1);
+const GRID_COLOR: Color = Color::srgba(0.6, 0.6, 0.6, 0.5);
+
+/// Number of segments used to draw limit arcs
+const ARC_SEGMENTS: usize = 32;
+
+/// Number of grid cells along each side
+const GRID_CELLS: u32 = 20;
+
+/// Draw the enabled visuals for each camera with `PanOrbitGizmos`
+pub fn draw_gizmos(
+    cameras: Query<(&PanOrbitCamera, &PanOrbitGizmos)>,
+    mut gizmos: Gizmos<PanOrbitGizmoConfigGroup>,
+) {
+    for (pan_orbit, visuals) in cameras.iter() {
+        let (Some(yaw), Some(pitch), Some(radius)) =
+            (pan_orbit.yaw, pan_orbit.pitch, pan_orbit.radius)
+        else {
+            continue;
+        };
+        // Gizmos are drawn in the same space as the camera's transform
+        let focus = pan_orbit.to_transform_space(pan_orbit.focus);
+        let axis = pan_orbit.orbit_axis();
+        let up = axis[1].as_vec3();
+
+        if visuals.focus {
+            let rotation = Quat::from_rotation_arc(Vec3::Y, up);
+            let isometry = Isometry3d::new(focus.as_vec3(), rotation);
+            gizmos.sphere(isometry, (radius * 0.02) as f32, FOCUS_COLOR);
+        }
+
+        if visuals.limits {
+            // Arcs are drawn at half the radius, so they're in front of the camera
+            let arc_radius = radius * 0.5;
+            let mut arc = |from: f64, to: f64, point: &dyn Fn(f64) -> DVec3| {
+                let points = (0..=ARC_SEGMENTS).map(|i| {
+                    let angle = from + (to - from) * i as f64 / ARC_SEGMENTS as f64;
+                    (focus + point(angle) * arc_radius).as_vec3()
+                });
+                gizmos.linestrip(points, LIMITS_COLOR);
+            };
+            if let (Some(lower), Some(upper)) =
+                (pan_orbit.yaw_lower_limit, pan_orbit.yaw_upper_limit)
+            {
+                arc(lower, upper, &|yaw| util::orbit_offset(yaw, pitch, axis));
+            }
+            if let (Some(lower), Some(upper)) =
+                (pan_orbit.pitch_lower_limit, pan_orbit.pitch_upper_limit)
+            {
+                arc(lower, upper, &|pitch| util::orbit_offset(yaw, pitch, axis));
+            }
+            if let Some(shape) = pan_orbit.focus_bounds_shape {
+                let origin = pan_orbit
+                    .to_transform_space(pan_orbit.focus_bounds_origin)
+                    .as_vec3();
+                match shape {
+                    FocusBoundsShape::Sphere(sphere) => {
+                        gizmos.sphere(
+                            Isometry3d::from_translation(origin),
+                            sphere.radius,
+                            LIMITS_COLOR,
+                        );
+                    }
+                    FocusBoundsShape::Cuboid(cuboid) => {
+                        let transform =
+                            Transform::from_translation(origin).with_scale(cuboid.half_size * 2.0);
+                        gizmos.cuboid(transform, LIMITS_COLOR);
+                    }
+                }
+            }
+        }
+
+        if visuals.grid {
+            // Keep the grid a sensible size at any zoom level by snapping its spacing to a power
+            // of ten
+            let spacing = 10f64.powf((radius / 5.0).log10().floor()) as f32;
+            let rotation = Quat::from_rotation_arc(Vec3::Z, up);
+            gizmos.grid(
+                Isometry3d::new(focus.as_vec3(), rotation),
+                UVec2::splat(GRID_CELLS),
+                Vec2::splat(spacing),
+                GRID_COLOR,
+            );
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+
+use crate::{FocusBoundsShape, PanOrbitCamera};
+
+/// How much freedom the user has while a `PanOrbitCamera` is in guided mode, e.g. during a
+/// tutorial or scripted reveal. See `PanOrbitCamera::enter_guided_mode`.
+///
+/// While an animation plays (from `PanOrbitCamera::animate_to` or a `NavigationScript`), the user
+/// can't move the camera at all, so they can't interrupt it. In between, they can look around
+/// the view the animation ended at, within the ranges given here.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuidedMode {
+    /// How far the user can orbit either side of the guided view's yaw, in radians. `None` keeps
+    /// the camera's own yaw limits.
+    /// Defaults to `Some(0.3)`.
+    pub yaw_range: Option<f64>,
+    /// How far the user can orbit above or below the guided view's pitch, in radians. `None`
+    /// keeps the camera's own pitch limits.
+    /// Defaults to `Some(0.15)`.
+    pub pitch_range: Option<f64>,
+    /// Whether the user can zoom.
+    /// Defaults to `false`.
+    pub zoom: bool,
+    /// Whether the user can pan.
+    /// Defaults to `false`.
+    pub pan: bool,
+    /// If set, black bars are added to the camera's viewport so it has this aspect ratio (width /
+    /// height), e.g. `2.39` for a cinematic widescreen look.
+    /// Defaults to `None`.
+    pub letterbox: Option<f32>,
+}
+
+impl Default for GuidedMode {
+    fn default() -> Self {
+        Self {
+            yaw_range: Some(0.3),
+            pitch_range: Some(0.15),
+            zoom: false,
+            pan: false,
+            letterbox: None,
+        }
+    }
+}
+
+/// Whether a `PanOrbitCamera` is in guided mode. Use `PanOrbitCamera::enter_guided_mode` and
+/// `PanOrbitCamera::exit_guided_mode` rather than setting this directly.
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GuidedState {
+    /// The camera has its usual freedom.
+    #[default]
+    Inactive,
+    /// The user's control is restricted.
+    Active {
+        /// The restrictions.
+        mode: GuidedMode,
+        /// The camera's settings before entering guided mode. Captured automatically on the first
+        /// frame.
+        freedom: Option<CameraFreedom>,
+        /// Whether the limits are currently tightened around the guided view. They're loosened
+        /// while an animation plays, so it isn't held back by them.
+        restricted: bool,
+    },
+    /// Guided mode is ending, and the camera's previous settings will be restored on the next
+    /// update.
+    Exiting {
+        /// The settings to restore.
+        freedom: Option<CameraFreedom>,
+    },
+}
+
+/// The settings that guided mode changes, kept so they can be restored afterwards.
+#[derive(Reflect, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraFreedom {
+    /// The camera's `yaw_upper_limit`.
+    pub yaw_upper_limit: Option<f64>,
+    /// The camera's `yaw_lower_limit`.
+    pub yaw_lower_limit: Option<f64>,
+    /// The camera's `pitch_upper_limit`.
+    pub pitch_upper_limit: Option<f64>,
+    /// The camera's `pitch_lower_limit`.
+    pub pitch_lower_limit: Option<f64>,
+    /// The camera's `zoom_upper_limit`.
+    pub zoom_upper_limit: Option<f64>,
+    /// The camera's `zoom_lower_limit`.
+    pub zoom_lower_limit: f64,
+    /// The camera's `focus_bounds_origin`.
+    pub focus_bounds_origin: DVec3,
+    /// The camera's `focus_bounds_shape`.
+    pub focus_bounds_shape: Option<FocusBoundsShape>,
+    /// The camera's `bound_camera_position`.
+    pub bound_camera_position: bool,
+    /// The `Camera`'s viewport, if it had one.
+    #[cfg_attr(feature = "serialize", serde(with = "viewport_serde"))]
+    pub viewport: Option<Viewport>,
+}
+
+// `Viewport` doesn't implement `PartialEq`
+impl PartialEq for CameraFreedom {
+    fn eq(&self, other: &Self) -> bool {
+        self.yaw_upper_limit == other.yaw_upper_limit
+            && self.yaw_lower_limit == other.yaw_lower_limit
+            && self.pitch_upper_limit == other.pitch_upper_limit
+            && self.pitch_lower_limit == other.pitch_lower_limit
+            && self.zoom_upper_limit == other.zoom_upper_limit
+            && self.zoom_lower_limit == other.zoom_lower_limit
+            && self.focus_bounds_origin == other.focus_bounds_origin
+            && self.focus_bounds_shape == other.focus_bounds_shape
+            && self.bound_camera_position == other.bound_camera_position
+            && viewports_equal(self.viewport.as_ref(), other.viewport.as_ref())
+    }
+}
+
+/// Whether two optional viewports cover the same area and depth range
+fn viewports_equal(a: Option<&Viewport>, b: Option<&Viewport>) -> bool {
+    let parts = |viewport: &Viewport| {
+        (
+            viewport.physical_position,
+            viewport.physical_size,
+            viewport.depth.clone(),
+        )
+    };
+    a.map(parts) == b.map(parts)
+}
+
+// `Viewport` doesn't implement `Serialize`, so it's stored as its position, size, and depth
+#[cfg(feature = "serialize")]
+mod viewport_serde {
+    use std::ops::Range;
+
+    use bevy::prelude::*;
+    use bevy::render::camera::Viewport;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        viewport: &Option<Viewport>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        viewport
+            .as_ref()
+            .map(|viewport| {
+                (
+                    viewport.physical_position,
+                    viewport.physical_size,
+                    viewport.depth.clone(),
+                )
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Viewport>, D::Error> {
+        let parts = Option::<(UVec2, UVec2, Range<f32>)>::deserialize(deserializer)?;
+        Ok(
+            parts.map(|(physical_position, physical_size, depth)| Viewport {
+                physical_position,
+                physical_size,
+                depth,
+            }),
+        )
+    }
+}
+
+impl CameraFreedom {
+    fn capture(pan_orbit: &PanOrbitCamera, camera: &Camera) -> Self {
+        Self {
+            yaw_upper_limit: pan_orbit.yaw_upper_limit,
+            yaw_lower_limit: pan_orbit.yaw_lower_limit,
+            pitch_upper_limit: pan_orbit.pitch_upper_limit,
+            pitch_lower_limit: pan_orbit.pitch_lower_limit,
+            zoom_upper_limit: pan_orbit.zoom_upper_limit,
+            zoom_lower_limit: pan_orbit.zoom_lower_limit,
+            focus_bounds_origin: pan_orbit.focus_bounds_origin,
+            focus_bounds_shape: pan_orbit.focus_bounds_shape,
+            bound_camera_position: pan_orbit.bound_camera_position,
+            viewport: camera.viewport.clone(),
+        }
+    }
+
+    fn restore_limits(&self, pan_orbit: &mut PanOrbitCamera) {
+        pan_orbit.yaw_upper_limit = self.yaw_upper_limit;
+        pan_orbit.yaw_lower_limit = self.yaw_lower_limit;
+        pan_orbit.pitch_upper_limit = self.pitch_upper_limit;
+        pan_orbit.pitch_lower_limit = self.pitch_lower_limit;
+        pan_orbit.zoom_upper_limit = self.zoom_upper_limit;
+        pan_orbit.zoom_lower_limit = self.zoom_lower_limit;
+        pan_orbit.focus_bounds_origin = self.focus_bounds_origin;
+        pan_orbit.focus_bounds_shape = self.focus_bounds_shape;
+        pan_orbit.bound_camera_position = self.bound_camera_position;
+    }
+}
+
+/// Set the camera's viewport, if it's different
+fn set_viewport(camera: &mut Mut<Camera>, viewport: Option<Viewport>) {
+    if !viewports_equal(camera.viewport.as_ref(), viewport.as_ref()) {
+        camera.viewport = viewport;
+    }
+}
+
+/// Tighten the limits of cameras in guided mode around the guided view, loosening them while an
+/// animation plays, and restore the previous settings once guided mode ends
+pub fn update_guided_mode(mut cameras: Query<(&mut PanOrbitCamera, &mut Camera)>) {
+    for (mut pan_orbit, mut camera) in cameras.iter_mut() {
+        match pan_orbit.guided.clone() {
+            GuidedState::Inactive => {}
+            GuidedState::Exiting { freedom } => {
+                if let Some(freedom) = freedom {
+                    freedom.restore_limits(&mut pan_orbit);
+                    set_viewport(&mut camera, freedom.viewport.clone());
+                }
+                pan_orbit.guided = GuidedState::Inactive;
+            }
+            GuidedState::Active {
+                mode,
+                freedom,
+                restricted,
+            } => {
+                let freedom =
+                    freedom.unwrap_or_else(|| CameraFreedom::capture(&pan_orbit, &camera));
+                let viewport = match mode.letterbox {
+                    Some(aspect_ratio) => letterbox(&camera, &freedom, aspect_ratio),
+                    None => freedom.viewport.clone(),
+                };
+                set_viewport(&mut camera, viewport);
+                let playing = pan_orbit.animation.is_some();
+                if playing == restricted {
+                    freedom.restore_limits(&mut pan_orbit);
+                    if !playing {
+                        restrict(&mut pan_orbit, &mode);
+                    }
+                }
+                let state = GuidedState::Active {
+                    mode,
+                    freedom: Some(freedom),
+                    restricted: !playing,
+                };
+                if pan_orbit.guided != state {
+                    pan_orbit.guided = state;
+                }
+            }
+        }
+    }
+}
+
+/// Tighten the camera's limits around the view it's moving towards
+fn restrict(pan_orbit: &mut PanOrbitCamera, mode: &GuidedMode) {
+    let pose = pan_orbit.target_pose();
+    if let Some(range) = mode.yaw_range {
+        pan_orbit.yaw_lower_limit = Some(pose.yaw - range);
+        pan_orbit.yaw_upper_limit = Some(pose.yaw + range);
+    }
+    if let Some(range) = mode.pitch_range {
+        pan_orbit.pitch_lower_limit = Some(pose.pitch - range);
+        pan_orbit.pitch_upper_limit = Some(pose.pitch + range);
+    }
+    if !mode.zoom {
+        pan_orbit.zoom_lower_limit = pose.radius;
+        pan_orbit.zoom_upper_limit = Some(pose.radius);
+    }
+    if !mode.pan {
+        pan_orbit.focus_bounds_origin = pose.focus;
+        pan_orbit.focus_bounds_shape = Some(FocusBoundsShape::Sphere(Sphere::new(0.0)));
+        pan_orbit.bound_camera_position = false;
+    }
+}
+
+/// A viewport with `aspect_ratio`, centred in the area the camera had before guided mode, and
+/// keeping its depth range
+fn letterbox(camera: &Camera, freedom: &CameraFreedom, aspect_ratio: f32) -> Option<Viewport> {
+    let viewport = freedom.viewport.clone().or_else(|| {
+        Some(Viewport {
+            physical_size: camera.physical_target_size()?,
+            ..default()
+        })
+    })?;
+    let size = viewport.physical_size;
+    if size.x == 0 || size.y == 0 || aspect_ratio <= 0.0 {
+        return freedom.viewport.clone();
+    }
+    let fitted = match size.x as f32 / size.y as f32 > aspect_ratio {
+        true => UVec2::new((size.y as f32 * aspect_ratio) as u32, size.y),
+        false => UVec2::new(size.x, (size.x as f32 / aspect_ratio) as u32),
+    };
+    Some(Viewport {
+        physical_position: viewport.physical_position + (size - fitted) / 2,
+        physical_size: fitted,
+        ..viewport
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letterbox_keeps_viewport_depth() {
+        let camera = Camera {
+            viewport: Some(Viewport {
+                physical_position: UVec2::new(10, 0),
+                physical_size: UVec2::new(200, 100),
+                depth: 0.2..0.8,
+            }),
+            ..default()
+        };
+        let freedom = CameraFreedom::capture(&PanOrbitCamera::default(), &camera);
+        let viewport = letterbox(&camera, &freedom, 1.0).unwrap();
+        assert_eq!(viewport.physical_position, UVec2::new(60, 0));
+        assert_eq!(viewport.physical_size, UVec2::new(100, 100));
+        assert_eq!(viewport.depth, 0.2..0.8);
+    }
+}
@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::clock::CameraTime;
+use crate::{util, CameraGesture, PanOrbitCamera, PanOrbitGizmoConfigGroup};
+
+/// Shows where the camera orbits around, like Blender's pivot point, by drawing a marker at the
+/// focus that fades in while the user is orbiting or panning, and fades out once they stop. Add
+/// this alongside `PanOrbitCamera`. Requires the `bevy_gizmos` feature.
+///
+/// The marker is drawn with `PanOrbitGizmoConfigGroup`, so it shares its render layers and line
+/// settings with `PanOrbitGizmos`.
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{IndicatorShape, PanOrbitCamera, PanOrbitPivotIndicator};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         PanOrbitCamera::default(),
+///         PanOrbitPivotIndicator {
+///             shape: IndicatorShape::Crosshair,
+///             color: Color::srgb(1.0, 0.8, 0.2),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitPivotIndicator {
+    /// What the marker looks like.
+    /// Defaults to `IndicatorShape::Sphere`.
+    pub shape: IndicatorShape,
+    /// The color of the marker when it's fully shown. Its alpha is multiplied by the fade.
+    /// Defaults to `Color::WHITE`.
+    pub color: Color,
+    /// The radius of the marker as a proportion of the camera's radius, so it stays the same
+    /// size on screen as the camera zooms. Orthographic cameras use the distance that would show
+    /// as much of the scene in perspective, so switching projections keeps its size.
+    /// Defaults to `0.02`.
+    pub size: f32,
+    /// How long the marker takes to appear once orbiting or panning starts, in seconds.
+    /// Defaults to `0.1`.
+    pub fade_in: f32,
+    /// How long the marker takes to disappear once orbiting or panning ends, in seconds.
+    /// Defaults to `0.5`.
+    pub fade_out: f32,
+}
+
+impl Default for PanOrbitPivotIndicator {
+    fn default() -> Self {
+        Self {
+            shape: IndicatorShape::Sphere,
+            color: Color::WHITE,
+            size: 0.02,
+            fade_in: 0.1,
+            fade_out: 0.5,
+        }
+    }
+}
+
+/// What a `PanOrbitPivotIndicator` looks like.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndicatorShape {
+    /// A wireframe sphere.
+    #[default]
+    Sphere,
+    /// Three lines crossing at the focus, along the camera's axes.
+    Crosshair,
+}
+
+/// Fade each camera's pivot indicator in or out, and draw the ones that are showing
+pub fn draw_pivot_indicators(
+    cameras: Query<(
+        Entity,
+        &PanOrbitCamera,
+        &PanOrbitPivotIndicator,
+        &Projection,
+    )>,
+    mut opacities: Local<HashMap<Entity, f32>>,
+    mut gizmos: Gizmos<PanOrbitGizmoConfigGroup>,
+    time: CameraTime,
+) {
+    opacities.retain(|entity, _| cameras.contains(*entity));
+    let dt = time.delta_secs();
+    for (entity, pan_orbit, indicator, projection) in cameras.iter() {
+        let Some(radius) = pan_orbit.radius else {
+            continue;
+        };
+        let interacting = matches!(pan_orbit.gesture, CameraGesture::Orbit | CameraGesture::Pan);
+        let opacity = opacities.entry(entity).or_default();
+        *opacity = match interacting {
+            true => fade(*opacity, 1.0, dt, indicator.fade_in),
+            false => fade(*opacity, 0.0, dt, indicator.fade_out),
+        };
+        if *opacity <= 0.0 {
+            continue;
+        }
+
+        // Gizmos are drawn in the same space as the camera's transform
+        let focus = pan_orbit.to_transform_space(pan_orbit.focus).as_vec3();
+        // The radius is the scale for orthographic cameras, so track how much of the scene they
+        // show instead
+        let distance = util::pan_distance(radius, projection, pan_orbit.perspective_fov);
+        let size = distance as f32 * indicator.size;
+        let color = indicator
+            .color
+            .with_alpha(indicator.color.alpha() * *opacity);
+        match indicator.shape {
+            IndicatorShape::Sphere => {
+                let up = pan_orbit.axis[1].as_vec3();
+                let rotation = Quat::from_rotation_arc(Vec3::Y, up);
+                gizmos.sphere(Isometry3d::new(focus, rotation), size, color);
+            }
+            IndicatorShape::Crosshair => {
+                for axis in pan_orbit.axis {
+                    let offset = axis.as_vec3() * size;
+                    gizmos.line(focus - offset, focus + offset, color);
+                }
+            }
+        }
+    }
+}
+
+/// Move `opacity` towards `target`, taking `duration` seconds to fade all the way in or out
+fn fade(opacity: f32, target: f32, dt: f32, duration: f32) -> f32 {
+    if duration <= 0.0 {
+        return target;
+    }
+    let step = dt / duration;
+    opacity + (target - opacity).clamp(-step, step)
+}
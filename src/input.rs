@@ -1,18 +1,194 @@
 use bevy::input::gestures::PinchGesture;
 use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::window::{CursorGrabMode, NormalizedWindowRef, PrimaryWindow, WindowFocused, WindowRef};
 
-use crate::{ActiveCameraData, PanOrbitCamera, TrackpadBehavior};
+use crate::clock::CameraTime;
+use crate::{
+    util, ActiveCameraData, ButtonBinding, ControlMode, DragCursor, FocusLossPolicy,
+    GestureConflicts, InputRegion, KeyboardMovement, PanOrbitCamera, PanZoomCamera2d, ResetGesture,
+    ScrollAction, ScrollAxis, TrackpadBehavior, TwoFingerGesture,
+};
+
+/// The input settings of a camera that takes mouse and touch input: a `PanOrbitCamera`, or the
+/// `PanOrbitCamera` inside a `PanZoomCamera2d`. See `input_settings`.
+pub type InputSettings = AnyOf<(&'static PanOrbitCamera, &'static PanZoomCamera2d)>;
+
+/// The `PanOrbitCamera` from an `InputSettings` query item
+pub fn input_settings<'a>(
+    (pan_orbit, pan_zoom): (Option<&'a PanOrbitCamera>, Option<&'a PanZoomCamera2d>),
+) -> &'a PanOrbitCamera {
+    match (pan_orbit, pan_zoom) {
+        (Some(pan_orbit), _) => pan_orbit,
+        (None, Some(pan_zoom)) => pan_zoom,
+        (None, None) => unreachable!("`AnyOf` matches at least one component"),
+    }
+}
 
 #[derive(Resource, Default, Debug)]
 pub struct MouseKeyTracker {
     pub orbit: Vec2,
     pub pan: Vec2,
+    /// Horizontal mouse motion of a roll drag
+    pub roll: f32,
+    /// Mouse motion of a zoom drag, positive to zoom in
+    pub zoom_drag: f32,
+    /// Where the zoom drag in progress started, in viewport coordinates
+    pub zoom_drag_start: Option<Vec2>,
     pub scroll_line: f32,
     pub scroll_pixel: f32,
+    /// Scrolling bound to `ScrollAction::Fov`, in lines, positive to narrow the field of view
+    pub scroll_fov: f32,
     pub orbit_button_changed: bool,
+    /// Cursor position relative to the active camera's viewport, in logical pixels
+    pub cursor_position: Option<Vec2>,
+    /// Whether the camera's `reset_gesture` was performed this frame
+    pub reset_requested: bool,
+    /// The mouse button that was double-clicked this frame, if any
+    pub double_click: Option<MouseButton>,
+    /// Whether any mouse button was pressed this frame
+    pub button_just_pressed: bool,
+    /// Direction to move in `ControlMode::Fly`, in the camera's local space (not normalized)
+    pub fly_direction: Vec3,
+    /// Whether the camera's `key_toggle_fly` was pressed this frame
+    pub toggle_fly: bool,
+    /// Direction of the keyboard orbit keys, with x to the right and y up. These are the keys
+    /// being held when moving smoothly, or the keys pressed this frame when moving in steps.
+    pub key_orbit: Vec2,
+    /// Direction of the keyboard pan keys, like `key_orbit`
+    pub key_pan: Vec2,
+    /// Direction of the keyboard zoom keys, positive to zoom in, like `key_orbit`
+    pub key_zoom: f32,
+    /// Whether the keyboard keys move the camera in steps this frame, rather than smoothly
+    pub key_step: bool,
+    /// How long the keyboard orbit keys have been held, in seconds
+    pub key_orbit_held_for: f32,
+    /// How long the keyboard pan keys have been held, in seconds
+    pub key_pan_held_for: f32,
+    /// The drag gesture in progress, if any
+    pub drag: Option<DragGesture>,
+    /// The rectangle being drawn by a box zoom drag, in viewport coordinates
+    pub box_zoom_rect: Option<Rect>,
+    /// The rectangle of a box zoom drag that finished this frame, in viewport coordinates
+    pub box_zoom: Option<Rect>,
+    /// Whether a window lost focus this frame
+    pub focus_lost: bool,
+    /// Button, time, and cursor position of the last click, used to detect double-clicks
+    last_click: Option<(MouseButton, f64, Vec2)>,
+    /// Where the box zoom drag in progress started, in viewport coordinates
+    box_zoom_start: Option<Vec2>,
+    /// Whether to discard the next mouse motion, after a window regains focus
+    ignore_next_motion: bool,
+    /// Whether a drag was cancelled by the window losing focus, and its button hasn't been
+    /// released yet
+    drag_cancelled: bool,
+    /// Whether trackpad scrolling or pinching is in control, and when its last event arrived,
+    /// when only one is allowed at a time
+    trackpad_gesture: Option<(TwoFingerGesture, f64)>,
+}
+
+impl MouseKeyTracker {
+    /// Decide whether trackpad scrolling or pinching controls the camera this frame, when
+    /// `conflicts` only allows one at a time. The gesture in control keeps it until none of its
+    /// events have arrived for `trackpad_hold_time`.
+    fn trackpad_gesture(
+        &mut self,
+        conflicts: &GestureConflicts,
+        scrolled: bool,
+        pinched: bool,
+        now: f64,
+    ) -> Option<TwoFingerGesture> {
+        let hold_time = conflicts.trackpad_hold_time as f64;
+        let current = self
+            .trackpad_gesture
+            .filter(|(_, last_event)| now - last_event <= hold_time)
+            .map(|(gesture, _)| gesture);
+        let present = [
+            (TwoFingerGesture::Drag, scrolled),
+            (TwoFingerGesture::Pinch, pinched),
+        ]
+        .into_iter()
+        .filter_map(|(gesture, present)| present.then_some(gesture));
+        let gesture = current.or_else(|| conflicts.two_finger_winner(present.clone()))?;
+        if present.clone().any(|other| other == gesture) {
+            self.trackpad_gesture = Some((gesture, now));
+        }
+        Some(gesture)
+    }
+}
+
+/// What a mouse drag is doing. See `PanOrbitInputMap` for the bindings of each action.
+#[derive(Reflect, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragAction {
+    /// Orbit the camera.
+    Orbit,
+    /// Pan the camera.
+    Pan,
+    /// Draw a rectangle to zoom to.
+    BoxZoom,
+    /// Roll the camera.
+    Roll,
+    /// Zoom the camera towards where the drag started.
+    Zoom,
+}
+
+impl DragAction {
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Orbit,
+        Self::Pan,
+        Self::BoxZoom,
+        Self::Roll,
+        Self::Zoom,
+    ];
+}
+
+/// A mouse drag that is in progress. Once started, a drag keeps performing the same action until
+/// the button that started it is released, regardless of modifier changes or the cursor leaving
+/// the viewport or window.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DragGesture {
+    pub action: DragAction,
+    pub button: MouseButton,
+    /// How far the mouse has moved since the button was pressed, in physical pixels
+    pub offset: Vec2,
+    /// Whether the mouse has moved far enough for this to be a drag rather than a click. Only
+    /// then is it reported as a gesture.
+    pub moved: bool,
+}
+
+/// The cursor held by the drag in progress, if any, for `PanOrbitCamera::drag_cursor`
+#[derive(Resource, Default, Debug)]
+pub struct CursorGrabState {
+    grabbed: Option<GrabbedCursor>,
+    /// Whether the window lost focus during the drag in progress, in which case the cursor isn't
+    /// taken again until the next drag
+    focus_lost: bool,
+}
+
+/// A window's cursor settings from before a drag took hold of it, so they can be restored
+/// afterwards
+#[derive(Debug, Copy, Clone)]
+struct GrabbedCursor {
+    window: Entity,
+    grab_mode: CursorGrabMode,
+    visible: bool,
+    position: Option<Vec2>,
+    drag_cursor: DragCursor,
 }
 
+/// How close the cursor has to get to a window edge to wrap around, in logical pixels
+const CURSOR_WRAP_MARGIN: f32 = 2.0;
+
+/// Maximum time between clicks for them to count as a double-click, in seconds
+const DOUBLE_CLICK_TIME: f64 = 0.4;
+/// Maximum cursor movement between clicks for them to count as a double-click, in pixels
+const DOUBLE_CLICK_DISTANCE: f32 = 5.0;
+/// How far the mouse has to move with a button held before it counts as a drag rather than a
+/// click, in physical pixels
+const DRAG_THRESHOLD: f32 = 4.0;
+
 #[allow(clippy::too_many_arguments)]
 pub fn mouse_key_tracker(
     mut camera_movement: ResMut<MouseKeyTracker>,
@@ -22,20 +198,69 @@ pub fn mouse_key_tracker(
     mut pinch_events: EventReader<PinchGesture>,
     mut scroll_events: EventReader<MouseWheel>,
     active_cam: Res<ActiveCameraData>,
-    orbit_cameras: Query<&PanOrbitCamera>,
+    orbit_cameras: Query<(InputSettings, &Camera)>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    time: CameraTime,
+    mut focus_events: EventReader<WindowFocused>,
 ) {
+    // Handle the window losing focus, e.g. from alt-tabbing mid-drag, according to the active
+    // camera's policy
+    let focus_lost = focus_events.read().fold(None, |lost, event| {
+        Some(lost.unwrap_or(false) || !event.focused)
+    });
+    camera_movement.focus_lost = focus_lost == Some(true);
+    if let Some(lost) = focus_lost {
+        // The first mouse motion after coming back can include everything that happened while
+        // the window wasn't focused
+        camera_movement.ignore_next_motion = !lost;
+    }
+    let focus_loss_policy = active_cam
+        .entity
+        .and_then(|entity| orbit_cameras.get(entity).ok())
+        .map(|(settings, _)| input_settings(settings).focus_loss_policy);
+    if camera_movement.focus_lost && focus_loss_policy != Some(FocusLossPolicy::Continue) {
+        camera_movement.drag = None;
+        camera_movement.box_zoom_rect = None;
+        camera_movement.box_zoom_start = None;
+        // The button may still count as pressed, so wait for it to be released before starting
+        // another drag
+        camera_movement.drag_cancelled = true;
+    }
+    if mouse_input.get_pressed().next().is_none() {
+        camera_movement.drag_cancelled = false;
+    }
+
+    // End the drag gesture once its button is released. This is done before anything else so a
+    // drag can't get stuck if the camera goes away mid-drag
+    camera_movement.box_zoom = None;
+    if let Some(drag) = camera_movement
+        .drag
+        .filter(|drag| !mouse_input.pressed(drag.button))
+    {
+        camera_movement.drag = None;
+        if drag.action == DragAction::BoxZoom {
+            camera_movement.box_zoom = camera_movement.box_zoom_rect.take();
+            camera_movement.box_zoom_start = None;
+        }
+    }
+
     let active_entity = match active_cam.entity {
         Some(entity) => entity,
         None => return,
     };
 
-    let pan_orbit = match orbit_cameras.get(active_entity) {
-        Ok(camera) => camera,
+    let (pan_orbit, camera) = match orbit_cameras.get(active_entity) {
+        Ok((settings, camera)) => (input_settings(settings), camera),
         Err(_) => return,
     };
 
     // Collect input deltas
-    let mouse_delta = mouse_motion.read().map(|event| event.delta).sum::<Vec2>();
+    let mut mouse_delta = mouse_motion.read().map(|event| event.delta).sum::<Vec2>();
+    if camera_movement.ignore_next_motion && mouse_delta != Vec2::ZERO {
+        mouse_delta = Vec2::ZERO;
+        camera_movement.ignore_next_motion = false;
+    }
 
     // Collect scroll events
     let scroll_events_vec: Vec<MouseWheel> = scroll_events.read().cloned().collect();
@@ -43,40 +268,341 @@ pub fn mouse_key_tracker(
     // scroll processing needs to account for mouse and trackpad
     // and when it's the trackpad, if we're in BlenderLike mode, we get back trackpad_orbit and trackpad_pan
     // these two values are set to zero if we're in backwards compatible DefaultZoom mode
-    let scroll_result = process_scroll_events(&scroll_events_vec, pan_orbit, &key_input);
-    // Initialize orbit and pan with trackpad contributions
-    let mut orbit = scroll_result.trackpad_orbit;
-    let mut pan = scroll_result.trackpad_pan;
+    let mut scroll_result = process_scroll_events(&scroll_events_vec, pan_orbit, &key_input);
 
     // Handle pinch gestures separately
     // Process pinch events
-    let pinch_zoom = process_pinch_events(&mut pinch_events, pan_orbit, &key_input);
+    let mut pinch_zoom = process_pinch_events(&mut pinch_events, pan_orbit, &key_input);
 
-    // Handle mouse movement for orbiting and panning
-    if orbit_pressed(pan_orbit, &mouse_input, &key_input) {
-        orbit += mouse_delta;
-    } else if pan_pressed(pan_orbit, &mouse_input, &key_input) {
-        pan += mouse_delta;
+    // Only let one of scrolling and pinching through if they conflict
+    let conflicts = &pan_orbit.gesture_conflicts;
+    if conflicts.exclusive_two_finger {
+        let scrolled = scroll_result.scroll_pixel != 0.0
+            || scroll_result.trackpad_orbit != Vec2::ZERO
+            || scroll_result.trackpad_pan != Vec2::ZERO;
+        let now = time.elapsed_secs_f64();
+        match camera_movement.trackpad_gesture(conflicts, scrolled, pinch_zoom != 0.0, now) {
+            Some(TwoFingerGesture::Pinch) => {
+                scroll_result.scroll_pixel = 0.0;
+                scroll_result.trackpad_orbit = Vec2::ZERO;
+                scroll_result.trackpad_pan = Vec2::ZERO;
+            }
+            Some(TwoFingerGesture::Drag) => pinch_zoom = 0.0,
+            Some(TwoFingerGesture::Twist) | None => {}
+        }
+    }
+
+    // Initialize orbit and pan with trackpad contributions
+    let mut orbit = scroll_result.trackpad_orbit;
+    let mut pan = scroll_result.trackpad_pan;
+    let mut roll = 0.0;
+    let mut zoom_drag = 0.0;
+
+    // Handle mouse movement for orbiting and panning. The drag is captured when it starts, so
+    // it continues until its button is released, even if the cursor leaves the window (mouse
+    // motion events are still received on platforms that support it)
+    if camera_movement.drag.is_none() && !camera_movement.drag_cancelled {
+        camera_movement.drag = start_drag(pan_orbit, &mouse_input, &key_input);
+    }
+    if let Some(drag) = camera_movement.drag.as_mut() {
+        drag.offset += mouse_delta;
+        drag.moved |= drag.offset.length() > DRAG_THRESHOLD;
+    }
+    match camera_movement.drag.map(|drag| drag.action) {
+        Some(DragAction::Orbit) => orbit += mouse_delta,
+        Some(DragAction::Pan) => pan += mouse_delta,
+        Some(DragAction::Roll) => roll += mouse_delta.x,
+        // Dragging right or up zooms in
+        Some(DragAction::Zoom) => zoom_drag += mouse_delta.x - mouse_delta.y,
+        Some(DragAction::BoxZoom) | None => {}
     }
 
     // Track button state changes
     let orbit_button_changed = orbit_just_pressed(pan_orbit, &mouse_input, &key_input)
-        || orbit_just_released(pan_orbit, &mouse_input, &key_input);
+        || orbit_just_released(pan_orbit, &mouse_input);
 
     // Update the movement resource
     camera_movement.orbit = orbit;
     camera_movement.pan = pan;
+    camera_movement.roll = roll;
+    camera_movement.zoom_drag = zoom_drag;
     camera_movement.scroll_line = scroll_result.scroll_line;
     camera_movement.scroll_pixel = scroll_result.scroll_pixel + pinch_zoom;
+    camera_movement.scroll_fov = scroll_result.scroll_fov;
     camera_movement.orbit_button_changed = orbit_button_changed;
+    camera_movement.button_just_pressed = mouse_input.get_just_pressed().next().is_some();
+    camera_movement.cursor_position = viewport_cursor_position(
+        camera,
+        pan_orbit.input_region.as_ref(),
+        &primary_windows,
+        &other_windows,
+    );
+    if camera_movement.drag.map(|drag| drag.action) == Some(DragAction::BoxZoom) {
+        // Keep the last rectangle if the cursor leaves the window
+        if let Some(cursor) = camera_movement.cursor_position {
+            let start = *camera_movement.box_zoom_start.get_or_insert(cursor);
+            camera_movement.box_zoom_rect = Some(Rect::from_corners(start, cursor));
+        }
+    }
+    // Zoom drags stay anchored where they started, even though the cursor moves
+    camera_movement.zoom_drag_start = match camera_movement.drag.map(|drag| drag.action) {
+        Some(DragAction::Zoom) => camera_movement
+            .zoom_drag_start
+            .or(camera_movement.cursor_position),
+        _ => None,
+    };
+
+    camera_movement.double_click = None;
+    if let Some(&button) = mouse_input.get_just_pressed().next() {
+        let now = time.elapsed_secs_f64();
+        let cursor = camera_movement.cursor_position.unwrap_or_default();
+        let is_double_click =
+            camera_movement
+                .last_click
+                .is_some_and(|(last_button, time, position)| {
+                    last_button == button
+                        && now - time < DOUBLE_CLICK_TIME
+                        && position.distance(cursor) < DOUBLE_CLICK_DISTANCE
+                });
+        // Don't let a third click count as a second double-click
+        camera_movement.last_click = if is_double_click {
+            camera_movement.double_click = Some(button);
+            None
+        } else {
+            Some((button, now, cursor))
+        };
+    }
+
+    camera_movement.reset_requested = match pan_orbit.reset_gesture {
+        Some(ResetGesture::DoubleClick(button)) => camera_movement.double_click == Some(button),
+        Some(ResetGesture::Key(key)) => key_input.just_pressed(key),
+        None => false,
+    };
+
+    camera_movement.toggle_fly = pan_orbit
+        .key_toggle_fly
+        .is_some_and(|key| key_input.just_pressed(key));
+    let key_axis = |positive: KeyCode, negative: KeyCode| {
+        key_input.pressed(positive) as i8 as f32 - key_input.pressed(negative) as i8 as f32
+    };
+    (camera_movement.key_orbit, camera_movement.key_pan) = (Vec2::ZERO, Vec2::ZERO);
+    camera_movement.key_zoom = 0.0;
+    camera_movement.key_step = false;
+    if let Some(controls) = pan_orbit.keyboard_controls {
+        let modifier_held = |key: Option<KeyCode>| key.is_some_and(|key| key_input.pressed(key));
+        let step = (controls.movement == KeyboardMovement::Incremental)
+            != modifier_held(controls.step_modifier);
+        // When moving in steps, only count keys pressed this frame
+        let axis = |positive: KeyCode, negative: KeyCode| match step {
+            true => {
+                key_input.just_pressed(positive) as i8 as f32
+                    - key_input.just_pressed(negative) as i8 as f32
+            }
+            false => key_axis(positive, negative),
+        };
+        let arrows = Vec2::new(
+            axis(controls.orbit_right, controls.orbit_left),
+            axis(controls.orbit_up, controls.orbit_down),
+        );
+        if modifier_held(controls.pan_modifier) {
+            camera_movement.key_pan = arrows;
+        } else {
+            camera_movement.key_orbit = arrows;
+        }
+        camera_movement.key_zoom = axis(controls.zoom_in, controls.zoom_out);
+        camera_movement.key_step = step;
+    }
+    // The speed ramps restart whenever all their keys are released
+    camera_movement.key_orbit_held_for = match camera_movement.key_orbit {
+        Vec2::ZERO => 0.0,
+        _ => camera_movement.key_orbit_held_for + time.delta_secs(),
+    };
+    camera_movement.key_pan_held_for = match camera_movement.key_pan {
+        Vec2::ZERO => 0.0,
+        _ => camera_movement.key_pan_held_for + time.delta_secs(),
+    };
+    camera_movement.fly_direction = if pan_orbit.control_mode == ControlMode::Fly {
+        let keys = pan_orbit.fly_keys;
+        Vec3::new(
+            key_axis(keys.right, keys.left),
+            key_axis(keys.up, keys.down),
+            key_axis(keys.backward, keys.forward),
+        )
+    } else {
+        Vec3::ZERO
+    };
+}
+
+/// Get the cursor position relative to the camera's viewport, if the camera renders to a window
+/// (or its image is shown in one, see `InputRegion`) and the cursor is inside that window.
+pub fn viewport_cursor_position(
+    camera: &Camera,
+    input_region: Option<&InputRegion>,
+    primary_windows: &Query<&Window, With<PrimaryWindow>>,
+    other_windows: &Query<&Window, Without<PrimaryWindow>>,
+) -> Option<Vec2> {
+    let window = camera_window(camera, input_region, primary_windows, other_windows)?;
+    let cursor_position = window.cursor_position()?;
+    if input_region.is_some() {
+        // Scale from where the image is shown to the image itself
+        let size = camera.logical_viewport_size()?;
+        let rect = rendered_rect(camera, input_region)?;
+        return (rect.size().cmpgt(Vec2::ZERO).all())
+            .then(|| (cursor_position - rect.min) / rect.size() * size);
+    }
+    let viewport_min = camera
+        .logical_viewport_rect()
+        .map(|rect| rect.min)
+        .unwrap_or(Vec2::ZERO);
+    Some(cursor_position - viewport_min)
+}
+
+/// Get the part of the window the camera's image covers, in logical pixels: its viewport, or
+/// where its image is shown if it has an `InputRegion`, leaving out any letterbox bars. `None` if
+/// it renders to an image without an `InputRegion`.
+pub fn rendered_rect(camera: &Camera, input_region: Option<&InputRegion>) -> Option<Rect> {
+    match (input_region, &camera.target) {
+        (Some(region), _) if region.letterbox => {
+            let size = camera.logical_viewport_size()?;
+            Some(util::fit_aspect_ratio(region.rect, size.x / size.y))
+        }
+        (Some(region), _) => Some(region.rect),
+        (None, RenderTarget::Window(_)) => camera.logical_viewport_rect(),
+        (None, _) => None,
+    }
+}
+
+/// Get the window the camera renders to, or the window its image is shown in if it has an
+/// `InputRegion`.
+pub fn camera_window<'a>(
+    camera: &Camera,
+    input_region: Option<&InputRegion>,
+    primary_windows: &'a Query<&Window, With<PrimaryWindow>>,
+    other_windows: &'a Query<&Window, Without<PrimaryWindow>>,
+) -> Option<&'a Window> {
+    let win_ref = match (input_region, &camera.target) {
+        (Some(region), _) => region.window,
+        (None, RenderTarget::Window(win_ref)) => *win_ref,
+        (None, _) => return None,
+    };
+    match win_ref {
+        WindowRef::Primary => primary_windows.get_single().ok(),
+        WindowRef::Entity(entity) => other_windows.get(entity).ok(),
+    }
 }
 
+/// Update `PanOrbitCamera::hovered` and `PanOrbitCamera::render_rect` for each camera. Where
+/// viewports overlap in the same render target, only the camera with the highest order is hovered,
+/// and images shown with an `InputRegion` are on top of the window's own viewports.
+pub fn hover_tracker(
+    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera, &Camera)>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    primary_window_entity: Query<Entity, With<PrimaryWindow>>,
+) {
+    // Only windows have a cursor, so compare the windows
+    let primary_window = primary_window_entity.get_single().ok();
+    let under_cursor: Vec<(Entity, NormalizedWindowRef, (bool, isize))> = orbit_cameras
+        .iter()
+        .filter(|(_, pan_orbit, camera)| {
+            let Some(size) = camera.logical_viewport_size() else {
+                return false;
+            };
+            viewport_cursor_position(
+                camera,
+                pan_orbit.input_region.as_ref(),
+                &primary_windows,
+                &other_windows,
+            )
+            .is_some_and(|cursor| cursor.cmpge(Vec2::ZERO).all() && cursor.cmplt(size).all())
+        })
+        .filter_map(|(entity, pan_orbit, camera)| {
+            let window = match (&pan_orbit.input_region, &camera.target) {
+                (Some(region), _) => region.window,
+                (None, RenderTarget::Window(window)) => *window,
+                (None, _) => return None,
+            };
+            let priority = (pan_orbit.input_region.is_some(), camera.order);
+            window
+                .normalize(primary_window)
+                .map(|window| (entity, window, priority))
+        })
+        .collect();
+    let hovered: Vec<Entity> = under_cursor
+        .iter()
+        .filter(|(_, window, priority)| {
+            !under_cursor
+                .iter()
+                .any(|(_, other_window, other_priority)| {
+                    other_window == window && other_priority > priority
+                })
+        })
+        .map(|(entity, _, _)| *entity)
+        .collect();
+
+    for (entity, mut pan_orbit, camera) in orbit_cameras.iter_mut() {
+        let is_hovered = hovered.contains(&entity);
+        // Avoid triggering change detection every frame
+        if pan_orbit.hovered != is_hovered {
+            pan_orbit.hovered = is_hovered;
+        }
+        let render_rect = rendered_rect(camera, pan_orbit.input_region.as_ref());
+        if pan_orbit.render_rect != render_rect {
+            pan_orbit.render_rect = render_rect;
+        }
+    }
+}
+
+/// How many pixels of orbiting or panning one line of scrolling is worth
+const SCROLL_LINE_PIXELS: f32 = 20.0;
+
+/// How many lines of zoom one pixel of scrolling is worth
+const SCROLL_PIXEL_LINES: f32 = 0.005;
+
 #[derive(Default)]
 struct ScrollProcessingResult {
+    /// Orbiting from trackpad gestures and scroll bindings, in pixels
     trackpad_orbit: Vec2,
+    /// Panning from trackpad gestures and scroll bindings, in pixels
     trackpad_pan: Vec2,
     scroll_line: f32,
     scroll_pixel: f32,
+    scroll_fov: f32,
+}
+
+impl ScrollProcessingResult {
+    /// Add `event` to whichever actions its axes are bound to
+    fn add_bound_scroll(
+        &mut self,
+        event: &MouseWheel,
+        pan_orbit: &PanOrbitCamera,
+        key_input: &ButtonInput<KeyCode>,
+    ) {
+        let (pixels, lines) = match event.unit {
+            MouseScrollUnit::Line => (SCROLL_LINE_PIXELS, 1.0),
+            MouseScrollUnit::Pixel => (pan_orbit.trackpad_sensitivity as f32, SCROLL_PIXEL_LINES),
+        };
+        for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            let amount = axis.value(event);
+            if amount == 0.0 {
+                continue;
+            }
+            match pan_orbit.input_map.scroll_action(axis, key_input) {
+                Some(ScrollAction::Zoom) => match event.unit {
+                    MouseScrollUnit::Line => self.scroll_line += amount,
+                    MouseScrollUnit::Pixel => self.scroll_pixel += amount * lines,
+                },
+                Some(ScrollAction::Yaw) => self.trackpad_orbit.x += amount * pixels,
+                Some(ScrollAction::Pitch) => self.trackpad_orbit.y += amount * pixels,
+                Some(ScrollAction::Pan) => match axis {
+                    ScrollAxis::Horizontal => self.trackpad_pan.x += amount * pixels,
+                    ScrollAxis::Vertical => self.trackpad_pan.y += amount * pixels,
+                },
+                Some(ScrollAction::Fov) => self.scroll_fov += amount * lines,
+                None => {}
+            }
+        }
+    }
 }
 
 /// mimic how blender _doesn't_ handle pinch gestures when modifiers are pressed
@@ -100,11 +626,11 @@ fn process_scroll_events(
             for event in scroll_events {
                 match event.unit {
                     MouseScrollUnit::Line => {
-                        result.scroll_line += event.y;
+                        result.add_bound_scroll(event, pan_orbit, key_input);
                     }
                     MouseScrollUnit::Pixel => {
                         if is_zoom_modifier_pressed {
-                            result.scroll_pixel += event.y * 0.005;
+                            result.scroll_pixel += event.y * SCROLL_PIXEL_LINES;
                         } else if is_pan_modifier_pressed {
                             result.trackpad_pan +=
                                 Vec2::new(event.x, event.y) * pan_orbit.trackpad_sensitivity as f32;
@@ -118,23 +644,43 @@ fn process_scroll_events(
 
             result
         }
-        _ => {
-            // Default behavior: all scroll events contribute to zoom
-            let (scroll_line, scroll_pixel) = scroll_events
-                .iter()
-                .map(|event| match event.unit {
-                    MouseScrollUnit::Line => (event.y, 0.0),
-                    MouseScrollUnit::Pixel => (0.0, event.y * 0.005),
-                })
-                .fold((0.0, 0.0), |acc, item| (acc.0 + item.0, acc.1 + item.1));
-
-            // DefaultZoom behavior - no trackpad involved
-            ScrollProcessingResult {
-                trackpad_orbit: Vec2::ZERO,
-                trackpad_pan: Vec2::ZERO,
-                scroll_line,
-                scroll_pixel,
+        TrackpadBehavior::ScrollPans {
+            modifier_orbit,
+            reverse_direction,
+        } => {
+            let is_orbit_modifier_pressed =
+                modifier_orbit.is_some_and(|modifier| key_input.pressed(modifier));
+            let direction = if reverse_direction { -1.0 } else { 1.0 };
+
+            let mut result = ScrollProcessingResult::default();
+
+            for event in scroll_events {
+                match event.unit {
+                    MouseScrollUnit::Line => {
+                        result.add_bound_scroll(event, pan_orbit, key_input);
+                    }
+                    MouseScrollUnit::Pixel => {
+                        let delta = Vec2::new(event.x, event.y)
+                            * pan_orbit.trackpad_sensitivity as f32
+                            * direction;
+                        if is_orbit_modifier_pressed {
+                            result.trackpad_orbit += delta;
+                        } else {
+                            result.trackpad_pan += delta;
+                        }
+                    }
+                }
+            }
+
+            result
+        }
+        TrackpadBehavior::Default => {
+            // Default behavior: scroll events do whatever they're bound to, zooming by default
+            let mut result = ScrollProcessingResult::default();
+            for event in scroll_events {
+                result.add_bound_scroll(event, pan_orbit, key_input);
             }
+            result
         }
     }
 }
@@ -144,36 +690,29 @@ fn process_pinch_events(
     pan_orbit: &PanOrbitCamera,
     key_input: &Res<ButtonInput<KeyCode>>,
 ) -> f32 {
-    if !pan_orbit.trackpad_pinch_to_zoom_enabled {
+    let scroll_pans = matches!(
+        pan_orbit.trackpad_behavior,
+        TrackpadBehavior::ScrollPans { .. }
+    );
+    if !pan_orbit.trackpad_pinch_to_zoom_enabled && !scroll_pans {
         return 0.0;
     }
 
     // Check if no modifiers are pressed (including BlenderLike modifiers if applicable)
-    let no_modifiers_pressed = match pan_orbit.trackpad_behavior {
-        TrackpadBehavior::BlenderLike {
-            modifier_pan,
-            modifier_zoom,
-        } => {
-            // Check regular modifiers and BlenderLike modifiers
-            pan_orbit
-                .modifier_orbit
-                .is_none_or(|modifier| !key_input.pressed(modifier))
-                && pan_orbit
-                    .modifier_pan
-                    .is_none_or(|modifier| !key_input.pressed(modifier))
-                && modifier_pan.is_none_or(|modifier| !key_input.pressed(modifier))
-                && modifier_zoom.is_none_or(|modifier| !key_input.pressed(modifier))
-        }
-        _ => {
-            // Just check regular modifiers
-            pan_orbit
-                .modifier_orbit
-                .is_none_or(|modifier| !key_input.pressed(modifier))
-                && pan_orbit
-                    .modifier_pan
-                    .is_none_or(|modifier| !key_input.pressed(modifier))
-        }
-    };
+    let no_modifiers_pressed = !pan_orbit.input_map.any_drag_modifier_pressed(key_input)
+        && match pan_orbit.trackpad_behavior {
+            TrackpadBehavior::BlenderLike {
+                modifier_pan,
+                modifier_zoom,
+            } => {
+                modifier_pan.is_none_or(|modifier| !key_input.pressed(modifier))
+                    && modifier_zoom.is_none_or(|modifier| !key_input.pressed(modifier))
+            }
+            TrackpadBehavior::ScrollPans { modifier_orbit, .. } => {
+                modifier_orbit.is_none_or(|modifier| !key_input.pressed(modifier))
+            }
+            TrackpadBehavior::Default => true,
+        };
 
     if no_modifiers_pressed {
         pinch_events
@@ -185,20 +724,64 @@ fn process_pinch_events(
     }
 }
 
-pub fn orbit_pressed(
+/// Start a drag if any orbit, pan, box zoom, roll, or zoom binding is active. Which action wins
+/// when more than one is active is decided by the camera's `gesture_conflicts`.
+fn start_drag(
     pan_orbit: &PanOrbitCamera,
-    mouse_input: &Res<ButtonInput<MouseButton>>,
-    key_input: &Res<ButtonInput<KeyCode>>,
-) -> bool {
-    let is_pressed = pan_orbit
-        .modifier_orbit
-        .is_none_or(|modifier| key_input.pressed(modifier))
-        && mouse_input.pressed(pan_orbit.button_orbit);
-
-    is_pressed
-        && pan_orbit
-            .modifier_pan
-            .is_none_or(|modifier| !key_input.pressed(modifier))
+    mouse_input: &ButtonInput<MouseButton>,
+    key_input: &ButtonInput<KeyCode>,
+) -> Option<DragGesture> {
+    let input_map = &pan_orbit.input_map;
+    let bindings = |action| match action {
+        DragAction::Orbit => &input_map.orbit,
+        DragAction::Pan => &input_map.pan,
+        DragAction::BoxZoom => &input_map.box_zoom,
+        DragAction::Roll => &input_map.roll,
+        DragAction::Zoom => &input_map.zoom_drag,
+    };
+    // Actions the user isn't allowed to do don't start drags, leaving the button for others
+    let enabled = |action| match action {
+        DragAction::Orbit | DragAction::Roll => pan_orbit.orbit_enabled,
+        DragAction::Pan => pan_orbit.pan_enabled,
+        DragAction::BoxZoom | DragAction::Zoom => pan_orbit.zoom_enabled,
+    };
+    let action = pan_orbit
+        .gesture_conflicts
+        .drag_winner(DragAction::ALL.map(|action| {
+            let specificity = enabled(action)
+                .then(|| {
+                    binding_specificity(bindings(action), key_input, |b| mouse_input.pressed(b))
+                })
+                .flatten();
+            (action, specificity)
+        }))?;
+    // The button of the most specific active binding
+    let binding = bindings(action)
+        .iter()
+        .filter(|binding| {
+            mouse_input.pressed(binding.button) && binding.modifiers_pressed(key_input)
+        })
+        .max_by_key(|binding| binding.modifiers.len())?;
+    Some(DragGesture {
+        action,
+        button: binding.button,
+        offset: Vec2::ZERO,
+        moved: false,
+    })
+}
+
+/// The highest number of modifiers of any binding in `bindings` whose modifiers are held and
+/// whose button passes `button_check`, or `None` if no bindings are active.
+fn binding_specificity(
+    bindings: &[ButtonBinding],
+    key_input: &ButtonInput<KeyCode>,
+    button_check: impl Fn(MouseButton) -> bool,
+) -> Option<usize> {
+    bindings
+        .iter()
+        .filter(|binding| button_check(binding.button) && binding.modifiers_pressed(key_input))
+        .map(|binding| binding.modifiers.len())
+        .max()
 }
 
 pub fn orbit_just_pressed(
@@ -206,61 +789,140 @@ pub fn orbit_just_pressed(
     mouse_input: &Res<ButtonInput<MouseButton>>,
     key_input: &Res<ButtonInput<KeyCode>>,
 ) -> bool {
-    let just_pressed = pan_orbit
-        .modifier_orbit
-        .is_none_or(|modifier| key_input.pressed(modifier))
-        && (mouse_input.just_pressed(pan_orbit.button_orbit));
-
-    just_pressed
-        && pan_orbit
-            .modifier_pan
-            .is_none_or(|modifier| !key_input.pressed(modifier))
+    let input_map = &pan_orbit.input_map;
+    let orbit = binding_specificity(&input_map.orbit, key_input, |b| mouse_input.just_pressed(b));
+    let pan = binding_specificity(&input_map.pan, key_input, |b| mouse_input.pressed(b));
+    let conflicts = &pan_orbit.gesture_conflicts;
+    orbit.is_some()
+        && conflicts.drag_winner([(DragAction::Orbit, orbit), (DragAction::Pan, pan)])
+            == Some(DragAction::Orbit)
 }
 
 pub fn orbit_just_released(
     pan_orbit: &PanOrbitCamera,
     mouse_input: &Res<ButtonInput<MouseButton>>,
-    key_input: &Res<ButtonInput<KeyCode>>,
 ) -> bool {
-    let just_released = pan_orbit
-        .modifier_orbit
-        .is_none_or(|modifier| key_input.pressed(modifier))
-        && (mouse_input.just_released(pan_orbit.button_orbit));
-
-    just_released
-        && pan_orbit
-            .modifier_pan
-            .is_none_or(|modifier| !key_input.pressed(modifier))
+    // Modifiers are ignored, since they may have been released first
+    pan_orbit
+        .input_map
+        .orbit
+        .iter()
+        .any(|binding| mouse_input.just_released(binding.button))
 }
 
-pub fn pan_pressed(
+pub fn pan_just_pressed(
     pan_orbit: &PanOrbitCamera,
     mouse_input: &Res<ButtonInput<MouseButton>>,
     key_input: &Res<ButtonInput<KeyCode>>,
 ) -> bool {
-    let is_pressed = pan_orbit
-        .modifier_pan
-        .is_none_or(|modifier| key_input.pressed(modifier))
-        && mouse_input.pressed(pan_orbit.button_pan);
-
-    is_pressed
-        && pan_orbit
-            .modifier_orbit
-            .is_none_or(|modifier| !key_input.pressed(modifier))
+    let input_map = &pan_orbit.input_map;
+    let orbit = binding_specificity(&input_map.orbit, key_input, |b| mouse_input.pressed(b));
+    let pan = binding_specificity(&input_map.pan, key_input, |b| mouse_input.just_pressed(b));
+    let conflicts = &pan_orbit.gesture_conflicts;
+    pan.is_some()
+        && conflicts.drag_winner([(DragAction::Orbit, orbit), (DragAction::Pan, pan)])
+            == Some(DragAction::Pan)
 }
 
-pub fn pan_just_pressed(
-    pan_orbit: &PanOrbitCamera,
-    mouse_input: &Res<ButtonInput<MouseButton>>,
-    key_input: &Res<ButtonInput<KeyCode>>,
-) -> bool {
-    let just_pressed = pan_orbit
-        .modifier_pan
-        .is_none_or(|modifier| key_input.pressed(modifier))
-        && (mouse_input.just_pressed(pan_orbit.button_pan));
-
-    just_pressed
-        && pan_orbit
-            .modifier_orbit
-            .is_none_or(|modifier| !key_input.pressed(modifier))
+/// Hide and lock, or wrap, the cursor while the active camera is being dragged, according to its
+/// `drag_cursor`, and give the cursor back once the drag ends or the window loses focus
+pub fn cursor_grab(
+    mut state: ResMut<CursorGrabState>,
+    mouse_key_tracker: Res<MouseKeyTracker>,
+    active_cam: Res<ActiveCameraData>,
+    orbit_cameras: Query<(InputSettings, &Camera)>,
+    mut windows: Query<&mut Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let dragging = mouse_key_tracker.drag.is_some_and(|drag| {
+        matches!(
+            drag.action,
+            DragAction::Orbit | DragAction::Pan | DragAction::Roll | DragAction::Zoom
+        )
+    });
+    if !dragging {
+        state.focus_lost = false;
+    } else if mouse_key_tracker.focus_lost {
+        state.focus_lost = true;
+    }
+
+    if !dragging || state.focus_lost {
+        if let Some(grabbed) = state.grabbed.take() {
+            if let Ok(mut window) = windows.get_mut(grabbed.window) {
+                window.cursor_options.grab_mode = grabbed.grab_mode;
+                window.cursor_options.visible = grabbed.visible;
+                if grabbed.drag_cursor == DragCursor::Locked && grabbed.position.is_some() {
+                    window.set_cursor_position(grabbed.position);
+                }
+            }
+        }
+        return;
+    }
+
+    if state.grabbed.is_none() {
+        let Some((settings, camera)) = active_cam
+            .entity
+            .and_then(|entity| orbit_cameras.get(entity).ok())
+        else {
+            return;
+        };
+        let pan_orbit = input_settings(settings);
+        let RenderTarget::Window(win_ref) = camera.target else {
+            return;
+        };
+        let Some(entity) = win_ref
+            .normalize(primary_window.get_single().ok())
+            .map(|window| window.entity())
+        else {
+            return;
+        };
+        let Ok(mut window) = windows.get_mut(entity) else {
+            return;
+        };
+        let (grab_mode, visible) = match pan_orbit.drag_cursor {
+            DragCursor::Free => return,
+            DragCursor::Locked => (CursorGrabMode::Locked, false),
+            DragCursor::Wrap => (CursorGrabMode::Confined, true),
+        };
+        state.grabbed = Some(GrabbedCursor {
+            window: entity,
+            grab_mode: window.cursor_options.grab_mode,
+            visible: window.cursor_options.visible,
+            position: window.cursor_position(),
+            drag_cursor: pan_orbit.drag_cursor,
+        });
+        window.cursor_options.grab_mode = grab_mode;
+        window.cursor_options.visible = visible;
+    }
+
+    let Some(grabbed) = state
+        .grabbed
+        .filter(|grabbed| grabbed.drag_cursor == DragCursor::Wrap)
+    else {
+        return;
+    };
+    let Ok(mut window) = windows.get_mut(grabbed.window) else {
+        return;
+    };
+    let Some(position) = window.cursor_position() else {
+        return;
+    };
+    let size = Vec2::new(window.width(), window.height());
+    let span = size - 2.0 * CURSOR_WRAP_MARGIN;
+    let wrap = |value: f32, span: f32, size: f32| {
+        if value < CURSOR_WRAP_MARGIN {
+            value + span
+        } else if value > size - CURSOR_WRAP_MARGIN {
+            value - span
+        } else {
+            value
+        }
+    };
+    let wrapped = Vec2::new(
+        wrap(position.x, span.x, size.x),
+        wrap(position.y, span.y, size.y),
+    );
+    if wrapped != position {
+        window.set_cursor_position(Some(wrapped));
+    }
 }
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+/// Configuration for controlling a `PanOrbitCamera` with the keyboard. By default, the arrow
+/// keys orbit the camera around the focus, holding Shift makes them pan instead, and `=` and `-`
+/// zoom in and out. Holding a key moves the camera smoothly, speeding up according to the
+/// speeds, so a tap makes a fine adjustment while holding covers a lot of ground quickly. Holding
+/// Ctrl moves the camera in fixed steps instead, one per key press (see `KeyboardMovement`).
+///
+/// Like the mouse, keyboard input only controls the active camera, and the camera's sensitivity,
+/// smoothing, and limits still apply.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyboardControls {
+    /// Orbit left. Defaults to `KeyCode::ArrowLeft`.
+    pub orbit_left: KeyCode,
+    /// Orbit right. Defaults to `KeyCode::ArrowRight`.
+    pub orbit_right: KeyCode,
+    /// Orbit up, i.e. look down from higher up. Defaults to `KeyCode::ArrowUp`.
+    pub orbit_up: KeyCode,
+    /// Orbit down. Defaults to `KeyCode::ArrowDown`.
+    pub orbit_down: KeyCode,
+    /// While held, the orbit keys pan the camera instead. `None` disables keyboard panning.
+    /// Defaults to `Some(KeyCode::ShiftLeft)`.
+    pub pan_modifier: Option<KeyCode>,
+    /// Zoom in. Defaults to `KeyCode::Equal` (`=`, which has `+` on it on most layouts).
+    pub zoom_in: KeyCode,
+    /// Zoom out. Defaults to `KeyCode::Minus`.
+    pub zoom_out: KeyCode,
+    /// Whether keys move the camera smoothly or in steps.
+    /// Defaults to `KeyboardMovement::Smooth`.
+    pub movement: KeyboardMovement,
+    /// While held, keys move the camera the other way to `movement`, e.g. in steps rather than
+    /// smoothly. `None` always uses `movement`.
+    /// Defaults to `Some(KeyCode::ControlLeft)`.
+    pub step_modifier: Option<KeyCode>,
+    /// How fast the orbit keys rotate the camera, in radians per second, and how that speeds up
+    /// while they're held.
+    /// Defaults to ramping from 30 to 180 degrees per second over 1.5 seconds.
+    pub orbit_speed: SpeedRamp,
+    /// How fast the keys pan the camera, in multiples of the radius per second, so it covers the
+    /// same proportion of the view however far it's zoomed out, and how that speeds up while
+    /// they're held. Orthographic cameras pan as fast as a perspective camera showing the same
+    /// view.
+    /// Defaults to ramping from `0.5` to `2.0` over 1.5 seconds.
+    pub pan_speed: SpeedRamp,
+    /// How fast the zoom keys zoom, as the proportion of the radius to change by each second.
+    /// Defaults to `1.0`.
+    pub zoom_speed: f64,
+    /// How far each press of an orbit key rotates the camera when moving in steps, in radians.
+    /// Defaults to 45 degrees.
+    pub orbit_step: f64,
+    /// How far each press of a key pans the camera when moving in steps, in multiples of the
+    /// radius.
+    /// Defaults to `0.25`.
+    pub pan_step: f64,
+    /// How much each press of a zoom key zooms when moving in steps, as a proportion of the
+    /// radius.
+    /// Defaults to `0.2`.
+    pub zoom_step: f64,
+}
+
+impl Default for KeyboardControls {
+    fn default() -> Self {
+        Self {
+            orbit_left: KeyCode::ArrowLeft,
+            orbit_right: KeyCode::ArrowRight,
+            orbit_up: KeyCode::ArrowUp,
+            orbit_down: KeyCode::ArrowDown,
+            pan_modifier: Some(KeyCode::ShiftLeft),
+            zoom_in: KeyCode::Equal,
+            zoom_out: KeyCode::Minus,
+            movement: KeyboardMovement::Smooth,
+            step_modifier: Some(KeyCode::ControlLeft),
+            orbit_speed: SpeedRamp {
+                start: 30f64.to_radians(),
+                max: 180f64.to_radians(),
+                ramp_time: 1.5,
+            },
+            pan_speed: SpeedRamp {
+                start: 0.5,
+                max: 2.0,
+                ramp_time: 1.5,
+            },
+            zoom_speed: 1.0,
+            orbit_step: 45f64.to_radians(),
+            pan_step: 0.25,
+            zoom_step: 0.2,
+        }
+    }
+}
+
+/// How keyboard keys move a `PanOrbitCamera`, see `KeyboardControls`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyboardMovement {
+    /// The camera moves for as long as a key is held, at the `KeyboardControls` speeds.
+    #[default]
+    Smooth,
+    /// The camera moves once each time a key is pressed, by the `KeyboardControls` steps. Good for
+    /// precise, repeatable adjustments.
+    Incremental,
+}
+
+/// A speed that increases linearly from `start` to `max` while a key is held.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedRamp {
+    /// The speed as soon as the key is pressed.
+    pub start: f64,
+    /// The speed once the key has been held for `ramp_time`.
+    pub max: f64,
+    /// How long it takes to reach `max`, in seconds. Use `0.0` for a constant speed of `max`.
+    pub ramp_time: f32,
+}
+
+impl SpeedRamp {
+    /// A constant speed, with no ramp.
+    pub fn constant(speed: f64) -> Self {
+        Self {
+            start: speed,
+            max: speed,
+            ramp_time: 0.0,
+        }
+    }
+
+    /// The speed after the key has been held for `held_for` seconds.
+    pub fn speed(&self, held_for: f32) -> f64 {
+        let t = if self.ramp_time > 0.0 {
+            (held_for / self.ramp_time).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        self.start + (self.max - self.start) * t as f64
+    }
+}
@@ -1,33 +1,149 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::PI;
+use std::time::Duration;
 
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::input::gestures::PinchGesture;
 use bevy::input::mouse::MouseWheel;
-use bevy::math::{DVec2, DVec3, FloatPow, VectorSpace};
+use bevy::input::InputSystem;
+use bevy::math::{DQuat, DVec2, DVec3};
 use bevy::prelude::*;
-use bevy::render::camera::{CameraUpdateSystem, RenderTarget};
+use bevy::render::camera::{CameraProjection, CameraUpdateSystem, RenderTarget, ScalingMode};
+#[cfg(feature = "view_cube")]
+use bevy::render::view::RenderLayers;
 use bevy::transform::TransformSystem;
+use bevy::utils::HashMap;
 use bevy::window::{PrimaryWindow, WindowRef};
 #[cfg(feature = "bevy_egui")]
 use bevy_egui::EguiPreUpdateSet;
 
+pub use crate::bindings::{
+    ButtonBinding, Modifier, PanOrbitInputMap, ScrollAction, ScrollAxis, ScrollBinding,
+};
+pub use crate::camera_2d::PanZoomCamera2d;
+pub use crate::capture::{CameraMatrices, MatrixCapture};
+use crate::clock::{CameraTime, PanOrbitClock};
+#[cfg(feature = "bevy_picking")]
+pub use crate::collision::{IgnoreCameraCollision, PanOrbitCollision};
+pub use crate::conflicts::{GestureConflicts, TwoFingerGesture};
+#[cfg(feature = "cursor_icon")]
+pub use crate::cursor::PanOrbitCursorIcons;
+use crate::curves::{apply_sensitivity_curves, ResponseCurveLoader};
+pub use crate::curves::{ResponseCurve, ResponseCurveLoaderError, SensitivityCurves};
+#[cfg(feature = "bevy_picking")]
+pub use crate::depth::PanOrbitCursorDepth;
+use crate::drift::idle_drift;
+pub use crate::drift::IdleDrift;
+use crate::drive::apply_camera_input;
+pub use crate::drive::CameraInput;
+pub use crate::edge_pan::EdgePan;
+use crate::edge_pan::{edge_pan_tracker, EdgePanTracker};
 #[cfg(feature = "bevy_egui")]
-pub use crate::egui::{EguiFocusIncludesHover, EguiWantsFocus};
-use crate::input::{mouse_key_tracker, MouseKeyTracker};
-pub use crate::touch::TouchControls;
-use crate::touch::{touch_tracker, TouchGestures, TouchTracker};
+pub use crate::egui::{view_menu_ui, EguiFocusIncludesHover, EguiWantsFocus, PanOrbitEguiBlocking};
+pub use crate::events::{
+    CameraIdle, OrbitEnded, OrbitStarted, PanEnded, PanStarted, SnappedToView, ZoomChanged,
+};
+use crate::follow::follow_target;
+pub use crate::follow::{LookAhead, PanOrbitFollow};
+use crate::framing::auto_frame;
+pub use crate::framing::PanOrbitAutoFrame;
+pub use crate::gamepad::GamepadControls;
+use crate::gamepad::{gamepad_tracker, GamepadTracker};
+#[cfg(feature = "bevy_gizmos")]
+pub use crate::ghost::PanOrbitGhost;
+#[cfg(feature = "bevy_gizmos")]
+pub use crate::gizmos::{PanOrbitGizmoConfigGroup, PanOrbitGizmos};
+use crate::guided::update_guided_mode;
+pub use crate::guided::{CameraFreedom, GuidedMode, GuidedState};
+#[cfg(feature = "bevy_gizmos")]
+pub use crate::indicator::{IndicatorShape, PanOrbitPivotIndicator};
+pub use crate::input::DragAction;
+use crate::input::{
+    cursor_grab, hover_tracker, mouse_key_tracker, CursorGrabState, InputSettings, MouseKeyTracker,
+};
+pub use crate::keyboard::{KeyboardControls, KeyboardMovement, SpeedRamp};
+pub use crate::motion::{MotionLimits, MotionShape};
+pub use crate::pacing::{InputPacing, PendingInput};
+#[cfg(feature = "bevy_picking")]
+pub use crate::pivot::{PanOrbitPickPivot, PanOrbitPivotRaise};
+pub use crate::recovery::CameraRecovered;
+use crate::recovery::{recover_invalid_cameras, recover_invalid_transforms, RecoveryTracker};
+pub use crate::replay::{InjectedInput, InputReplay};
+use crate::script::run_navigation_scripts;
+pub use crate::script::{NavigationScript, NavigationStep, ScriptProjection};
+pub use crate::settings::{
+    PanOrbitSettings, PanOrbitSettingsPlugin, SettingsApplied, SharedSettings,
+};
+pub use crate::smoothing::{SmoothingCurve, SmoothingCurves, SmoothingFn, SpringVelocity};
+use crate::sticky::snap_to_sticky_views;
+pub use crate::sticky::StickyViews;
+use crate::sync::sync_cameras;
+pub use crate::sync::{SyncChannels, SyncGroup};
+use crate::touch::touch_tracker;
+pub use crate::touch::{
+    OneFingerGestures, TouchAction, TouchControls, TouchGestureBinding, TouchGestures,
+    TouchTracker, TwoFingerGestures,
+};
 use crate::traits::OptionalClamp;
+use crate::view_commands::apply_view_commands;
+pub use crate::view_commands::{ApplyViewCommand, ViewCommand, ViewIcon, ViewMenuItem};
+#[cfg(feature = "view_cube")]
+pub use crate::view_cube::{ViewCube, ViewCubeGizmoConfigGroup, VIEW_CUBE_LAYER};
 
+mod bindings;
+mod camera_2d;
+mod capture;
+mod clock;
+#[cfg(feature = "bevy_picking")]
+mod collision;
+mod conflicts;
+#[cfg(feature = "cursor_icon")]
+mod cursor;
+mod curves;
+#[cfg(feature = "bevy_picking")]
+mod depth;
+mod drift;
+mod drive;
+mod edge_pan;
 #[cfg(feature = "bevy_egui")]
 mod egui;
+mod events;
+mod follow;
+mod framing;
+mod gamepad;
+#[cfg(feature = "bevy_gizmos")]
+mod ghost;
+#[cfg(feature = "bevy_gizmos")]
+mod gizmos;
+mod guided;
+#[cfg(feature = "bevy_gizmos")]
+mod indicator;
 mod input;
+mod keyboard;
+mod motion;
+mod pacing;
+#[cfg(feature = "bevy_picking")]
+mod pivot;
+mod recovery;
+mod replay;
+mod script;
+mod settings;
+mod smoothing;
+mod sticky;
+mod sync;
 mod touch;
 mod traits;
 mod util;
+mod view_commands;
+#[cfg(feature = "view_cube")]
+mod view_cube;
 
 /// Bevy plugin that contains the systems for controlling `PanOrbitCamera` components.
+///
+/// By default, the cameras update in `PostUpdate`, and advance with the virtual clock, so they
+/// freeze while `Time<Virtual>` is paused. Use `in_schedule` and `use_real_time` to change this.
 /// # Example
 /// ```no_run
 /// # use bevy::prelude::*;
@@ -35,45 +151,330 @@ mod util;
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
-///         .add_plugins(PanOrbitCameraPlugin)
+///         .add_plugins(PanOrbitCameraPlugin::default())
 ///         .run();
 /// }
 /// ```
-pub struct PanOrbitCameraPlugin;
+/// Keep the camera moving while the game is paused, and update it before the game's own systems
+/// in `PostUpdate`, e.g. physics interpolation, by moving it to `Update`:
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::PanOrbitCameraPlugin;
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(PanOrbitCameraPlugin::new().in_schedule(Update).use_real_time())
+///     .run();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PanOrbitCameraPlugin {
+    schedule: InternedScheduleLabel,
+    real_time: bool,
+}
+
+impl Default for PanOrbitCameraPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PanOrbitCameraPlugin {
+    /// Create the plugin, which updates cameras in `PostUpdate` using virtual time.
+    pub fn new() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            real_time: false,
+        }
+    }
+
+    /// Run the camera systems, and `PanOrbitCameraSystemSet`, in `schedule`, e.g. `Update`,
+    /// instead of `PostUpdate`. Wherever they run, they update the camera's `Transform` before
+    /// `TransformSystem::TransformPropagate`. Input replay always runs in `PreUpdate`, since it
+    /// has to come before Bevy's input handling.
+    ///
+    /// User input is read once each time the schedule runs, so the schedule should run once per
+    /// frame. `FixedUpdate` runs zero or several times per frame, which drops or repeats button
+    /// presses and touch movement, e.g. one click can count as a double-click, so only use it
+    /// for cameras that are moved from code, e.g. with `CameraInput` or animations.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Advance smoothing, momentum, animations, and other timed behaviour with `Time<Real>`
+    /// instead of the schedule's `Time`, so cameras keep moving while virtual time is paused or
+    /// scaled. `Time<Real>` advances once per frame, so in `FixedUpdate` every step that frame
+    /// would see the whole frame's time; prefer `Update` or `PostUpdate` with real time.
+    pub fn use_real_time(mut self) -> Self {
+        self.real_time = true;
+        self
+    }
+}
 
 impl Plugin for PanOrbitCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<ActiveCameraData>()
-            .init_resource::<MouseKeyTracker>()
-            .init_resource::<TouchTracker>()
-            .add_systems(
-                PostUpdate,
+        if self.schedule == FixedUpdate.intern() {
+            warn!(
+                "PanOrbitCameraPlugin is running in FixedUpdate, where user input can be dropped \
+                or repeated. Prefer Update or PostUpdate for cameras the user controls."
+            );
+        }
+        app.insert_resource(PanOrbitClock {
+            real_time: self.real_time,
+        })
+        .init_resource::<ActiveCameraData>()
+        .init_resource::<ActivePanOrbitCamera>()
+        .init_resource::<MouseKeyTracker>()
+        .init_resource::<TouchTracker>()
+        .init_resource::<GamepadTracker>()
+        .init_resource::<CursorGrabState>()
+        .init_resource::<EdgePanTracker>()
+        .init_resource::<RecoveryTracker>()
+        .register_type::<PanOrbitCamera>()
+        .register_type::<TimeSinceInput>()
+        .register_type::<PanOrbitFollow>()
+        .register_type::<PanOrbitAutoFrame>()
+        .register_type::<NavigationScript>()
+        .register_type::<SensitivityCurves>()
+        .register_type::<SyncGroup>()
+        .register_type::<PanZoomCamera2d>()
+        .register_type::<CameraInput>()
+        .register_type::<PanOrbitSettings>()
+        .add_event::<OrbitStarted>()
+        .add_event::<OrbitEnded>()
+        .add_event::<PanStarted>()
+        .add_event::<PanEnded>()
+        .add_event::<ZoomChanged>()
+        .add_event::<CameraIdle>()
+        .add_event::<SnappedToView>()
+        .add_event::<InjectedInput>()
+        .add_event::<ApplyViewCommand>()
+        .add_event::<CameraRecovered>()
+        .add_systems(
+            PreUpdate,
+            (replay::play_input_replay, replay::inject_input)
+                .chain()
+                .before(InputSystem),
+        )
+        .add_systems(
+            self.schedule,
+            (
                 (
-                    (
-                        active_viewport_data
-                            .run_if(|active_cam: Res<ActiveCameraData>| !active_cam.manual),
-                        mouse_key_tracker,
-                        touch_tracker,
-                    ),
-                    pan_orbit_camera,
-                )
-                    .chain()
-                    .in_set(PanOrbitCameraSystemSet)
+                    active_viewport_data
+                        .run_if(|active_cam: Res<ActiveCameraData>| !active_cam.manual),
+                    mouse_key_tracker,
+                    hover_tracker,
+                    touch_tracker,
+                    gamepad_tracker,
+                    edge_pan_tracker,
+                    follow_target,
+                    auto_frame,
+                    idle_drift,
+                    snap_to_sticky_views,
+                    run_navigation_scripts,
+                ),
+                pan_orbit_camera,
+            )
+                .chain()
+                .in_set(PanOrbitCameraSystemSet)
+                .before(TransformSystem::TransformPropagate)
+                .before(CameraUpdateSystem),
+        )
+        .add_systems(
+            self.schedule,
+            (
+                // After following, which starts the camera on its target
+                initialize_cameras
+                    .after(follow_target)
+                    .before(pan_orbit_camera),
+                apply_camera_input
+                    .after(initialize_cameras)
+                    .after(run_navigation_scripts)
+                    .before(pan_orbit_camera),
+                // Last before updating, to catch bad values from the app and the systems above
+                recover_invalid_cameras
+                    .after(apply_camera_input)
+                    .after(sync_cameras)
+                    .before(pan_orbit_camera),
+                recover_invalid_transforms
+                    .after(pan_orbit_camera)
+                    .before(events::send_camera_events),
+                update_guided_mode
+                    .after(run_navigation_scripts)
+                    .before(pan_orbit_camera),
+                cursor_grab.after(mouse_key_tracker),
+                apply_view_commands
+                    .after(run_navigation_scripts)
+                    .before(update_guided_mode),
+                sync_cameras
+                    .after(update_guided_mode)
+                    .before(pan_orbit_camera),
+                apply_sensitivity_curves
+                    .after(mouse_key_tracker)
+                    .before(pan_orbit_camera),
+            )
+                .in_set(PanOrbitCameraSystemSet),
+        )
+        .add_systems(
+            self.schedule,
+            // Uses the same input as `pan_orbit_camera`
+            camera_2d::pan_zoom_camera_2d
+                .after(pan_orbit_camera)
+                .in_set(PanOrbitCameraSystemSet)
+                .before(TransformSystem::TransformPropagate)
+                .before(CameraUpdateSystem),
+        )
+        .add_systems(
+            self.schedule,
+            events::send_camera_events
+                .after(pan_orbit_camera)
+                .in_set(PanOrbitCameraSystemSet),
+        )
+        .add_systems(
+            PostUpdate,
+            // Capture the matrices the frame is rendered with, whichever schedule the cameras
+            // update in
+            capture::capture_matrices
+                .after(TransformSystem::TransformPropagate)
+                .after(CameraUpdateSystem),
+        );
+
+        // Cameras spawned after the camera systems have run, or before a `FixedUpdate` that
+        // doesn't run this frame, still need to be in place before the frame is rendered
+        if self.schedule != PostUpdate.intern() {
+            app.add_systems(
+                PostUpdate,
+                initialize_cameras
                     .before(TransformSystem::TransformPropagate)
                     .before(CameraUpdateSystem),
             );
+        }
+
+        #[cfg(feature = "bevy_gizmos")]
+        {
+            app.register_type::<PanOrbitGizmos>()
+                .register_type::<PanOrbitGhost>()
+                .register_type::<PanOrbitPivotIndicator>();
+        }
+
+        #[cfg(feature = "cursor_icon")]
+        {
+            app.register_type::<PanOrbitCursorIcons>().add_systems(
+                self.schedule,
+                cursor::update_cursor_icons.after(PanOrbitCameraSystemSet),
+            );
+        }
+
+        #[cfg(feature = "view_cube")]
+        {
+            app.register_type::<ViewCube>().add_systems(
+                self.schedule,
+                (
+                    view_cube::setup_view_cube,
+                    view_cube::view_cube_input
+                        .after(active_viewport_data)
+                        .before(mouse_key_tracker)
+                        .in_set(PanOrbitCameraSystemSet),
+                ),
+            );
+        }
+
+        #[cfg(feature = "bevy_picking")]
+        {
+            app.register_type::<PanOrbitCollision>()
+                .register_type::<IgnoreCameraCollision>()
+                .register_type::<PanOrbitPickPivot>()
+                .register_type::<PanOrbitCursorDepth>()
+                .register_type::<PanOrbitPivotRaise>()
+                .add_systems(
+                    self.schedule,
+                    (
+                        // Ray casts need the mesh assets, which apps without rendering don't have
+                        collision::camera_collision
+                            .run_if(resource_exists::<Assets<Mesh>>)
+                            .after(follow_target)
+                            .before(pan_orbit_camera)
+                            .in_set(PanOrbitCameraSystemSet),
+                        pivot::pick_pivot
+                            .run_if(resource_exists::<Assets<Mesh>>)
+                            .after(mouse_key_tracker)
+                            .before(pan_orbit_camera)
+                            .in_set(PanOrbitCameraSystemSet),
+                        pivot::ignore_reset_on_hits
+                            .run_if(resource_exists::<Assets<Mesh>>)
+                            .after(mouse_key_tracker)
+                            .before(pan_orbit_camera)
+                            .in_set(PanOrbitCameraSystemSet),
+                        depth::update_cursor_depth
+                            .run_if(resource_exists::<Assets<Mesh>>)
+                            .before(pan_orbit_camera)
+                            .in_set(PanOrbitCameraSystemSet),
+                        pivot::raise_pivot
+                            .run_if(resource_exists::<Assets<Mesh>>)
+                            .after(run_navigation_scripts)
+                            .before(pan_orbit_camera)
+                            .in_set(PanOrbitCameraSystemSet),
+                    ),
+                );
+        }
 
         #[cfg(feature = "bevy_egui")]
         {
             app.init_resource::<EguiWantsFocus>()
-                .init_resource::<EguiFocusIncludesHover>()
+                .init_resource::<EguiFocusIncludesHover>();
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Loading curves needs the asset server, which apps without `AssetPlugin` don't have.
+        // Checking here rather than in `build` means it doesn't matter which plugin is added first.
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<ResponseCurve>()
+                .init_asset_loader::<ResponseCurveLoader>();
+        }
+
+        // Gizmo groups need `GizmoPlugin`, which apps without rendering don't have
+        #[cfg(feature = "bevy_gizmos")]
+        if app.is_plugin_added::<bevy::gizmos::GizmoPlugin>() {
+            app.init_gizmo_group::<PanOrbitGizmoConfigGroup>()
                 .add_systems(
-                    PostUpdate,
-                    egui::check_egui_wants_focus
-                        .after(EguiPreUpdateSet::InitContexts)
-                        .before(PanOrbitCameraSystemSet),
+                    self.schedule,
+                    (
+                        gizmos::draw_gizmos,
+                        ghost::draw_ghosts,
+                        indicator::draw_pivot_indicators,
+                    )
+                        .after(PanOrbitCameraSystemSet),
                 );
         }
+
+        #[cfg(feature = "view_cube")]
+        if app.is_plugin_added::<bevy::gizmos::GizmoPlugin>() {
+            app.insert_gizmo_config(
+                ViewCubeGizmoConfigGroup,
+                GizmoConfig {
+                    render_layers: RenderLayers::layer(VIEW_CUBE_LAYER),
+                    ..default()
+                },
+            )
+            .add_systems(
+                self.schedule,
+                view_cube::update_view_cube
+                    .after(PanOrbitCameraSystemSet)
+                    .before(TransformSystem::TransformPropagate)
+                    .before(CameraUpdateSystem),
+            );
+        }
+
+        // Reading egui's contexts needs `EguiPlugin`; without it egui never wants focus
+        #[cfg(feature = "bevy_egui")]
+        if app.is_plugin_added::<bevy_egui::EguiPlugin>() {
+            app.add_systems(
+                self.schedule,
+                egui::check_egui_wants_focus
+                    .after(EguiPreUpdateSet::InitContexts)
+                    .before(PanOrbitCameraSystemSet),
+            );
+        }
     }
 }
 
@@ -83,6 +484,9 @@ pub struct PanOrbitCameraSystemSet;
 
 /// Tags an entity as capable of panning and orbiting, and provides a way to configure the
 /// camera's behaviour and controls.
+///
+/// With the `serialize` feature, serializing a camera whose `smoothing_curves` use
+/// `SmoothingCurve::Custom` fails, as functions can't be serialized.
 /// # Example
 /// ```no_run
 /// # use bevy::prelude::*;
@@ -90,7 +494,7 @@ pub struct PanOrbitCameraSystemSet;
 /// # fn main() {
 /// #     App::new()
 /// #         .add_plugins(DefaultPlugins)
-/// #         .add_plugins(PanOrbitCameraPlugin)
+/// #         .add_plugins(PanOrbitCameraPlugin::default())
 /// #         .add_systems(Startup, setup)
 /// #         .run();
 /// # }
@@ -102,8 +506,10 @@ pub struct PanOrbitCameraSystemSet;
 ///         ));
 ///  }
 /// ```
-#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
-#[require(Camera3d)]
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+#[require(Camera3d, TimeSinceInput)]
 pub struct PanOrbitCamera {
     /// The point to orbit around, and what the camera looks at. Updated automatically.
     /// If you want to change the focus programmatically after initialization, set `target_focus`
@@ -133,6 +539,14 @@ pub struct PanOrbitCamera {
     /// You should not update this after initialization - use `target_pitch` instead.
     /// Defaults to `None`.
     pub pitch: Option<f64>,
+    /// Rotation in radians around the camera's view direction, tilting the horizon. Positive
+    /// values turn the view clockwise. Updated automatically.
+    /// Set this before initialization to start with a tilted horizon. After that, use
+    /// `target_roll` instead.
+    /// In `OrbitMode::Trackball`, roll is part of `orientation` instead, which starts from this
+    /// value.
+    /// Defaults to `0.0`.
+    pub roll: f64,
     /// The target focus point. The camera will smoothly transition to this value. Updated
     /// automatically, but you can also update it manually to control the camera independently of
     /// the mouse controls, e.g. with the keyboard.
@@ -148,6 +562,11 @@ pub struct PanOrbitCamera {
     /// the mouse controls, e.g. with the keyboard.
     /// Defaults to `0.0`.
     pub target_pitch: f64,
+    /// The target roll value. The camera will smoothly transition to this value, using
+    /// `orbit_smoothness`. Updated automatically by roll drags (see `PanOrbitInputMap::roll`),
+    /// but you can also update it manually.
+    /// Defaults to `0.0`.
+    pub target_roll: f64,
     /// The target radius value. The camera will smoothly transition to this value. Updated
     /// automatically, but you can also update it manually to control the camera independently of
     /// the mouse controls, e.g. with the keyboard.
@@ -176,6 +595,11 @@ pub struct PanOrbitCamera {
     /// `focus_bounds_origin`.
     /// Defaults to `None`.
     pub focus_bounds_shape: Option<FocusBoundsShape>,
+    /// Whether the camera itself is kept inside `focus_bounds_shape`, as well as its focus. The
+    /// target radius is reduced where needed, so zooming out stops at the edge of the bounds,
+    /// though never below `zoom_lower_limit`. Only applies to perspective projections.
+    /// Defaults to `false`.
+    pub bound_camera_position: bool,
     /// Upper limit on the zoom. This applies to `radius`, in the case of using a perspective
     /// camera, or the projection's scale in the case of using an orthographic camera.
     /// Defaults to `None`.
@@ -185,13 +609,21 @@ pub struct PanOrbitCamera {
     /// Should always be >0 otherwise you'll get stuck at 0.
     /// Defaults to `0.05`.
     pub zoom_lower_limit: f64,
+    /// If set, the near and far clipping planes follow the zoom, so that zooming from far out down
+    /// to small details neither clips nearby geometry nor causes z-fighting. This overwrites the
+    /// projection's `near` and `far`. See `AutoClipPlanes`.
+    /// Defaults to `None`.
+    pub auto_clip_planes: Option<AutoClipPlanes>,
     /// The sensitivity of the orbiting motion. A value of `0.0` disables orbiting.
+    /// At `1.0`, dragging across the full width of the window turns the camera 360 degrees, and
+    /// dragging across its full height tilts it 180 degrees. See
+    /// `PanOrbitCamera::set_orbit_degrees_per_width` to set it in those terms instead.
     /// Defaults to `1.0`.
     pub orbit_sensitivity: f64,
     /// How much smoothing is applied to the orbit motion. A value of `0.0` disables smoothing,
     /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
     /// smoothing.
-    /// Defaults to `0.8`.
+    /// Defaults to `0.1`.
     pub orbit_smoothness: f64,
     /// The sensitivity of the panning motion. A value of `0.0` disables panning.
     /// Defaults to `1.0`.
@@ -199,7 +631,7 @@ pub struct PanOrbitCamera {
     /// How much smoothing is applied to the panning motion. A value of `0.0` disables smoothing,
     /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
     /// smoothing.
-    /// Defaults to `0.6`.
+    /// Defaults to `0.02`.
     pub pan_smoothness: f64,
     /// The sensitivity of moving the camera closer or further way using the scroll wheel.
     /// A value of `0.0` disables zooming.
@@ -208,33 +640,77 @@ pub struct PanOrbitCamera {
     /// How much smoothing is applied to the zoom motion. A value of `0.0` disables smoothing,
     /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
     /// smoothing.
-    /// Defaults to `0.8`.
+    /// Defaults to `0.1`.
     /// Note that this setting does not apply to pixel-based scroll events, as they are typically
     /// already smooth. It only applies to line-based scroll events.
     pub zoom_smoothness: f64,
-    /// Button used to orbit the camera.
-    /// Defaults to `Button::Left`.
-    pub button_orbit: MouseButton,
-    /// Button used to pan the camera.
-    /// Defaults to `Button::Right`.
-    pub button_pan: MouseButton,
-    /// Key that must be pressed for `button_orbit` to work.
-    /// Defaults to `None` (no modifier).
-    pub modifier_orbit: Option<KeyCode>,
-    /// Key that must be pressed for `button_pan` to work.
-    /// Defaults to `None` (no modifier).
-    pub modifier_pan: Option<KeyCode>,
+    /// Smoothing to use instead of `orbit_smoothness`, `pan_smoothness`, and `zoom_smoothness`
+    /// while the camera is being controlled with a particular device, e.g. less smoothing for
+    /// touch, where the finger itself smooths the motion. See `DeviceSmoothing`.
+    /// Defaults to no overrides, so every device uses the camera's own smoothing.
+    pub device_smoothing: DeviceSmoothing,
+    /// The shape of the smoothing for orbiting, panning, and zooming, e.g. exponential or a
+    /// spring. The amount of smoothing is still set by the smoothness values. See
+    /// `SmoothingCurves`. Custom curves can't be serialized.
+    /// Defaults to the exponential curve for all three.
+    pub smoothing_curves: SmoothingCurves,
+    /// How fast each value is moving towards its target when its smoothing curve is
+    /// `SmoothingCurve::Spring`. Updated automatically.
+    /// Defaults to `SpringVelocity::default()`.
+    pub spring_velocity: SpringVelocity,
+    /// How input from long frames is spread over the following frames, so a hitch doesn't make
+    /// the camera jump. `None` applies all input as soon as it arrives. See `InputPacing`.
+    /// Defaults to `Some(InputPacing::default())`.
+    pub input_pacing: Option<InputPacing>,
+    /// Input from long frames that hasn't been applied yet, see `input_pacing`. Updated
+    /// automatically.
+    /// Defaults to `PendingInput::default()`.
+    pub pending_input: PendingInput,
+    /// The input device that last moved the camera, which chooses the smoothing from
+    /// `device_smoothing`. Updated automatically.
+    /// Defaults to `InputDevice::Mouse`.
+    pub input_device: InputDevice,
+    /// The mouse and scroll wheel controls. Each action can have multiple bindings, and each
+    /// binding can require modifier keys. See `PanOrbitInputMap`.
+    /// Defaults to Left Mouse to orbit, Right Mouse to pan, and vertical scrolling to zoom.
+    pub input_map: PanOrbitInputMap,
+    /// Whether mouse and touch sensitivity adapts to the display. When `true`, orbiting is scaled
+    /// by the window size and panning by the viewport size, and mouse motion is converted to
+    /// logical pixels using the window's scale factor, so drags feel the same on displays of any
+    /// size and DPI. When `false`, raw input deltas are used and scaled as if the window and
+    /// viewport were always 1280x720.
+    /// Defaults to `true`.
+    pub adaptive_sensitivity: bool,
     /// Whether touch controls are enabled.
     /// Defaults to `true`.
     pub touch_enabled: bool,
     /// The control scheme for touch inputs.
-    /// Defaults to `TouchControls::OneFingerOrbit`.
+    /// Defaults to `TouchControls::one_finger_orbit()`.
     pub touch_controls: TouchControls,
+    /// Gamepad controls. Unlike mouse and touch input, gamepad input is not limited to the
+    /// active camera - it controls every camera that has this set. See `GamepadControls`.
+    /// Defaults to `None` (gamepad disabled).
+    pub gamepad_controls: Option<GamepadControls>,
+    /// Keyboard controls, which orbit, pan, and zoom the active camera with the arrow keys by
+    /// default. See `KeyboardControls`.
+    /// Defaults to `None` (keyboard disabled).
+    pub keyboard_controls: Option<KeyboardControls>,
+    /// RTS style panning when the cursor is near the edge of the viewport. See `EdgePan`.
+    /// Defaults to `None` (edge panning disabled).
+    pub edge_pan: Option<EdgePan>,
+    /// Where this camera's image is shown, for cameras that render to an image that's displayed
+    /// in a window, e.g. a 3D preview in a UI panel. Input inside the region controls the camera,
+    /// and the cursor is mapped into the image so that e.g. zooming to the cursor works. Keep this
+    /// up to date if the image moves. See `InputRegion`.
+    /// Defaults to `None`.
+    pub input_region: Option<InputRegion>,
     /// The behavior for trackpad inputs.
     /// Defaults to `TrackpadBehavior::DefaultZoom`.
     /// To enable orbit behavior similar to Blender, change this to TrackpadBehavior::BlenderLike.
-    /// For `BlenderLike` panning, add `ShiftLeft` to the `modifier_pan` field.
-    /// For `BlenderLike` zooming, add `ControlLeft` in `modifier_zoom` field.
+    /// To make scrolling pan instead, like in map viewers, use TrackpadBehavior::ScrollPans.
+    /// For `BlenderLike` panning, set `modifier_pan` to `ShiftLeft`.
+    /// For `BlenderLike` zooming, set `modifier_zoom` to `ControlLeft`.
+    /// You probably also want to use `PanOrbitInputMap::blender()` for the mouse controls.
     pub trackpad_behavior: TrackpadBehavior,
     /// Whether to enable pinch-to-zoom functionality on trackpads.
     /// Defaults to `false`.
@@ -244,20 +720,225 @@ pub struct PanOrbitCamera {
     /// operations when using a trackpad with the `BlenderLike` behavior mode.
     /// Defaults to `1.0`.
     pub trackpad_sensitivity: f64,
+    /// How to decide between gestures that could match the same input, e.g. orbit and pan
+    /// bindings on the same button, or a pinch and a two finger drag. See `GestureConflicts`.
+    /// Defaults to `GestureConflicts::default()`.
+    pub gesture_conflicts: GestureConflicts,
     /// Whether to reverse the zoom direction.
     /// Defaults to `false`.
     pub reversed_zoom: bool,
+    /// Whether to zoom towards the point under the cursor, rather than towards `focus`.
+    /// The point is found by projecting the cursor onto the plane that passes through
+    /// `target_focus` and faces the camera, and `target_focus` is moved towards it in proportion
+    /// to the change in radius, so that the point under the cursor stays put.
+    /// Has no effect on touch or trackpad pinch zooming.
+    /// Defaults to `false`.
+    pub zoom_to_cursor: bool,
+    /// What zooming changes on a perspective camera: the radius, the field of view, or both at
+    /// once for a dolly zoom. Orthographic cameras always change their scale. See `ZoomMode`.
+    /// Defaults to `ZoomMode::Dolly`.
+    pub zoom_mode: ZoomMode,
+    /// Which extent of the viewport framing fits to, i.e. in `PanOrbitCamera::framing_pose`,
+    /// `PanOrbitAutoFrame`, and box zoom. See `FramingFit`.
+    /// Defaults to `FramingFit::Both`.
+    pub framing_fit: FramingFit,
+    /// Parts of the viewport covered by UI, e.g. a side panel, so that framing centres things in
+    /// the region that's still visible. Used in the same places as `framing_fit`, and by
+    /// `PanOrbitCamera::safe_region_pose` for other views such as bookmarks.
+    /// Defaults to no insets.
+    pub framing_insets: FramingInsets,
+    /// The view that the camera returns to when `reset_gesture` is performed, or when
+    /// `PanOrbitCamera::reset_to_home` is called. If `None`, it will be set to the camera's initial
+    /// view during initialization.
+    /// Set this directly to define a canonical starting view, or use
+    /// `PanOrbitCamera::save_home_view` to update it to the current view at runtime.
+    /// Defaults to `None`.
+    pub home_view: Option<CameraPose>,
+    /// Named views that can be saved with `PanOrbitCamera::save_view` and animated back to with
+    /// `PanOrbitCamera::recall_view`, e.g. for numbered viewpoints.
+    /// Defaults to no views.
+    pub saved_views: CameraViews,
+    /// The gesture that smoothly moves the camera back to `home_view`. Set to `None` to disable.
+    /// With the `bevy_picking` feature, a double-click only resets the camera when it lands on
+    /// empty space, i.e. no visible mesh and nothing else the app's picking backends report.
+    /// Without it, there's no way to tell what was clicked, so a double-click anywhere resets.
+    /// Defaults to `Some(ResetGesture::DoubleClick(MouseButton::Left))`.
+    pub reset_gesture: Option<ResetGesture>,
+    /// What happens to gestures in progress when the window loses focus, e.g. from alt-tabbing
+    /// mid-drag. Only the policy of the camera receiving input (see `ActiveCameraData`) is used,
+    /// and only that camera is affected. See `FocusLossPolicy`.
+    /// Defaults to `FocusLossPolicy::CancelGestures`.
+    pub focus_loss_policy: FocusLossPolicy,
+    /// What the cursor does while orbiting, panning, or rolling with a mouse drag, e.g. hiding
+    /// it so long drags aren't cut short by the window's edges. See `DragCursor`.
+    /// Defaults to `DragCursor::Free`.
+    pub drag_cursor: DragCursor,
+    /// Whether the camera orbits around `focus`, or flies around freely. See `ControlMode`.
+    /// Defaults to `ControlMode::Orbit`.
+    pub control_mode: ControlMode,
+    /// Whether orbiting keeps the camera upright, or tumbles it freely. See `OrbitMode`.
+    /// Defaults to `OrbitMode::Turntable`.
+    pub orbit_mode: OrbitMode,
+    /// The camera's current rotation in `OrbitMode::Trackball`, including any roll. Updated
+    /// automatically, and `None` in `OrbitMode::Turntable`.
+    /// Defaults to `None`.
+    pub orientation: Option<DQuat>,
+    /// The rotation the camera is moving towards in `OrbitMode::Trackball`. Updated
+    /// automatically, and `None` in `OrbitMode::Turntable`. Use
+    /// `PanOrbitCamera::set_target_orientation` to change it, so that `target_yaw` and
+    /// `target_pitch` stay in sync.
+    /// Defaults to `None`.
+    pub target_orientation: Option<DQuat>,
+    /// How long `PanOrbitCamera::set_projection_mode` takes to switch between perspective and
+    /// orthographic.
+    /// Defaults to 0.5 seconds.
+    pub projection_switch_duration: Duration,
+    /// The vertical field of view, in radians, that `PanOrbitCamera::set_projection_mode` switches
+    /// back to when going from orthographic to perspective. Updated automatically when switching
+    /// away from perspective.
+    /// Defaults to `PI / 4.0`, the same as `PerspectiveProjection`.
+    pub perspective_fov: f32,
+    /// The other settings of the projections that `PanOrbitCamera::set_projection_mode` switches
+    /// between, e.g. their clip planes. Updated automatically when switching away from a
+    /// projection, so that switching back restores it.
+    /// Defaults to `SavedProjections::default()`.
+    pub saved_projections: SavedProjections,
+    /// The narrowest vertical field of view, in radians, that scrolling with
+    /// `PanOrbitInputMap::scroll_fov` can reach.
+    /// Defaults to 5 degrees.
+    pub fov_lower_limit: f32,
+    /// The widest vertical field of view, in radians, that scrolling with
+    /// `PanOrbitInputMap::scroll_fov` can reach.
+    /// Defaults to 120 degrees.
+    pub fov_upper_limit: f32,
+    /// The switch between projections in progress, if any. Use
+    /// `PanOrbitCamera::set_projection_mode` to start one.
+    /// Defaults to `None`.
+    pub projection_switch: Option<ProjectionSwitch>,
+    /// Key that toggles between `ControlMode::Orbit` and `ControlMode::Fly`.
+    /// Defaults to `None` (no key).
+    pub key_toggle_fly: Option<KeyCode>,
+    /// The keys used to move the camera in `ControlMode::Fly`.
+    /// Defaults to WASD, with E and Q for up and down.
+    pub fly_keys: FlyKeys,
+    /// How fast the camera moves in `ControlMode::Fly`, in units per second.
+    /// Defaults to `5.0`.
+    pub fly_speed: f64,
+    /// The animation in progress, if any. Use `PanOrbitCamera::animate_to` to start one.
+    /// Defaults to `None`.
+    pub animation: Option<PoseAnimation>,
+    /// Whether the camera keeps moving after an orbit or pan drag, or a pinch zoom, is released,
+    /// with a master switch and settings for each action. See `Momentum`.
+    /// Defaults to momentum being disabled.
+    pub momentum: Momentum,
+    /// Slowly spin the camera around the focus while the user isn't interacting with it, like a
+    /// turntable. `None` disables auto-rotation. See `AutoRotate`.
+    /// Defaults to `None`.
+    pub auto_rotate: Option<AutoRotate>,
+    /// Slowly reframe the camera onto nearby geometry while the user isn't interacting with it.
+    /// `None` disables drifting. See `IdleDrift`.
+    /// Defaults to `None`.
+    pub idle_drift: Option<IdleDrift>,
+    /// Snap onto axis-aligned views, e.g. top or front, when the user stops orbiting close to
+    /// one. `None` disables snapping. See `StickyViews`.
+    /// Defaults to `None`.
+    pub sticky_views: Option<StickyViews>,
+    /// The axis-aligned view the camera is snapped onto with `sticky_views`, until it's orbited
+    /// away. Updated automatically.
+    /// Defaults to `None`.
+    pub snapped_view: Option<StandardView>,
+    /// How fast the camera is orbiting, in radians per second of yaw (x) and pitch (y). Tracked
+    /// during drags, and used for momentum after they are released. Updated automatically.
+    /// Defaults to `DVec2::ZERO`.
+    pub orbit_velocity: DVec2,
+    /// How fast the camera's focus is panning, in units per second. Tracked during drags, and
+    /// used for momentum after they are released. Updated automatically.
+    /// Defaults to `DVec3::ZERO`.
+    pub pan_velocity: DVec3,
+    /// How fast the camera is zooming, as the rate of change of the natural logarithm of the
+    /// radius, per second, so `-1.0` shrinks the radius by a factor of `e` every second. Tracked
+    /// during pinch and trackpad zooms, and used for momentum after they end. Updated
+    /// automatically.
+    /// Defaults to `0.0`.
+    pub zoom_velocity: f64,
+    /// The distance from the focus to the nearest obstruction between the focus and the camera.
+    /// While set, the camera is kept at most this far from the focus, without changing
+    /// `target_radius`, so it smoothly moves back out once the obstruction is gone. This is kept
+    /// up to date by `PanOrbitCollision` (requires the `bevy_picking` feature), or you can set it
+    /// yourself, e.g. from your physics engine's ray casts, in a system that runs before
+    /// `PanOrbitCameraSystemSet`. Has no effect on orthographic cameras.
+    /// Defaults to `None`.
+    pub obstruction_distance: Option<f64>,
+    /// The depth of the geometry under the cursor, i.e. its distance from the camera along the
+    /// view direction. While set, panning and zooming speed scale with this depth instead of the
+    /// radius, so panning over a small detail is slow and panning across a whole city is fast.
+    /// Zoom steps only ever get smaller, so a scroll can't jump past the focus. This is kept up to
+    /// date by `PanOrbitCursorDepth` (requires the `bevy_picking` feature), or you can set it
+    /// yourself, e.g. from a depth buffer read back, in a system that runs before
+    /// `PanOrbitCameraSystemSet`. Has no effect on orthographic cameras.
+    /// Defaults to `None`.
+    pub cursor_depth: Option<f64>,
+    /// The rectangle being drawn by a box zoom drag (see `PanOrbitInputMap::box_zoom`), in
+    /// logical pixels relative to the viewport's top left corner. Use this to draw the rectangle,
+    /// e.g. with a UI node. Updated automatically.
+    /// Defaults to `None`.
+    pub box_zoom_rect: Option<Rect>,
+    /// The gesture the user is currently making with this camera, e.g. for showing a matching
+    /// cursor icon. Updated automatically.
+    /// Defaults to `CameraGesture::None`.
+    pub gesture: CameraGesture,
+    /// Whether the cursor is over this camera's viewport. Where viewports overlap, only the one
+    /// with the highest `Camera::order` is hovered. Updated automatically.
+    /// Defaults to `false`.
+    pub hovered: bool,
+    /// The part of the window this camera's image covers, in logical pixels from the window's
+    /// top left, e.g. for lining up UI with it. This is the camera's viewport, or where its image
+    /// is shown with `input_region`, leaving out any letterbox bars. Cursor positions, like
+    /// `box_zoom_rect`, are relative to its top left corner. `None` if the camera doesn't render
+    /// to a window or `input_region`. Updated automatically.
+    /// Defaults to `None`.
+    pub render_rect: Option<Rect>,
     /// Whether the camera is currently upside down. Updated automatically.
     /// This is used to determine which way to orbit, because it's more intuitive to reverse the
     /// orbit direction when upside down.
     /// Should not be set manually unless you know what you're doing.
     /// Defaults to `false` (but will be updated immediately).
     pub is_upside_down: bool,
-    /// Whether to allow the camera to go upside down.
+    /// Whether to allow the camera to go upside down. When `true` (and there are no pitch
+    /// limits), pitch is unrestricted, so orbiting vertically tumbles continuously over the top
+    /// and bottom of the focus, flipping the camera's up direction as it passes each pole.
     /// Defaults to `false`.
     pub allow_upside_down: bool,
     /// If `false`, disable control of the camera. Defaults to `true`.
     pub enabled: bool,
+    /// Whether the user can orbit and roll the camera, with any input device. Like the other
+    /// per-action settings, this can be changed at any time, e.g. to restrict the camera during a
+    /// cutscene, and doesn't affect moving the camera from code, e.g. with `animate_to`.
+    /// Defaults to `true`.
+    pub orbit_enabled: bool,
+    /// Whether the user can pan the camera, with any input device, e.g. `false` for a turntable
+    /// product viewer.
+    /// Defaults to `true`.
+    pub pan_enabled: bool,
+    /// Whether the user can zoom the camera, with any input device.
+    /// Defaults to `true`.
+    pub zoom_enabled: bool,
+    /// Whether orbiting left and right is locked, so the user can only orbit up and down.
+    /// Defaults to `false`.
+    pub lock_yaw: bool,
+    /// Whether orbiting up and down is locked, so the user can only orbit left and right.
+    /// Defaults to `false`.
+    pub lock_pitch: bool,
+    /// Whether control has been handed to a cutscene/animation system. Use
+    /// `PanOrbitCamera::start_cutscene` and `PanOrbitCamera::end_cutscene` rather than setting
+    /// this directly.
+    /// Defaults to `CutsceneState::Inactive`.
+    pub cutscene: CutsceneState,
+    /// Whether the user's control is restricted while a guided sequence plays. Use
+    /// `PanOrbitCamera::enter_guided_mode` and `PanOrbitCamera::exit_guided_mode` rather than
+    /// setting this directly.
+    /// Defaults to `GuidedState::Inactive`.
+    pub guided: GuidedState,
     /// Whether `PanOrbitCamera` has been initialized with the initial config.
     /// Set to `true` if you want the camera to smoothly animate to its initial position.
     /// Defaults to `false`.
@@ -270,9 +951,44 @@ pub struct PanOrbitCamera {
     /// Axis order definition. This can be used to e.g. define a different default
     /// up direction. The default up is Y, but if you want the camera rotated.
     /// The axis can be switched. Default is [Vec3::X, Vec3::Y, Vec3::Z]
+    /// The axes are right, up, and back: at zero yaw and pitch the camera sits along `axis[2]`
+    /// from the focus, pitch tilts it towards `axis[1]`, and yaw turns it towards `axis[0]`.
+    /// Mirrored (left-handed) axes such as `[DVec3::NEG_X, DVec3::Y, DVec3::Z]` are supported, e.g.
+    /// to match the yaw convention of imported CAD data. Orbiting and panning still follow the
+    /// mouse on screen, and yaw/pitch derived from the camera's initial position are consistent
+    /// with the mapping.
     pub axis: [DVec3; 3],
+    /// The local axes used for panning, i.e. which axis of the camera's rotation is 'right'
+    /// (first element) and 'up' (second element).
+    /// Default is [Vec3::X, Vec3::Y, Vec3::Z]
     pub pan_axis: [DVec3; 3],
+    /// How far the site, e.g. a building in BIM or GIS data, is turned from `axis`, in radians in
+    /// the direction of increasing yaw. Yaw, yaw limits, and standard views are measured from the
+    /// site's axes, while `PanOrbitCamera::heading` stays relative to `axis`. Use this for the
+    /// difference between project north and true north, instead of changing `axis`.
+    /// Defaults to `0.0`.
+    pub site_rotation: f64,
+    /// Whether panning moves the focus in the view plane, or along a plane in the world such as
+    /// the ground. See `PanMode`.
+    /// Defaults to `PanMode::ViewPlane`.
+    pub pan_mode: PanMode,
+    /// The camera's position, in full precision. Updated automatically.
+    /// If the camera's `Transform` has a translation of zero during initialization, this is used
+    /// as the starting position instead.
+    /// Defaults to `Vec3::ZERO`.
     pub position: DVec3,
+    /// The origin of the camera's `Transform` space, in world space. This is subtracted from
+    /// `position` in full precision before it's written to the `Transform`, for floating origin
+    /// setups where the rendered world is shifted to keep the camera near the origin. Positions
+    /// such as `focus`, `position`, and the limits stay in world space, so move this along with
+    /// the rest of the rendered world when rebasing, and the camera won't move.
+    /// Defaults to `Vec3::ZERO`.
+    pub world_offset: DVec3,
+    /// The `world_offset` that the camera's `Transform` was last written with, so that the
+    /// `Transform` is updated when `world_offset` changes, even if the camera isn't moving.
+    /// Updated automatically.
+    /// Defaults to `None`.
+    pub applied_world_offset: Option<DVec3>,
 }
 
 impl Default for PanOrbitCamera {
@@ -281,6 +997,21 @@ impl Default for PanOrbitCamera {
             focus: DVec3::ZERO,
             target_focus: DVec3::ZERO,
             radius: None,
+            animation: None,
+            momentum: Momentum::default(),
+            auto_rotate: None,
+            idle_drift: None,
+            sticky_views: None,
+            snapped_view: None,
+            orbit_velocity: DVec2::ZERO,
+            pan_velocity: DVec3::ZERO,
+            zoom_velocity: 0.0,
+            obstruction_distance: None,
+            cursor_depth: None,
+            box_zoom_rect: None,
+            gesture: CameraGesture::None,
+            hovered: false,
+            render_rect: None,
             is_upside_down: false,
             allow_upside_down: false,
             orbit_sensitivity: 1.0,
@@ -289,21 +1020,62 @@ impl Default for PanOrbitCamera {
             pan_smoothness: 0.02,
             zoom_sensitivity: 1.0,
             zoom_smoothness: 0.1,
-            button_orbit: MouseButton::Left,
-            button_pan: MouseButton::Right,
-            modifier_orbit: None,
-            modifier_pan: None,
+            device_smoothing: DeviceSmoothing::default(),
+            smoothing_curves: SmoothingCurves::default(),
+            spring_velocity: SpringVelocity::default(),
+            input_pacing: Some(InputPacing::default()),
+            pending_input: PendingInput::default(),
+            input_device: InputDevice::Mouse,
+            input_map: PanOrbitInputMap::default(),
+            adaptive_sensitivity: true,
             touch_enabled: true,
-            touch_controls: TouchControls::OneFingerOrbit,
+            touch_controls: TouchControls::one_finger_orbit(),
+            gamepad_controls: None,
+            keyboard_controls: None,
+            edge_pan: None,
+            input_region: None,
             trackpad_behavior: TrackpadBehavior::Default,
             trackpad_pinch_to_zoom_enabled: false,
             trackpad_sensitivity: 1.0,
+            gesture_conflicts: GestureConflicts::default(),
             reversed_zoom: false,
+            zoom_to_cursor: false,
+            zoom_mode: ZoomMode::Dolly,
+            framing_fit: FramingFit::Both,
+            framing_insets: FramingInsets::default(),
+            home_view: None,
+            saved_views: CameraViews::default(),
+            reset_gesture: Some(ResetGesture::DoubleClick(MouseButton::Left)),
+            focus_loss_policy: FocusLossPolicy::CancelGestures,
+            drag_cursor: DragCursor::Free,
+            control_mode: ControlMode::Orbit,
+            orbit_mode: OrbitMode::Turntable,
+            orientation: None,
+            target_orientation: None,
+            projection_switch_duration: Duration::from_secs_f32(0.5),
+            perspective_fov: PI as f32 / 4.0,
+            saved_projections: SavedProjections::default(),
+            fov_lower_limit: 5f32.to_radians(),
+            fov_upper_limit: 120f32.to_radians(),
+            projection_switch: None,
+            pan_mode: PanMode::ViewPlane,
+            key_toggle_fly: None,
+            fly_keys: FlyKeys::default(),
+            fly_speed: 5.0,
             enabled: true,
+            orbit_enabled: true,
+            pan_enabled: true,
+            zoom_enabled: true,
+            lock_yaw: false,
+            lock_pitch: false,
+            cutscene: CutsceneState::Inactive,
+            guided: GuidedState::Inactive,
             yaw: None,
             pitch: None,
             target_yaw: 0.0,
             target_pitch: 0.0,
+            roll: 0.0,
+            target_roll: 0.0,
             target_radius: 1.0,
             initialized: false,
             yaw_upper_limit: None,
@@ -312,362 +1084,2429 @@ impl Default for PanOrbitCamera {
             pitch_lower_limit: None,
             focus_bounds_origin: DVec3::ZERO,
             focus_bounds_shape: None,
+            bound_camera_position: false,
             zoom_upper_limit: None,
             zoom_lower_limit: 0.05,
+            auto_clip_planes: None,
             force_update: false,
             axis: [DVec3::X, DVec3::Y, DVec3::Z],
             pan_axis: [DVec3::X, DVec3::Y, DVec3::Z],
+            site_rotation: 0.0,
             position: DVec3::ZERO,
+            world_offset: DVec3::ZERO,
+            applied_world_offset: None,
         }
     }
 }
 
-/// Tracks which `PanOrbitCamera` is active (should handle input events), along with the window
-/// and viewport dimensions, which are used for scaling mouse motion.
-/// `PanOrbitCameraPlugin` manages this resource automatically, in order to support multiple
-/// viewports/windows. However, if this doesn't work for you, you can take over and manage it
-/// yourself, e.g. when you want to control a camera that is rendering to a texture.
-#[derive(Resource, Default, Debug, PartialEq)]
-pub struct ActiveCameraData {
-    /// ID of the entity with `PanOrbitCamera` that will handle user input. In other words, this
-    /// is the camera that will move when you orbit/pan/zoom.
-    pub entity: Option<Entity>,
-    /// The viewport size. This is only used to scale the panning mouse motion. I recommend setting
-    /// this to the actual render target dimensions (e.g. the image or viewport), and changing
-    /// `PanOrbitCamera::pan_sensitivity` to adjust the sensitivity if required.
-    pub viewport_size: Option<Vec2>,
-    /// The size of the window. This is only used to scale the orbit mouse motion. I recommend
-    /// setting this to actual dimensions of the window that you want to control the camera from,
-    /// and changing `PanOrbitCamera::orbit_sensitivity` to adjust the sensitivity if required.
-    pub window_size: Option<Vec2>,
-    /// Indicates to `PanOrbitCameraPlugin` that it should not update/overwrite this resource.
-    /// If you are manually updating this resource you should set this to `true`.
-    /// Note that setting this to `true` will effectively break multiple viewport/window support
-    /// unless you manually reimplement it.
-    pub manual: bool,
-}
-
-/// The shape to restrict the camera's focus inside.
-#[derive(Clone, PartialEq, Debug, Reflect, Copy)]
-pub enum FocusBoundsShape {
-    /// Limit the camera's focus to a sphere centered on `focus_bounds_origin`.
-    Sphere(Sphere),
-    /// Limit the camera's focus to a cuboid centered on `focus_bounds_origin`.
-    Cuboid(Cuboid),
-}
+impl PanOrbitCamera {
+    /// The `orbit_sensitivity` at which dragging across the full width of the window turns the
+    /// camera by `degrees`. Vertical drags scale the same way, so dragging across the full
+    /// height tilts it by half as many degrees.
+    pub fn orbit_sensitivity_from_degrees_per_width(degrees: f64) -> f64 {
+        degrees / ORBIT_DEGREES_PER_WIDTH
+    }
 
-impl From<Sphere> for FocusBoundsShape {
-    fn from(value: Sphere) -> Self {
-        Self::Sphere(value)
+    /// How many degrees dragging across the full width of the window turns the camera, at the
+    /// given `orbit_sensitivity`. The inverse of `orbit_sensitivity_from_degrees_per_width`.
+    pub fn degrees_per_width_from_orbit_sensitivity(sensitivity: f64) -> f64 {
+        sensitivity * ORBIT_DEGREES_PER_WIDTH
     }
-}
 
-impl From<Cuboid> for FocusBoundsShape {
-    fn from(value: Cuboid) -> Self {
-        Self::Cuboid(value)
+    /// How many degrees dragging across the full width of the window currently turns the camera.
+    pub fn orbit_degrees_per_width(&self) -> f64 {
+        Self::degrees_per_width_from_orbit_sensitivity(self.orbit_sensitivity)
     }
-}
 
-/// Allows for changing the `TrackpadBehavior` from default to the way it works in Blender.
-/// In Blender the trackpad orbits when scrolling. If you hold down the `ShiftLeft`, it Pans and
-/// holding down `ControlLeft` will Zoom.
-#[derive(Clone, PartialEq, Debug, Reflect, Copy)]
-pub enum TrackpadBehavior {
-    /// Default touchpad behavior. I.e., no special gesture support, scrolling on the touchpad (vertically) will zoom, as it does with a mouse.
-    Default,
-    /// Blender-like touchpad behavior. Scrolling on the touchpad will orbit, and you can pinch to zoom. Optionally you can pan, or switch scroll to zoom, by holding down a modifier.
-    BlenderLike {
-        /// Modifier key that enables panning while scrolling
-        modifier_pan: Option<KeyCode>,
+    /// Set `orbit_sensitivity` so that dragging across the full width of the window turns the
+    /// camera by `degrees`. This doesn't depend on the window's resolution, as mouse motion is
+    /// already scaled by the window size (or by a fixed reference size when
+    /// `adaptive_sensitivity` is `false`).
+    pub fn set_orbit_degrees_per_width(&mut self, degrees: f64) {
+        self.orbit_sensitivity = Self::orbit_sensitivity_from_degrees_per_width(degrees);
+    }
 
-        /// Modifier key that enables panning while scrolling
-        modifier_zoom: Option<KeyCode>,
-    },
-}
+    /// The view the camera is currently moving towards, i.e. the target values.
+    pub fn target_pose(&self) -> CameraPose {
+        CameraPose {
+            focus: self.target_focus,
+            yaw: self.target_yaw,
+            pitch: self.target_pitch,
+            roll: self.target_roll,
+            orientation: self.target_orientation,
+            radius: self.target_radius,
+        }
+    }
 
-impl TrackpadBehavior {
-    /// Creates a `BlenderLike` variant with default modifiers (Shift for pan, Ctrl for zoom)
-    pub fn blender_default() -> Self {
-        Self::BlenderLike {
-            modifier_pan: Some(KeyCode::ShiftLeft),
-            modifier_zoom: Some(KeyCode::ControlLeft),
+    /// Set the target values from `pose`. The camera will smoothly transition to it. The pose's
+    /// `orientation` is only used in `OrbitMode::Trackball`, and without one, the trackball's
+    /// orientation is made from the pose's yaw, pitch, and roll.
+    pub fn set_target_pose(&mut self, pose: CameraPose) {
+        self.target_focus = pose.focus;
+        self.target_yaw = pose.yaw;
+        self.target_pitch = pose.pitch;
+        self.target_roll = pose.roll;
+        self.target_radius = pose.radius;
+        self.target_orientation = None;
+        if let (OrbitMode::Trackball, Some(orientation)) = (self.orbit_mode, pose.orientation) {
+            self.set_target_orientation(orientation);
         }
     }
-}
 
-/// Gather data about the active viewport, i.e. the viewport the user is interacting with.
-/// Enables multiple viewports/windows.
-#[allow(clippy::too_many_arguments)]
-fn active_viewport_data(
-    mut active_cam: ResMut<ActiveCameraData>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    key_input: Res<ButtonInput<KeyCode>>,
-    pinch_events: EventReader<PinchGesture>,
-    scroll_events: EventReader<MouseWheel>,
-    touches: Res<Touches>,
-    primary_windows: Query<&Window, With<PrimaryWindow>>,
-    other_windows: Query<&Window, Without<PrimaryWindow>>,
-    orbit_cameras: Query<(Entity, &Camera, &PanOrbitCamera)>,
-    #[cfg(feature = "bevy_egui")] egui_wants_focus: Res<EguiWantsFocus>,
-) {
-    let mut new_resource = ActiveCameraData::default();
-    let mut max_cam_order = 0;
+    /// The camera's current rotation, in full precision. Together with `position`, this is the
+    /// camera's transform in world space.
+    pub fn rotation(&self) -> DQuat {
+        self.orientation.unwrap_or_else(|| {
+            let yaw = self.yaw.unwrap_or(self.target_yaw);
+            let pitch = self.pitch.unwrap_or(self.target_pitch);
+            util::orbit_rotation(yaw, pitch, self.orbit_axis()) * DQuat::from_rotation_z(self.roll)
+        })
+    }
 
-    let mut has_input = false;
-    for (entity, camera, pan_orbit) in orbit_cameras.iter() {
-        let input_just_activated = input::orbit_just_pressed(pan_orbit, &mouse_input, &key_input)
-            || input::pan_just_pressed(pan_orbit, &mouse_input, &key_input)
-            || !pinch_events.is_empty()
-            || !scroll_events.is_empty()
-            || (touches.iter_just_pressed().count() > 0
-                && touches.iter_just_pressed().count() == touches.iter().count());
+    /// The axes that yaw and pitch are measured from, i.e. `axis` turned by `site_rotation`.
+    pub fn orbit_axis(&self) -> [DVec3; 3] {
+        util::rotate_axis_yaw(self.axis, self.site_rotation)
+    }
 
-        if input_just_activated {
-            has_input = true;
-            #[allow(unused_mut, unused_assignments)]
-            let mut should_get_input = true;
-            #[cfg(feature = "bevy_egui")]
-            {
-                should_get_input = !egui_wants_focus.prev && !egui_wants_focus.curr;
-            }
-            if should_get_input {
-                // First check if cursor is in the same window as this camera
-                if let RenderTarget::Window(win_ref) = camera.target {
-                    let Some(window) = (match win_ref {
-                        WindowRef::Primary => primary_windows.get_single().ok(),
-                        WindowRef::Entity(entity) => other_windows.get(entity).ok(),
-                    }) else {
-                        // Window does not exist - maybe it was closed and the camera not cleaned up
-                        continue;
-                    };
+    /// The compass direction the camera is facing, in radians clockwise from north, between `0.0`
+    /// and `TAU`. North is `-axis[2]` and east is `axis[0]`, regardless of `site_rotation`, so
+    /// with the default axes, north is -Z and east is +X.
+    pub fn heading(&self) -> f64 {
+        util::compass_heading(self.rotation(), self.axis)
+    }
 
-                    // Is the cursor/touch in this window?
-                    // Note: there's a bug in winit that causes `window.cursor_position()` to return
-                    // a `Some` value even if the cursor is not in this window, in very specific cases.
-                    // See: https://github.com/Plonq/bevy_panorbit_camera/issues/22
-                    if let Some(input_position) = window.cursor_position().or(touches
-                        .iter_just_pressed()
-                        .collect::<Vec<_>>()
-                        .first()
-                        .map(|touch| touch.position()))
-                    {
-                        // Now check if cursor is within this camera's viewport
-                        if let Some(Rect { min, max }) = camera.logical_viewport_rect() {
-                            // Window coordinates have Y starting at the bottom, so we need to reverse
-                            // the y component before comparing with the viewport rect
-                            let cursor_in_vp = input_position.x > min.x
-                                && input_position.x < max.x
-                                && input_position.y > min.y
-                                && input_position.y < max.y;
-
-                            // Only set if camera order is higher. This may overwrite a previous value
-                            // in the case the viewport is overlapping another viewport.
-                            if cursor_in_vp && camera.order >= max_cam_order {
-                                new_resource = ActiveCameraData {
-                                    entity: Some(entity),
-                                    viewport_size: camera.logical_viewport_size(),
-                                    window_size: Some(Vec2::new(window.width(), window.height())),
-                                    manual: false,
-                                };
-                                max_cam_order = camera.order;
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    /// Convert a point from world space to the camera's `Transform` space, by subtracting
+    /// `world_offset`.
+    pub fn to_transform_space(&self, point: DVec3) -> DVec3 {
+        point - self.world_offset
     }
 
-    if has_input {
-        active_cam.set_if_neq(new_resource);
+    /// Convert a point from the camera's `Transform` space to world space, by adding
+    /// `world_offset`. Use this for positions read from other entities' transforms.
+    pub fn from_transform_space(&self, point: Vec3) -> DVec3 {
+        point.as_dvec3() + self.world_offset
     }
-}
 
-/// Main system for processing input and converting to transformations
-fn pan_orbit_camera(
-    active_cam: Res<ActiveCameraData>,
-    mouse_key_tracker: Res<MouseKeyTracker>,
-    touch_tracker: Res<TouchTracker>,
-    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera, &mut Transform, &mut Projection)>,
-    time: Res<Time>,
-) {
-    for (entity, mut pan_orbit, mut transform, mut projection) in orbit_cameras.iter_mut() {
-        // Closures that apply limits to the yaw, pitch, and zoom values
-        let apply_zoom_limits = {
-            let zoom_upper_limit = pan_orbit.zoom_upper_limit;
-            let zoom_lower_limit = pan_orbit.zoom_lower_limit;
-            move |zoom: f64| zoom.clamp_optional(Some(zoom_lower_limit), zoom_upper_limit)
-        };
+    /// Set `target_orientation`, along with the `target_yaw` and `target_pitch` that look in the
+    /// same direction. Only has an effect in `OrbitMode::Trackball`.
+    pub fn set_target_orientation(&mut self, orientation: DQuat) {
+        let orientation = orientation.normalize();
+        (self.target_yaw, self.target_pitch) =
+            util::yaw_pitch_from_rotation(orientation, self.orbit_axis());
+        self.target_orientation = Some(orientation);
+    }
 
-        let apply_yaw_limits = {
-            let yaw_upper_limit = pan_orbit.yaw_upper_limit;
-            let yaw_lower_limit = pan_orbit.yaw_lower_limit;
-            move |yaw: f64| yaw.clamp_optional(yaw_lower_limit, yaw_upper_limit)
-        };
+    /// Smoothly switch to a perspective or orthographic projection over
+    /// `projection_switch_duration`. The field of view narrows (or widens) while the camera moves
+    /// back (or in), so that things at the focus stay the same size on screen throughout, and the
+    /// projection is swapped once the perspective is barely noticeable. The projection that's
+    /// switched to gets its settings from `saved_projections`, and the zoom limits are converted
+    /// between radius and orthographic scale along with the radius. Calling this again mid-switch
+    /// reverses from wherever the switch has got to.
+    pub fn set_projection_mode(&mut self, mode: ProjectionMode) {
+        self.projection_switch = Some(ProjectionSwitch {
+            to: mode,
+            progress: self.projection_switch.and_then(|switch| switch.progress),
+        });
+        self.force_update = true;
+    }
 
-        let apply_pitch_limits = {
-            let pitch_upper_limit = pan_orbit.pitch_upper_limit;
-            let pitch_lower_limit = pan_orbit.pitch_lower_limit;
-            move |pitch: f64| pitch.clamp_optional(pitch_lower_limit, pitch_upper_limit)
+    /// Save the view the camera is currently moving towards as `home_view`.
+    pub fn save_home_view(&mut self) {
+        self.home_view = Some(self.target_pose());
+    }
+
+    /// The pose that frames a sphere at `center` with radius `sphere_radius`, keeping the current
+    /// yaw and pitch, and fitting the part of `camera`'s viewport left visible by
+    /// `framing_insets` according to `framing_fit`. Returns `None` if the projection doesn't have
+    /// a usable size yet, e.g. before the camera has rendered its first frame. The zoom limits
+    /// are not applied.
+    /// Pass the result to `animate_to` or `set_target_pose` to move the camera.
+    pub fn framing_pose(
+        &self,
+        center: DVec3,
+        sphere_radius: f64,
+        camera: &Camera,
+        projection: &Projection,
+    ) -> Option<CameraPose> {
+        let extent = camera.logical_viewport_size().map_or(DVec2::ONE, |size| {
+            util::safe_region(size, &self.framing_insets).0
+        });
+        let pose = CameraPose {
+            focus: center,
+            yaw: self.yaw.unwrap_or(self.target_yaw),
+            pitch: self.pitch.unwrap_or(self.target_pitch),
+            roll: self.roll,
+            orientation: self.orientation,
+            radius: util::fit_radius(sphere_radius, projection, self.framing_fit, extent)?,
         };
+        Some(self.safe_region_pose(pose, camera, projection))
+    }
 
-        let apply_focus_limits = {
-            let origin = pan_orbit.focus_bounds_origin;
-            let shape = pan_orbit.focus_bounds_shape;
+    /// `pose`, with its focus moved so that the point it was looking at appears in the centre of
+    /// the part of `camera`'s viewport left visible by `framing_insets`, rather than the centre of
+    /// the whole viewport. Use this before moving to a saved view, so that it isn't hidden behind
+    /// UI:
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::PanOrbitCamera;
+    /// fn go_to_bookmark(mut cameras: Query<(&mut PanOrbitCamera, &Camera, &Projection)>) {
+    ///     for (mut pan_orbit, camera, projection) in cameras.iter_mut() {
+    ///         if let Some(&pose) = pan_orbit.saved_views.views.get("overview") {
+    ///             let pose = pan_orbit.safe_region_pose(pose, camera, projection);
+    ///             pan_orbit.set_target_pose(pose);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn safe_region_pose(
+        &self,
+        mut pose: CameraPose,
+        camera: &Camera,
+        projection: &Projection,
+    ) -> CameraPose {
+        let Some(size) = camera.logical_viewport_size() else {
+            return pose;
+        };
+        let center = util::safe_region(size, &self.framing_insets).1;
+        let offset = center * util::view_half_extents(pose.radius, projection);
+        let rotation = pose.rotation(self.orbit_axis());
+        pose.focus -= rotation * offset.extend(0.0);
+        pose
+    }
 
-            move |focus: DVec3| {
-                let Some(shape) = shape else {
-                    return focus;
-                };
+    /// Save the view the camera is currently moving towards as `name`, replacing any view already
+    /// saved with that name.
+    pub fn save_view(&mut self, name: impl Into<String>) {
+        let pose = self.settled_pose();
+        self.saved_views.views.insert(name.into(), pose);
+    }
 
-                match shape {
-                    FocusBoundsShape::Cuboid(shape) => {
-                        let point = focus - origin;
-                        point.clamp(-shape.half_size.as_dvec3(), shape.half_size.as_dvec3())
-                            + origin
-                    }
-                    FocusBoundsShape::Sphere(shape) => {
-                        let point = focus - origin;
-                        let distance_squared = point.length_squared();
-
-                        let closest_point = if distance_squared <= (shape.radius as f64).powi(2) {
-                            // The point is inside the sphere.
-                            point
-                        } else {
-                            // The point is outside the sphere.
-                            // Find the closest point on the surface of the sphere.
-                            let dir_to_point = point / distance_squared.sqrt();
-                            shape.radius as f64 * dir_to_point
-                        };
-                        closest_point + origin
-                    }
-                }
-            }
+    /// Animate the camera to the view saved as `name`, using the transition settings in
+    /// `saved_views`. Returns `false` if there is no view with that name.
+    pub fn recall_view(&mut self, name: &str) -> bool {
+        let Some(&pose) = self.saved_views.views.get(name) else {
+            return false;
         };
+        self.transition_to(pose);
+        true
+    }
 
-        if !pan_orbit.initialized {
-            // Calculate yaw, pitch, and radius from the camera's position. If user sets all
-            // these explicitly, this calculation is wasted, but that's okay since it will only run
-            // once on init.
-            let (yaw, pitch, radius) = util::calculate_from_translation_and_focus(
-                if transform.translation == Vec3::ZERO {
-                    pan_orbit.position
-                } else {
-                    transform.translation.as_dvec3()
-                },
-                pan_orbit.focus,
-                pan_orbit.axis,
-            );
-            let &mut mut yaw = pan_orbit.yaw.get_or_insert(yaw);
-            let &mut mut pitch = pan_orbit.pitch.get_or_insert(pitch);
-            let &mut mut radius = pan_orbit.radius.get_or_insert(radius);
-            let mut focus = pan_orbit.focus;
-
-            // Apply limits
-            yaw = apply_yaw_limits(yaw);
-            pitch = apply_pitch_limits(pitch);
-            radius = apply_zoom_limits(radius);
-            focus = apply_focus_limits(focus);
-
-            // Set initial values
-            pan_orbit.yaw = Some(yaw);
-            pan_orbit.pitch = Some(pitch);
-            pan_orbit.radius = Some(radius);
-            pan_orbit.target_yaw = yaw;
-            pan_orbit.target_pitch = pitch;
-            pan_orbit.target_radius = radius;
-            pan_orbit.target_focus = focus;
-
-            let axis = pan_orbit.axis;
-            util::update_orbit_transform(
-                yaw,
-                pitch,
-                radius,
-                focus,
-                &mut transform,
-                &mut pan_orbit.position,
-                &mut projection,
-                axis,
-            );
+    /// Save the view the camera is currently moving towards in one of the two comparison slots,
+    /// replacing whatever was there.
+    pub fn store_snapshot(&mut self, slot: SnapshotSlot) {
+        let pose = self.settled_pose();
+        match slot {
+            SnapshotSlot::A => self.saved_views.snapshot_a = Some(pose),
+            SnapshotSlot::B => self.saved_views.snapshot_b = Some(pose),
+        }
+        self.saved_views.shown_snapshot = Some(slot);
+    }
 
-            pan_orbit.initialized = true;
+    /// Move to the view in `slot`, either cutting straight to it or blending using the transition
+    /// settings in `saved_views`. Returns `false` if nothing is stored in that slot.
+    pub fn show_snapshot(&mut self, slot: SnapshotSlot, animated: bool) -> bool {
+        let pose = match slot {
+            SnapshotSlot::A => self.saved_views.snapshot_a,
+            SnapshotSlot::B => self.saved_views.snapshot_b,
+        };
+        let Some(pose) = pose else {
+            return false;
+        };
+        if animated {
+            self.transition_to(pose);
+        } else {
+            self.animation = None;
+            self.move_to_pose(pose, false);
         }
+        self.saved_views.shown_snapshot = Some(slot);
+        true
+    }
 
-        // 1 - Get Input
+    /// Switch to the other comparison snapshot, e.g. for before/after comparisons. Shows
+    /// `SnapshotSlot::A` if neither has been shown yet. Returns `false` if that slot is empty.
+    pub fn flip_snapshot(&mut self, animated: bool) -> bool {
+        let slot = match self.saved_views.shown_snapshot {
+            Some(SnapshotSlot::A) => SnapshotSlot::B,
+            Some(SnapshotSlot::B) | None => SnapshotSlot::A,
+        };
+        self.show_snapshot(slot, animated)
+    }
+
+    /// The pose the camera will end up at, ignoring any smoothing or animation still in progress.
+    fn settled_pose(&self) -> CameraPose {
+        match self.animation {
+            Some(animation) => animation.to,
+            None => self.target_pose(),
+        }
+    }
+
+    /// Hand control of the camera to a cutscene or animation system. Until `end_cutscene` is
+    /// called, the camera ignores input and does not write to its `Transform`, so something else
+    /// can animate it freely.
+    pub fn start_cutscene(&mut self) {
+        self.cutscene = CutsceneState::Active;
+        self.orbit_velocity = DVec2::ZERO;
+        self.pan_velocity = DVec3::ZERO;
+        self.zoom_velocity = 0.0;
+    }
+
+    /// Take back control after a cutscene. The camera's orbit state (focus, yaw, and pitch) is
+    /// re-derived from wherever the cutscene left its `Transform`, keeping the current radius, and
+    /// the transform is blended from the cutscene's final transform to the orbit transform over
+    /// `blend_duration`. This smooths over anything the orbit controls can't represent, such as
+    /// roll.
+    pub fn end_cutscene(&mut self, blend_duration: Duration) {
+        if self.cutscene == CutsceneState::Inactive {
+            return;
+        }
+        self.cutscene = CutsceneState::Resuming {
+            from: None,
+            duration: blend_duration.as_secs_f32(),
+            elapsed: 0.0,
+        };
+    }
+
+    /// Restrict the user's control while a guided sequence plays, e.g. a tutorial or scripted
+    /// reveal. Animations (from `animate_to` or a `NavigationScript`) can't be interrupted, and in
+    /// between them the user can only look around the view the last one ended at, as allowed by
+    /// `mode`. The camera's limits are tightened to do this, and restored by `exit_guided_mode`.
+    /// Calling this again while already in guided mode switches to the new `mode`.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::{GuidedMode, NavigationScript, NavigationStep, PanOrbitCamera};
+    /// fn start_tour(mut commands: Commands, mut cameras: Query<(Entity, &mut PanOrbitCamera)>) {
+    ///     let Ok((entity, mut pan_orbit)) = cameras.get_single_mut() else {
+    ///         return;
+    ///     };
+    ///     pan_orbit.enter_guided_mode(GuidedMode {
+    ///         letterbox: Some(2.39),
+    ///         ..default()
+    ///     });
+    ///     commands.entity(entity).insert(NavigationScript::new(vec![
+    ///         NavigationStep::OrbitBy { yaw: 1.5, pitch: 0.0, duration: 3.0 },
+    ///         NavigationStep::ZoomTo { radius: 3.0, duration: 1.0 },
+    ///     ]));
+    /// }
+    /// ```
+    pub fn enter_guided_mode(&mut self, mode: GuidedMode) {
+        let freedom = match &self.guided {
+            GuidedState::Inactive => None,
+            GuidedState::Active { freedom, .. } | GuidedState::Exiting { freedom } => {
+                freedom.clone()
+            }
+        };
+        self.guided = GuidedState::Active {
+            mode,
+            freedom,
+            restricted: false,
+        };
+    }
+
+    /// Give the user back the freedom they had before `enter_guided_mode`, restoring the camera's
+    /// limits and viewport on the next update.
+    pub fn exit_guided_mode(&mut self) {
+        if let GuidedState::Active { freedom, .. } = &self.guided {
+            self.guided = GuidedState::Exiting {
+                freedom: freedom.clone(),
+            };
+        }
+    }
+
+    /// Whether the camera is in guided mode, see `enter_guided_mode`.
+    pub fn is_guided(&self) -> bool {
+        matches!(self.guided, GuidedState::Active { .. })
+    }
+
+    /// What is moving the camera right now, e.g. to ignore clicks on the scene while the user is
+    /// dragging the camera. This reflects the last update, so it's most accurate after
+    /// `PanOrbitCameraSystemSet`. Use `is_settled` to tell whether the camera is still moving
+    /// after a gesture or animation ends. A mouse drag is only reported once the mouse has moved a
+    /// few pixels, so a click without moving leaves the camera `Idle`.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::{InteractionState, PanOrbitCamera};
+    /// fn pick_objects(cameras: Query<&PanOrbitCamera>) {
+    ///     let Ok(pan_orbit) = cameras.get_single() else {
+    ///         return;
+    ///     };
+    ///     if matches!(
+    ///         pan_orbit.state(),
+    ///         InteractionState::Orbiting | InteractionState::Panning
+    ///     ) {
+    ///         return;
+    ///     }
+    ///     // Pick objects under the cursor...
+    /// }
+    /// ```
+    pub fn state(&self) -> InteractionState {
+        match self.gesture {
+            CameraGesture::Orbit => InteractionState::Orbiting,
+            CameraGesture::Pan => InteractionState::Panning,
+            CameraGesture::Zoom => InteractionState::Zooming,
+            CameraGesture::None
+                if self.animation.is_some()
+                    || self.projection_switch.is_some()
+                    || self.cutscene != CutsceneState::Inactive =>
+            {
+                InteractionState::Animating
+            }
+            CameraGesture::None => InteractionState::Idle,
+        }
+    }
+
+    /// Whether the camera has come to rest, i.e. smoothing has caught up with the target values,
+    /// momentum has run out, no animation is playing, and neither `auto_rotate` nor `idle_drift`
+    /// is moving it. Following a target can start the camera moving again at any time, as can
+    /// auto rotation and idle drift once their delay has passed. `time_since_input` is the
+    /// camera's `TimeSinceInput`.
+    pub fn is_settled(&self, time_since_input: &TimeSinceInput) -> bool {
+        self.state() == InteractionState::Idle
+            && !self.is_idle_moving(time_since_input.0)
+            && self.yaw == Some(self.target_yaw)
+            && self.pitch == Some(self.target_pitch)
+            && self.radius == Some(self.target_radius)
+            && self.roll == self.target_roll
+            && self.focus == self.target_focus
+            && self.orientation == self.target_orientation
+            && self.orbit_velocity == DVec2::ZERO
+            && self.pan_velocity == DVec3::ZERO
+            && self.zoom_velocity == 0.0
+    }
+
+    /// Whether `auto_rotate` or `idle_drift` is moving the camera, as the user has left it alone
+    /// for long enough. Idle drift counts as moving for as long as it's active, even once it has
+    /// reached the mesh it's drifting to.
+    fn is_idle_moving(&self, time_since_input: f32) -> bool {
+        let idle_for = |delay: f32| {
+            time_since_input >= delay
+                && self.animation.is_none()
+                && self.control_mode != ControlMode::Fly
+        };
+        let rotating = self
+            .auto_rotate
+            .is_some_and(|auto_rotate| auto_rotate.speed != 0.0 && idle_for(auto_rotate.delay));
+        let drifting = self.idle_drift.is_some_and(|drift| {
+            idle_for(drift.delay) && self.initialized && self.enabled && !self.is_guided()
+        });
+        rotating || drifting
+    }
+
+    /// Whether the user can control the camera, i.e. it's `enabled` and not playing an animation
+    /// in guided mode
+    fn accepts_input(&self) -> bool {
+        self.enabled && !(self.is_guided() && self.animation.is_some())
+    }
+
+    /// Which axes of orbiting the user can control, as `1.0` for yaw and pitch that are allowed
+    /// and `0.0` for those that aren't, to multiply orbit input by
+    fn orbit_mask(&self) -> DVec2 {
+        let allowed = |locked: bool| match self.orbit_enabled && !locked {
+            true => 1.0,
+            false => 0.0,
+        };
+        DVec2::new(allowed(self.lock_yaw), allowed(self.lock_pitch))
+    }
+
+    /// Clamp `fov` to `fov_lower_limit` and `fov_upper_limit`, whichever way round they are
+    fn clamp_fov(&self, fov: f32) -> f32 {
+        let (lower, upper) = self.fov_limits();
+        fov.clamp(lower, upper)
+    }
+
+    /// `fov_lower_limit` and `fov_upper_limit`, narrowest first
+    fn fov_limits(&self) -> (f32, f32) {
+        (
+            self.fov_lower_limit.min(self.fov_upper_limit),
+            self.fov_lower_limit.max(self.fov_upper_limit),
+        )
+    }
+
+    /// The smoothing for the device that last moved the camera
+    fn smoothing(&self) -> Smoothing {
+        self.device_smoothing
+            .get(self.input_device)
+            .unwrap_or(Smoothing {
+                orbit: self.orbit_smoothness,
+                pan: self.pan_smoothness,
+                zoom: self.zoom_smoothness,
+            })
+    }
+
+    /// Animate the camera to `pose` over exactly `duration`, following the `ease` curve. The
+    /// camera's smoothness settings don't apply during the animation. Any user input that moves
+    /// the camera cancels the animation.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::math::DVec3;
+    /// # use bevy_panorbit_camera::{CameraPose, PanOrbitCamera};
+    /// # use std::time::Duration;
+    /// # let mut pan_orbit = PanOrbitCamera::default();
+    /// pan_orbit.animate_to(
+    ///     CameraPose {
+    ///         focus: DVec3::ZERO,
+    ///         yaw: 0.0,
+    ///         pitch: 0.5,
+    ///         radius: 5.0,
+    ///         ..default()
+    ///     },
+    ///     Duration::from_secs_f32(0.8),
+    ///     EaseFunction::CubicInOut,
+    /// );
+    /// ```
+    pub fn animate_to(&mut self, pose: CameraPose, duration: Duration, ease: EaseFunction) {
+        self.start_animation(pose, duration.as_secs_f32(), ease, None);
+    }
+
+    /// Animate the camera to `pose`, keeping within the speed and acceleration `limits`. The
+    /// animation takes as long as the limits need. Like `animate_to`, the camera's smoothness
+    /// settings don't apply, and any user input that moves the camera cancels the animation.
+    /// # Example
+    /// ```
+    /// # use bevy::math::DVec3;
+    /// # use bevy_panorbit_camera::{CameraPose, MotionLimits, PanOrbitCamera};
+    /// # let mut pan_orbit = PanOrbitCamera::default();
+    /// pan_orbit.animate_with_limits(
+    ///     CameraPose {
+    ///         focus: DVec3::new(20.0, 0.0, 0.0),
+    ///         yaw: 1.0,
+    ///         pitch: 0.3,
+    ///         radius: 8.0,
+    ///         ..Default::default()
+    ///     },
+    ///     MotionLimits {
+    ///         max_speed: 4.0,
+    ///         max_acceleration: 2.0,
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn animate_with_limits(&mut self, pose: CameraPose, limits: MotionLimits) {
+        self.start_animation(pose, 0.0, EaseFunction::Linear, Some(limits));
+    }
+
+    /// Animate to `pose` using the transition settings in `saved_views`
+    pub(crate) fn transition_to(&mut self, pose: CameraPose) {
+        let CameraViews {
+            transition_duration,
+            ease,
+            limits,
+            ..
+        } = self.saved_views;
+        match limits {
+            Some(limits) => self.animate_with_limits(pose, limits),
+            None => self.animate_to(pose, transition_duration, ease),
+        }
+    }
+
+    fn start_animation(
+        &mut self,
+        mut pose: CameraPose,
+        duration: f32,
+        ease: EaseFunction,
+        limits: Option<MotionLimits>,
+    ) {
+        let mut from = CameraPose {
+            focus: self.focus,
+            yaw: self.yaw.unwrap_or(self.target_yaw),
+            pitch: self.pitch.unwrap_or(self.target_pitch),
+            roll: self.roll,
+            orientation: self.orientation,
+            radius: self.radius.unwrap_or(self.target_radius),
+        };
+        // Take the shortest way around, unless that could cross the yaw limits
+        if self.yaw_upper_limit.is_none() && self.yaw_lower_limit.is_none() {
+            pose.yaw = util::nearest_equivalent_angle(from.yaw, pose.yaw);
+        }
+        // Blend the whole rotation if either end has a trackball orientation
+        if from.orientation.is_some() || pose.orientation.is_some() {
+            let axis = self.orbit_axis();
+            from.orientation = Some(from.rotation(axis));
+            pose.orientation = Some(pose.rotation(axis));
+        }
+        self.orbit_velocity = DVec2::ZERO;
+        self.pan_velocity = DVec3::ZERO;
+        self.zoom_velocity = 0.0;
+        let duration = match limits {
+            Some(limits) => {
+                let (speed, acceleration) = limits.normalized(&from, &pose);
+                let s_curve = limits.shape == MotionShape::SCurve;
+                util::motion_duration(speed, acceleration, s_curve) as f32
+            }
+            None => duration,
+        };
+        self.animation = Some(PoseAnimation {
+            from,
+            to: pose,
+            duration,
+            elapsed: 0.0,
+            ease,
+            limits,
+        });
+    }
+
+    /// Immediately stop the camera where it is, cancelling any momentum and remaining smoothing
+    /// of orbiting, panning, and zooming.
+    pub fn stop_motion(&mut self) {
+        self.orbit_velocity = DVec2::ZERO;
+        self.pan_velocity = DVec3::ZERO;
+        self.zoom_velocity = 0.0;
+        self.spring_velocity = SpringVelocity::default();
+        self.pending_input = PendingInput::default();
+        if let (Some(yaw), Some(pitch), Some(radius)) = (self.yaw, self.pitch, self.radius) {
+            self.set_target_pose(CameraPose {
+                focus: self.focus,
+                yaw,
+                pitch,
+                roll: self.roll,
+                orientation: self.orientation,
+                radius,
+            });
+        }
+    }
+
+    /// Move the camera back to `home_view`, if there is one. If `animated` is `true`, the camera
+    /// smoothly transitions to the home view, otherwise it jumps there on the next update.
+    /// Either way, the yaw is wrapped to the nearest equivalent angle, so the camera takes the
+    /// shortest way round, unless yaw limits are in place.
+    pub fn reset_to_home(&mut self, animated: bool) {
+        if let Some(home) = self.home_view {
+            self.move_to_pose(home, animated);
+        }
+    }
+
+    /// Rotate the camera to one of the standard views, e.g. looking straight down from above,
+    /// keeping the current focus and radius. The views are relative to `axis`, so `Top` looks
+    /// down `axis[1]` even in a Z-up scene. If `animated` is `true`, the camera smoothly
+    /// transitions to the view, otherwise it jumps there on the next update.
+    pub fn set_view(&mut self, view: StandardView, animated: bool) {
+        let (yaw, pitch) = view.yaw_pitch();
+        // Standard views are upright
+        let pose = CameraPose {
+            yaw,
+            pitch,
+            roll: 0.0,
+            orientation: None,
+            ..self.target_pose()
+        };
+        self.move_to_pose(pose, animated);
+    }
+
+    /// Move to `pose`, either smoothly or immediately. The yaw is wrapped so the camera takes the
+    /// shortest way round, unless yaw limits are in place.
+    fn move_to_pose(&mut self, mut pose: CameraPose, animated: bool) {
+        if self.yaw_upper_limit.is_none() && self.yaw_lower_limit.is_none() {
+            pose.yaw = util::nearest_equivalent_angle(self.target_yaw, pose.yaw);
+        }
+        self.set_target_pose(pose);
+        self.orbit_velocity = DVec2::ZERO;
+        self.pan_velocity = DVec3::ZERO;
+        self.zoom_velocity = 0.0;
+        if !animated {
+            self.focus = pose.focus;
+            self.yaw = Some(self.target_yaw);
+            self.pitch = Some(self.target_pitch);
+            self.roll = pose.roll;
+            self.orientation = self.target_orientation;
+            self.radius = Some(pose.radius);
+        }
+        self.force_update = true;
+    }
+}
+
+/// Standard views for `PanOrbitCamera::set_view`. Front looks at the focus from `axis[2]`, right
+/// from `axis[0]`, and top from `axis[1]`, so with the default axes these match Bevy's
+/// conventions (front looking along -Z, top looking along -Y).
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum StandardView {
+    /// Looking down from above.
+    Top,
+    /// Looking up from below.
+    Bottom,
+    /// Looking from the front.
+    Front,
+    /// Looking from the back.
+    Back,
+    /// Looking from the left.
+    Left,
+    /// Looking from the right.
+    Right,
+    /// Isometric view from the front, right, and above.
+    Isometric,
+}
+
+impl StandardView {
+    /// Every standard view, in the order they're usually listed.
+    pub const ALL: [StandardView; 7] = [
+        StandardView::Top,
+        StandardView::Bottom,
+        StandardView::Front,
+        StandardView::Back,
+        StandardView::Left,
+        StandardView::Right,
+        StandardView::Isometric,
+    ];
+
+    /// The name of this view, e.g. for a button label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardView::Top => "Top",
+            StandardView::Bottom => "Bottom",
+            StandardView::Front => "Front",
+            StandardView::Back => "Back",
+            StandardView::Left => "Left",
+            StandardView::Right => "Right",
+            StandardView::Isometric => "Isometric",
+        }
+    }
+
+    /// The yaw and pitch of this view.
+    pub fn yaw_pitch(&self) -> (f64, f64) {
+        match self {
+            StandardView::Top => (0.0, PI / 2.0),
+            StandardView::Bottom => (0.0, -PI / 2.0),
+            StandardView::Front => (0.0, 0.0),
+            StandardView::Back => (PI, 0.0),
+            StandardView::Left => (-PI / 2.0, 0.0),
+            StandardView::Right => (PI / 2.0, 0.0),
+            // The angle at which all three axes appear equally foreshortened
+            StandardView::Isometric => (PI / 4.0, (1.0 / 2f64.sqrt()).atan()),
+        }
+    }
+}
+
+/// A snapshot of a camera's view, i.e. everything needed to restore it. Small and stable enough to
+/// store in save files, e.g. `PanOrbitCamera::target_pose` with the `serialize` feature.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraPose {
+    /// The point the camera orbits around and looks at.
+    pub focus: DVec3,
+    /// Rotation in radians around the up axis.
+    pub yaw: f64,
+    /// Rotation in radians around the local X axis.
+    pub pitch: f64,
+    /// Rotation in radians around the view direction. See `PanOrbitCamera::roll`.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub roll: f64,
+    /// The full rotation of a camera in `OrbitMode::Trackball`, including any roll. `None` for
+    /// turntable cameras. See `PanOrbitCamera::orientation`.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub orientation: Option<DQuat>,
+    /// Distance from `focus`, or the projection's scale for orthographic cameras.
+    pub radius: f64,
+}
+
+/// Whether a `PanOrbitCamera` has handed control to a cutscene. See
+/// `PanOrbitCamera::start_cutscene`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum CutsceneState {
+    /// The camera is under interactive control.
+    #[default]
+    Inactive,
+    /// A cutscene controls the camera's transform.
+    Active,
+    /// Interactive control is resuming after a cutscene, and the transform is being blended from
+    /// the cutscene's final transform.
+    Resuming {
+        /// The cutscene's final transform. Captured automatically on the first frame after the
+        /// cutscene ends.
+        from: Option<Transform>,
+        /// How long the blend lasts, in seconds.
+        duration: f32,
+        /// How long the blend has been running, in seconds.
+        elapsed: f32,
+    },
+}
+
+/// The gesture the user is making with a `PanOrbitCamera`. See `PanOrbitCamera::gesture`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraGesture {
+    /// The user isn't controlling the camera.
+    #[default]
+    None,
+    /// The user is orbiting, with a mouse drag or touch.
+    Orbit,
+    /// The user is panning, with a mouse drag or touch.
+    Pan,
+    /// The user is zooming, with the scroll wheel, a pinch, or a box zoom drag.
+    Zoom,
+}
+
+/// What is moving a `PanOrbitCamera`. See `PanOrbitCamera::state`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum InteractionState {
+    /// Nothing is controlling the camera, though it may still be moving towards its targets due
+    /// to smoothing or momentum. See `PanOrbitCamera::is_settled`.
+    #[default]
+    Idle,
+    /// The user is orbiting, with a mouse drag or touch.
+    Orbiting,
+    /// The user is panning, with a mouse drag or touch.
+    Panning,
+    /// The user is zooming, with the scroll wheel, a pinch, or a box zoom drag.
+    Zooming,
+    /// The camera is following an animation, a projection switch, or a cutscene, rather than the
+    /// user.
+    Animating,
+}
+
+/// How long it has been since the user last interacted with a `PanOrbitCamera` or
+/// `PanZoomCamera2d`, in seconds. Added automatically and updated every frame. It's kept out of
+/// the camera component itself, so `Changed<PanOrbitCamera>` only fires when the camera changes.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct TimeSinceInput(pub f32);
+
+/// A kind of input device that can control a `PanOrbitCamera`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputDevice {
+    /// The mouse, scroll wheel, or trackpad.
+    #[default]
+    Mouse,
+    /// A touch screen.
+    Touch,
+    /// A gamepad.
+    Gamepad,
+    /// The keyboard controls.
+    Keyboard,
+}
+
+/// Smoothing values for orbiting, panning, and zooming, with the same meaning as
+/// `PanOrbitCamera::orbit_smoothness`, `pan_smoothness`, and `zoom_smoothness`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Smoothing {
+    /// How much smoothing is applied to the orbit motion.
+    pub orbit: f64,
+    /// How much smoothing is applied to the panning motion.
+    pub pan: f64,
+    /// How much smoothing is applied to the zoom motion.
+    pub zoom: f64,
+}
+
+/// Smoothing overrides for particular input devices, see `PanOrbitCamera::device_smoothing`. The
+/// device that last moved the camera is picked automatically, and its smoothing keeps applying
+/// while the camera settles after the gesture ends. Devices without an override use the camera's
+/// own smoothing, as does the mouse.
+/// ```
+/// # use bevy_panorbit_camera::{DeviceSmoothing, PanOrbitCamera, Smoothing};
+/// // Follow the finger closely, but keep the mouse smooth
+/// let camera = PanOrbitCamera {
+///     device_smoothing: DeviceSmoothing {
+///         touch: Some(Smoothing {
+///             orbit: 0.0,
+///             pan: 0.0,
+///             zoom: 0.0,
+///         }),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceSmoothing {
+    /// Smoothing while the camera is controlled by touch.
+    /// Defaults to `None`.
+    pub touch: Option<Smoothing>,
+    /// Smoothing while the camera is controlled by a gamepad.
+    /// Defaults to `None`.
+    pub gamepad: Option<Smoothing>,
+    /// Smoothing while the camera is controlled by the keyboard.
+    /// Defaults to `None`.
+    pub keyboard: Option<Smoothing>,
+}
+
+impl DeviceSmoothing {
+    /// The smoothing override for `device`, if there is one.
+    pub fn get(&self, device: InputDevice) -> Option<Smoothing> {
+        match device {
+            InputDevice::Mouse => None,
+            InputDevice::Touch => self.touch,
+            InputDevice::Gamepad => self.gamepad,
+            InputDevice::Keyboard => self.keyboard,
+        }
+    }
+}
+
+/// Named camera views, see `PanOrbitCamera::saved_views`.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraViews {
+    /// The saved views by name.
+    pub views: HashMap<String, CameraPose>,
+    /// How long recalling a view takes.
+    /// Defaults to 0.6 seconds.
+    pub transition_duration: Duration,
+    /// The easing curve used when recalling a view.
+    /// Defaults to `EaseFunction::CubicInOut`.
+    pub ease: EaseFunction,
+    /// Limits on speed and acceleration when recalling a view. When set, these decide how long
+    /// the transition takes, instead of `transition_duration` and `ease`.
+    /// Defaults to `None`.
+    pub limits: Option<MotionLimits>,
+    /// The first view to compare, see `PanOrbitCamera::store_snapshot`.
+    /// Defaults to `None`.
+    pub snapshot_a: Option<CameraPose>,
+    /// The second view to compare, see `PanOrbitCamera::store_snapshot`.
+    /// Defaults to `None`.
+    pub snapshot_b: Option<CameraPose>,
+    /// The snapshot that was last stored or shown, which `PanOrbitCamera::flip_snapshot` switches
+    /// away from.
+    /// Defaults to `None`.
+    pub shown_snapshot: Option<SnapshotSlot>,
+}
+
+/// One of the two comparison snapshots in `CameraViews`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SnapshotSlot {
+    /// The first snapshot, e.g. "before".
+    A,
+    /// The second snapshot, e.g. "after".
+    B,
+}
+
+impl Default for CameraViews {
+    fn default() -> Self {
+        Self {
+            views: HashMap::default(),
+            transition_duration: Duration::from_secs_f32(0.6),
+            ease: EaseFunction::CubicInOut,
+            limits: None,
+            snapshot_a: None,
+            snapshot_b: None,
+            shown_snapshot: None,
+        }
+    }
+}
+
+impl CameraPose {
+    /// Linearly interpolate between this pose and `other`. `t` of `0.0` gives this pose, and `1.0`
+    /// gives `other`.
+    pub fn lerp(&self, other: &CameraPose, t: f64) -> CameraPose {
+        let orientation = match (self.orientation, other.orientation) {
+            (Some(from), Some(to)) => Some(from.slerp(to, t)),
+            (from, to) => match t < 1.0 {
+                true => from,
+                false => to,
+            },
+        };
+        CameraPose {
+            focus: self.focus.lerp(other.focus, t),
+            yaw: self.yaw + (other.yaw - self.yaw) * t,
+            pitch: self.pitch + (other.pitch - self.pitch) * t,
+            roll: self.roll + (other.roll - self.roll) * t,
+            orientation,
+            radius: self.radius + (other.radius - self.radius) * t,
+        }
+    }
+
+    /// The camera's rotation in this pose, for a camera whose yaw and pitch are measured from
+    /// `axis`. This is `orientation` if there is one.
+    pub fn rotation(&self, axis: [DVec3; 3]) -> DQuat {
+        self.orientation.unwrap_or_else(|| {
+            util::orbit_rotation(self.yaw, self.pitch, axis) * DQuat::from_rotation_z(self.roll)
+        })
+    }
+}
+
+/// An animation started by `PanOrbitCamera::animate_to`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseAnimation {
+    /// Where the animation started.
+    pub from: CameraPose,
+    /// Where the animation ends.
+    pub to: CameraPose,
+    /// How long the animation lasts, in seconds.
+    pub duration: f32,
+    /// How long the animation has been running, in seconds.
+    pub elapsed: f32,
+    /// The easing curve the animation follows, unless it has `limits`.
+    pub ease: EaseFunction,
+    /// The speed and acceleration limits the animation keeps to, see
+    /// `PanOrbitCamera::animate_with_limits`.
+    pub limits: Option<MotionLimits>,
+}
+
+impl PoseAnimation {
+    /// The pose after `elapsed` seconds.
+    pub fn sample(&self) -> CameraPose {
+        if let Some(limits) = self.limits {
+            let (speed, acceleration) = limits.normalized(&self.from, &self.to);
+            let s_curve = limits.shape == MotionShape::SCurve;
+            let t = util::motion_progress(self.elapsed as f64, speed, acceleration, s_curve);
+            return self.from.lerp(&self.to, t);
+        }
+        let t = match self.duration > 0.0 {
+            true => self.elapsed / self.duration,
+            false => 1.0,
+        };
+        let t = EasingCurve::new(0.0, 1.0, self.ease).sample_clamped(t);
+        self.from.lerp(&self.to, t as f64)
+    }
+
+    /// Whether the animation has reached the end.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Settings for the camera continuing to move after a drag is released, gradually slowing down.
+///
+/// `enabled` switches all momentum on or off in one place, e.g. for an accessibility setting.
+/// `friction` and `max_speed` apply to every action, unless the action overrides them.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{ActionMomentum, Momentum, PanOrbitCamera};
+/// // Keep orbiting after a drag, and zooming after a pinch, but stop panning straight away
+/// let camera = PanOrbitCamera {
+///     momentum: Momentum {
+///         enabled: true,
+///         pan: ActionMomentum::disabled(),
+///         zoom: ActionMomentum {
+///             friction: Some(8.0),
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Momentum {
+    /// Whether the camera has momentum at all. When `false`, none of the actions have momentum,
+    /// whatever their own settings.
+    /// Defaults to `false`.
+    pub enabled: bool,
+    /// How quickly the camera slows down. Speed decreases by a factor of `e` every
+    /// `1.0 / friction` seconds, so higher values stop sooner.
+    /// Defaults to `4.0`.
+    pub friction: f64,
+    /// The fastest the camera can keep moving after a gesture is released, in radians per second
+    /// for orbiting, and multiples of the radius per second for panning and zooming. `None` means
+    /// no limit.
+    /// Defaults to `None`.
+    pub max_speed: Option<f64>,
+    /// Whether pressing a mouse button or touching the screen immediately stops the camera,
+    /// including any remaining smoothing, so it doesn't keep drifting under a new gesture.
+    /// Defaults to `true`.
+    pub stop_on_press: bool,
+    /// Momentum for orbiting.
+    /// Defaults to enabled, with the shared settings.
+    pub orbit: ActionMomentum,
+    /// Momentum for panning.
+    /// Defaults to enabled, with the shared settings.
+    pub pan: ActionMomentum,
+    /// Momentum for zooming with a pinch, on a touch screen or trackpad. Zooming with a scroll
+    /// wheel, keys, or a gamepad is already smoothed, so it has no momentum.
+    /// Defaults to disabled.
+    pub zoom: ActionMomentum,
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            friction: 4.0,
+            max_speed: None,
+            stop_on_press: true,
+            orbit: ActionMomentum::default(),
+            pan: ActionMomentum::default(),
+            zoom: ActionMomentum::disabled(),
+        }
+    }
+}
+
+impl Momentum {
+    /// The friction and maximum speed of `action`, or `None` if it has no momentum
+    fn settings(&self, action: &ActionMomentum) -> Option<(f64, Option<f64>)> {
+        (self.enabled && action.enabled).then(|| {
+            (
+                action.friction.unwrap_or(self.friction),
+                action.max_speed.or(self.max_speed),
+            )
+        })
+    }
+}
+
+/// Momentum settings for one action, see `Momentum`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionMomentum {
+    /// Whether this action has momentum, as long as `Momentum::enabled` is `true`.
+    /// Defaults to `true`.
+    pub enabled: bool,
+    /// Overrides `Momentum::friction` for this action.
+    /// Defaults to `None`.
+    pub friction: Option<f64>,
+    /// Overrides `Momentum::max_speed` for this action.
+    /// Defaults to `None`.
+    pub max_speed: Option<f64>,
+}
+
+impl Default for ActionMomentum {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            friction: None,
+            max_speed: None,
+        }
+    }
+}
+
+impl ActionMomentum {
+    /// No momentum for this action.
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..default()
+        }
+    }
+}
+
+/// Settings for spinning the camera while it's idle. Any interaction pauses the rotation straight
+/// away, and it resumes once the user has left the camera alone for `delay` seconds.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoRotate {
+    /// How fast to rotate, in radians per second of yaw. Use a negative value to spin the other
+    /// way.
+    /// Defaults to `0.3`.
+    pub speed: f64,
+    /// How long to wait after the last interaction before rotating again, in seconds. This also
+    /// applies when the app starts.
+    /// Defaults to `3.0`.
+    pub delay: f32,
+}
+
+impl Default for AutoRotate {
+    fn default() -> Self {
+        Self {
+            speed: 0.3,
+            delay: 3.0,
+        }
+    }
+}
+
+/// What the `PanOrbitCamera` receiving input does when its window loses focus, see
+/// `PanOrbitCamera::focus_loss_policy`. Whatever the policy, the first mouse motion after the
+/// window regains focus is ignored, since it can include movement from while the window wasn't
+/// focused.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum FocusLossPolicy {
+    /// End any drag in progress, as if its button had been released. Momentum carries on.
+    #[default]
+    CancelGestures,
+    /// End any drag in progress and stop the camera where it is, including momentum and
+    /// smoothing.
+    Freeze,
+    /// Carry on as if nothing happened. Drags continue until their button is released.
+    Continue,
+}
+
+/// What the cursor does during a mouse drag, see `PanOrbitCamera::drag_cursor`. The cursor is
+/// always given back, as it was, when the drag ends or the window loses focus.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragCursor {
+    /// The cursor moves as usual, and can leave the window.
+    #[default]
+    Free,
+    /// The cursor is hidden and locked in place, so the drag can carry on indefinitely. It
+    /// reappears where the drag started. Not supported on Windows, where the cursor is confined
+    /// to the window instead, so consider `Wrap` there.
+    Locked,
+    /// The cursor is confined to the window, and jumps to the opposite edge when it reaches an
+    /// edge. Confining isn't supported on macOS, where the cursor can still escape a fast drag.
+    Wrap,
+}
+
+/// The input that resets the camera to its home view.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResetGesture {
+    /// Double-click the given mouse button on empty space in the viewport. See
+    /// `PanOrbitCamera::reset_gesture`.
+    DoubleClick(MouseButton),
+    /// Press the given key while the viewport is active.
+    Key(KeyCode),
+}
+
+/// Which extent of the viewport to fit when framing something, e.g. with
+/// `PanOrbitCamera::framing_pose`. Wide and tall viewports often need different rules: on an
+/// ultrawide monitor, fitting the height leaves the sides free, while in a portrait viewport
+/// fitting the width keeps things from being cut off at the sides.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum FramingFit {
+    /// Fit whichever extent is narrower, so that everything is in view.
+    #[default]
+    Both,
+    /// Fit the viewport's height. Things may be cut off at the sides of a narrow viewport.
+    Vertical,
+    /// Fit the viewport's width. Things may be cut off at the top and bottom of a wide viewport.
+    Horizontal,
+}
+
+/// Space taken up by UI along each edge of a `PanOrbitCamera`'s viewport, in logical pixels, e.g.
+/// a 300 pixel wide panel on the right. See `PanOrbitCamera::framing_insets`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct FramingInsets {
+    /// Space covered along the left edge.
+    pub left: f32,
+    /// Space covered along the right edge.
+    pub right: f32,
+    /// Space covered along the top edge.
+    pub top: f32,
+    /// Space covered along the bottom edge.
+    pub bottom: f32,
+}
+
+/// Settings for scaling a `PanOrbitCamera`'s clipping planes with its zoom. See
+/// `PanOrbitCamera::auto_clip_planes`.
+///
+/// Perspective cameras place their near and far planes in proportion to the distance to the
+/// focus. Orthographic cameras don't get closer when zooming, so instead their depth range is
+/// centred on the focus and sized in proportion to how much of the scene is in view.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoClipPlanes {
+    /// The distance to the near plane, as a fraction of the distance to the focus. Only used by
+    /// perspective cameras.
+    /// Defaults to `0.01`.
+    pub near_ratio: f32,
+    /// The distance to the far plane, as a multiple of the distance to the focus. For
+    /// orthographic cameras, the view extends this many times the larger of its half width and
+    /// half height in front of and behind the focus.
+    /// Defaults to `1000.0`.
+    pub far_ratio: f32,
+    /// The closest that the near plane can get, to avoid depth precision problems when zoomed
+    /// all the way in.
+    /// Defaults to `0.00001`.
+    pub min_near: f32,
+}
+
+impl Default for AutoClipPlanes {
+    fn default() -> Self {
+        Self {
+            near_ratio: 0.01,
+            far_ratio: 1000.0,
+            min_near: 0.00001,
+        }
+    }
+}
+
+/// What zooming a perspective `PanOrbitCamera` changes, see `PanOrbitCamera::zoom_mode`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZoomMode {
+    /// Move the camera towards or away from the focus, changing the radius.
+    #[default]
+    Dolly,
+    /// Narrow or widen the field of view, keeping the camera where it is, like a zoom lens. The
+    /// field of view changes straight away, and stays within `PanOrbitCamera::fov_lower_limit`
+    /// and `PanOrbitCamera::fov_upper_limit`.
+    Fov,
+    /// Move the camera like `Dolly`, while changing the field of view to keep things at the focus
+    /// the same size on screen, for the "vertigo" effect. Any change to the radius, including
+    /// from code, changes the field of view along with it, and the radius stops where the field
+    /// of view reaches its limits.
+    DollyZoom,
+}
+
+/// How panning moves the camera's focus.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PanMode {
+    /// Move the focus parallel to the screen, along `pan_axis`.
+    #[default]
+    ViewPlane,
+    /// Move the focus along a plane in the world, such as the ground in a map or RTS style
+    /// camera. The point on the plane under the cursor stays under the cursor as it's dragged,
+    /// so the focus keeps its height above the plane. `pan_sensitivity` scales how far the focus
+    /// moves, so this only holds exactly at `1.0`. Has no effect in `ControlMode::Fly`.
+    Plane {
+        /// Any point on the plane.
+        point: DVec3,
+        /// The plane's normal, e.g. `DVec3::Y` for the XZ plane.
+        normal: DVec3,
+    },
+}
+
+impl PanMode {
+    /// Pan along the horizontal plane at the given height, with Y as up.
+    pub fn ground(height: f64) -> Self {
+        Self::Plane {
+            point: DVec3::new(0.0, height, 0.0),
+            normal: DVec3::Y,
+        }
+    }
+}
+
+/// How the camera responds to input.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ControlMode {
+    /// The camera orbits around `focus`, pans, and zooms.
+    #[default]
+    Orbit,
+    /// First person controls: the camera moves with `fly_keys`, and the orbit button rotates the
+    /// camera around its own position rather than `focus`. Panning moves the camera, and zooming is
+    /// disabled. `focus` is kept at `radius` distance in front of the camera, so switching back to
+    /// `ControlMode::Orbit` continues orbiting around the point the camera is looking at.
+    Fly,
+}
+
+/// How orbiting rotates the camera.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrbitMode {
+    /// Dragging changes yaw and pitch, so the camera stays upright (unless `allow_upside_down`
+    /// lets it tumble over the top).
+    #[default]
+    Turntable,
+    /// Dragging turns the camera around its own up and right axes, wherever they point, so it can
+    /// tumble in any direction and roll builds up over successive drags. The full rotation is
+    /// kept in `orientation`, and `yaw` and `pitch` follow the direction the camera looks in.
+    /// Other controls, such as the keyboard, gamepad, and animations, still change yaw and pitch,
+    /// keeping the current roll.
+    /// Yaw and pitch limits, `allow_upside_down`, and orbit momentum don't apply in this mode.
+    Trackball,
+}
+
+/// The kind of projection to switch to with `PanOrbitCamera::set_projection_mode`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProjectionMode {
+    /// A perspective projection, with a field of view of `PanOrbitCamera::perspective_fov`.
+    Perspective,
+    /// An orthographic projection.
+    Orthographic,
+}
+
+/// The settings of the perspective and orthographic projections that
+/// `PanOrbitCamera::set_projection_mode` switches between, apart from the perspective field of
+/// view, which is `PanOrbitCamera::perspective_fov`. Whichever projection the camera switches away
+/// from is saved here, so that switching back restores it.
+#[derive(Reflect, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedProjections {
+    /// The perspective projection's near clipping plane.
+    /// Defaults to `0.1`, the same as `PerspectiveProjection`.
+    pub perspective_near: f32,
+    /// The perspective projection's far clipping plane. It's moved back along with the camera
+    /// during a switch, so the focus isn't clipped.
+    /// Defaults to `1000.0`, the same as `PerspectiveProjection`.
+    pub perspective_far: f32,
+    /// How the orthographic projection is sized to the viewport.
+    /// Defaults to `ScalingMode::FixedVertical` with a `viewport_height` of `1.0`.
+    pub orthographic_scaling_mode: ScalingMode,
+    /// The orthographic projection's near clipping plane.
+    /// Defaults to `0.0`, the same as `OrthographicProjection::default_3d`.
+    pub orthographic_near: f32,
+    /// The orthographic projection's far clipping plane.
+    /// Defaults to `1000.0`, the same as `OrthographicProjection::default_3d`.
+    pub orthographic_far: f32,
+    /// The orthographic projection's `viewport_origin`.
+    /// Defaults to `Vec2::new(0.5, 0.5)`.
+    pub orthographic_viewport_origin: Vec2,
+}
+
+impl Default for SavedProjections {
+    fn default() -> Self {
+        let perspective = PerspectiveProjection::default();
+        let orthographic = OrthographicProjection::default_3d();
+        Self {
+            perspective_near: perspective.near,
+            perspective_far: perspective.far,
+            orthographic_scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: 1.0,
+            },
+            orthographic_near: orthographic.near,
+            orthographic_far: orthographic.far,
+            orthographic_viewport_origin: orthographic.viewport_origin,
+        }
+    }
+}
+
+// `ScalingMode` isn't `PartialEq`, so it's compared by hand
+impl PartialEq for SavedProjections {
+    fn eq(&self, other: &Self) -> bool {
+        use ScalingMode::*;
+        let scaling_modes_equal = match (
+            self.orthographic_scaling_mode,
+            other.orthographic_scaling_mode,
+        ) {
+            (WindowSize, WindowSize) => true,
+            (
+                Fixed { width, height },
+                Fixed {
+                    width: w,
+                    height: h,
+                },
+            )
+            | (
+                AutoMin {
+                    min_width: width,
+                    min_height: height,
+                },
+                AutoMin {
+                    min_width: w,
+                    min_height: h,
+                },
+            )
+            | (
+                AutoMax {
+                    max_width: width,
+                    max_height: height,
+                },
+                AutoMax {
+                    max_width: w,
+                    max_height: h,
+                },
+            ) => width == w && height == h,
+            (FixedVertical { viewport_height: a }, FixedVertical { viewport_height: b })
+            | (FixedHorizontal { viewport_width: a }, FixedHorizontal { viewport_width: b }) => {
+                a == b
+            }
+            _ => false,
+        };
+        self.perspective_near == other.perspective_near
+            && self.perspective_far == other.perspective_far
+            && scaling_modes_equal
+            && self.orthographic_near == other.orthographic_near
+            && self.orthographic_far == other.orthographic_far
+            && self.orthographic_viewport_origin == other.orthographic_viewport_origin
+    }
+}
+
+/// A switch between projections started by `PanOrbitCamera::set_projection_mode`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionSwitch {
+    /// The projection being switched to.
+    pub to: ProjectionMode,
+    /// How far from perspective (`0.0`) to orthographic (`1.0`) the switch has got. `None` until
+    /// the switch has started.
+    pub progress: Option<f32>,
+}
+
+/// The narrowest field of view reached while switching projections, at which point the
+/// perspective is barely noticeable and the projection can be swapped without a visible jump
+const PROJECTION_SWITCH_MIN_FOV: f64 = 0.02;
+
+/// The keys used to move the camera in `ControlMode::Fly`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlyKeys {
+    /// Move forward. Defaults to `KeyCode::KeyW`.
+    pub forward: KeyCode,
+    /// Move backward. Defaults to `KeyCode::KeyS`.
+    pub backward: KeyCode,
+    /// Move left. Defaults to `KeyCode::KeyA`.
+    pub left: KeyCode,
+    /// Move right. Defaults to `KeyCode::KeyD`.
+    pub right: KeyCode,
+    /// Move up. Defaults to `KeyCode::KeyE`.
+    pub up: KeyCode,
+    /// Move down. Defaults to `KeyCode::KeyQ`.
+    pub down: KeyCode,
+}
+
+impl Default for FlyKeys {
+    fn default() -> Self {
+        Self {
+            forward: KeyCode::KeyW,
+            backward: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            up: KeyCode::KeyE,
+            down: KeyCode::KeyQ,
+        }
+    }
+}
+
+/// Minimum cosine of the angle between a cursor ray and a `PanMode::Plane` normal. Rays closer to
+/// parallel with the plane are ignored, since the point they hit jumps far away near the horizon.
+const MIN_PAN_PLANE_ANGLE_COS: f64 = 0.1;
+
+/// Width and height in logical pixels below which box zoom rectangles are ignored
+const MIN_BOX_ZOOM_SIZE: f32 = 4.0;
+
+/// How long the camera takes to zoom to a box, in seconds
+const BOX_ZOOM_DURATION: f32 = 0.4;
+
+/// How much each frame's drag speed contributes to the tracked velocity, used for momentum.
+/// Averaging over a few frames stops a single jittery frame at the end of a drag deciding how the
+/// camera keeps moving.
+const VELOCITY_SMOOTHING: f64 = 0.5;
+
+/// Orbit speed in radians per second below which momentum stops
+const MIN_ORBIT_VELOCITY: f64 = 0.01;
+
+/// Pan speed in multiples of the radius per second below which momentum stops
+const MIN_PAN_VELOCITY: f64 = 0.005;
+
+/// Zoom speed, as the rate of change of the natural logarithm of the radius per second, below
+/// which momentum stops
+const MIN_ZOOM_VELOCITY: f64 = 0.005;
+
+/// How far dragging the full height of the window zooms, as the natural logarithm of the change in
+/// radius, when `zoom_sensitivity` is `1.0`. Dragging up the window zooms in about 7 times.
+const ZOOM_DRAG_PER_HEIGHT: f64 = 2.0;
+
+/// Degrees turned by dragging across the full width of the window when `orbit_sensitivity` is
+/// `1.0`
+const ORBIT_DEGREES_PER_WIDTH: f64 = 360.0;
+
+/// The window and viewport size used to scale input when `PanOrbitCamera::adaptive_sensitivity`
+/// is `false`
+const RAW_SENSITIVITY_REFERENCE_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+
+/// Tracks which `PanOrbitCamera` is active (should handle input events), along with the window
+/// and viewport dimensions, which are used for scaling mouse motion.
+/// `PanOrbitCameraPlugin` manages this resource automatically, in order to support multiple
+/// viewports/windows, and cameras with an `InputRegion`. To choose the camera yourself, e.g. one
+/// that is rendering to a texture, use `ActivePanOrbitCamera`. If that doesn't work for you
+/// either, you can take over and manage this resource yourself.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct ActiveCameraData {
+    /// ID of the entity with `PanOrbitCamera` that will handle user input. In other words, this
+    /// is the camera that will move when you orbit/pan/zoom.
+    pub entity: Option<Entity>,
+    /// The viewport size. This is only used to scale the panning mouse motion. I recommend setting
+    /// this to the actual render target dimensions (e.g. the image or viewport), and changing
+    /// `PanOrbitCamera::pan_sensitivity` to adjust the sensitivity if required.
+    pub viewport_size: Option<Vec2>,
+    /// The size of the window. This is only used to scale the orbit mouse motion. I recommend
+    /// setting this to actual dimensions of the window that you want to control the camera from,
+    /// and changing `PanOrbitCamera::orbit_sensitivity` to adjust the sensitivity if required.
+    pub window_size: Option<Vec2>,
+    /// The scale factor of the window, i.e. physical pixels per logical pixel. Mouse motion is
+    /// reported in physical pixels, so it is divided by this to make drags feel the same on high
+    /// DPI displays. Has no effect if `PanOrbitCamera::adaptive_sensitivity` is `false`.
+    /// `None` is treated as `1.0`.
+    pub scale_factor: Option<f32>,
+    /// Indicates to `PanOrbitCameraPlugin` that it should not update/overwrite this resource.
+    /// If you are manually updating this resource you should set this to `true`.
+    /// Note that setting this to `true` will effectively break multiple viewport/window support
+    /// unless you manually reimplement it.
+    pub manual: bool,
+}
+
+/// Routes all input to a chosen `PanOrbitCamera`, overriding the automatic choice of active camera,
+/// e.g. while a preview rendered to an image has focus. Unlike managing `ActiveCameraData`
+/// yourself, the viewport and window sizes are still kept up to date.
+/// `PanOrbitCameraPlugin` adds this resource.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::ActivePanOrbitCamera;
+/// #[derive(Component)]
+/// struct Preview;
+///
+/// fn focus_preview(
+///     mut active: ResMut<ActivePanOrbitCamera>,
+///     preview: Query<Entity, With<Preview>>,
+/// ) {
+///     active.entity = preview.get_single().ok();
+/// }
+/// ```
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq)]
+pub struct ActivePanOrbitCamera {
+    /// The camera that receives input. `None` chooses the camera under the cursor as usual.
+    pub entity: Option<Entity>,
+}
+
+/// The area of a window that shows the image a `PanOrbitCamera` renders to, see
+/// `PanOrbitCamera::input_region`.
+#[derive(Reflect, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRegion {
+    /// The window the image is shown in.
+    pub window: WindowRef,
+    /// Where the image is shown in the window, in logical pixels from the top left. If the image
+    /// is stretched, the cursor is scaled to match.
+    pub rect: Rect,
+    /// Whether the image keeps its aspect ratio inside `rect`, centred with bars along its sides
+    /// or top and bottom, rather than being stretched to fill it. Input over the bars is ignored.
+    pub letterbox: bool,
+}
+
+// `WindowRef` doesn't implement `PartialEq`
+impl PartialEq for InputRegion {
+    fn eq(&self, other: &Self) -> bool {
+        let same_window = match (self.window, other.window) {
+            (WindowRef::Primary, WindowRef::Primary) => true,
+            (WindowRef::Entity(a), WindowRef::Entity(b)) => a == b,
+            _ => false,
+        };
+        same_window && self.rect == other.rect && self.letterbox == other.letterbox
+    }
+}
+
+/// The shape to restrict the camera's focus inside.
+#[derive(Clone, PartialEq, Debug, Reflect, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum FocusBoundsShape {
+    /// Limit the camera's focus to a sphere centered on `focus_bounds_origin`.
+    Sphere(Sphere),
+    /// Limit the camera's focus to a cuboid centered on `focus_bounds_origin`.
+    Cuboid(Cuboid),
+}
+
+impl From<Sphere> for FocusBoundsShape {
+    fn from(value: Sphere) -> Self {
+        Self::Sphere(value)
+    }
+}
+
+impl From<Cuboid> for FocusBoundsShape {
+    fn from(value: Cuboid) -> Self {
+        Self::Cuboid(value)
+    }
+}
+
+/// Allows for changing the `TrackpadBehavior` from default to the way it works in Blender, or to
+/// the way map and CAD style viewers usually work.
+/// In Blender the trackpad orbits when scrolling. If you hold down the `ShiftLeft`, it Pans and
+/// holding down `ControlLeft` will Zoom.
+/// Mouse wheel scrolling is not affected, and always zooms.
+#[derive(Clone, PartialEq, Debug, Reflect, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackpadBehavior {
+    /// Default touchpad behavior. I.e., no special gesture support, scrolling on the touchpad (vertically) will zoom, as it does with a mouse.
+    Default,
+    /// Blender-like touchpad behavior. Scrolling on the touchpad will orbit, and you can pinch to zoom. Optionally you can pan, or switch scroll to zoom, by holding down a modifier.
+    BlenderLike {
+        /// Modifier key that enables panning while scrolling
+        modifier_pan: Option<KeyCode>,
+
+        /// Modifier key that enables panning while scrolling
+        modifier_zoom: Option<KeyCode>,
+    },
+    /// Touchpad behavior common in map and CAD style viewers. Two-finger scrolling pans, pinching
+    /// zooms (regardless of `trackpad_pinch_to_zoom_enabled`), and holding a modifier while
+    /// scrolling orbits.
+    ScrollPans {
+        /// Modifier key that enables orbiting while scrolling
+        modifier_orbit: Option<KeyCode>,
+        /// Scroll events already take the OS's natural scrolling setting into account, and by
+        /// default the view moves with the fingers, as if dragging it. Set this to `true` to
+        /// move the view the other way.
+        reverse_direction: bool,
+    },
+}
+
+impl TrackpadBehavior {
+    /// Creates a `BlenderLike` variant with default modifiers (Shift for pan, Ctrl for zoom)
+    pub fn blender_default() -> Self {
+        Self::BlenderLike {
+            modifier_pan: Some(KeyCode::ShiftLeft),
+            modifier_zoom: Some(KeyCode::ControlLeft),
+        }
+    }
+
+    /// Creates a `ScrollPans` variant with the default modifier (Alt to orbit)
+    pub fn scroll_pans_default() -> Self {
+        Self::ScrollPans {
+            modifier_orbit: Some(KeyCode::AltLeft),
+            reverse_direction: false,
+        }
+    }
+}
+
+/// Gather data about the active viewport, i.e. the viewport the user is interacting with.
+/// Enables multiple viewports/windows, and cameras that render to images shown in a window.
+#[allow(clippy::too_many_arguments)]
+fn active_viewport_data(
+    mut active_cam: ResMut<ActiveCameraData>,
+    active_override: Res<ActivePanOrbitCamera>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    pinch_events: EventReader<PinchGesture>,
+    scroll_events: EventReader<MouseWheel>,
+    touches: Res<Touches>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    orbit_cameras: Query<(Entity, &Camera, InputSettings)>,
+    mouse_key_tracker: Res<MouseKeyTracker>,
+    #[cfg(feature = "bevy_egui")] egui_wants_focus: Res<EguiWantsFocus>,
+    #[cfg(feature = "bevy_egui")] egui_blocking: Query<&PanOrbitEguiBlocking>,
+) {
+    // The camera's window, or the window its image is shown in
+    let window_of = |camera: &Camera, pan_orbit: &PanOrbitCamera| {
+        let win_ref = match (&pan_orbit.input_region, &camera.target) {
+            (Some(region), _) => region.window,
+            (None, RenderTarget::Window(win_ref)) => *win_ref,
+            (None, _) => return None,
+        };
+        match win_ref {
+            WindowRef::Primary => primary_windows.get_single().ok(),
+            WindowRef::Entity(entity) => other_windows.get(entity).ok(),
+        }
+    };
+    let camera_data = |entity: Entity, camera: &Camera, window: Option<&Window>| ActiveCameraData {
+        entity: Some(entity),
+        viewport_size: camera.logical_viewport_size(),
+        window_size: window.map(|window| Vec2::new(window.width(), window.height())),
+        scale_factor: window.map(|window| window.scale_factor()),
+        manual: false,
+    };
+
+    // An explicitly chosen camera gets all input, even in the middle of a drag
+    if let Some(entity) = active_override.entity {
+        if let Ok((entity, camera, settings)) = orbit_cameras.get(entity) {
+            let pan_orbit = input::input_settings(settings);
+            let window = window_of(camera, pan_orbit).or_else(|| primary_windows.get_single().ok());
+            active_cam.set_if_neq(camera_data(entity, camera, window));
+            return;
+        }
+    }
+
+    // Don't switch cameras in the middle of a drag
+    if mouse_key_tracker.drag.is_some() {
+        return;
+    }
+
+    let mut new_resource = ActiveCameraData::default();
+    // Images are shown on top of the window's own viewports, so their cameras come first
+    let mut max_cam_priority = (false, 0);
+
+    let mut has_input = false;
+    for (entity, camera, settings) in orbit_cameras.iter() {
+        let pan_orbit = input::input_settings(settings);
+        let input_just_activated = input::orbit_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || input::pan_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || !pinch_events.is_empty()
+            || !scroll_events.is_empty()
+            || (touches.iter_just_pressed().count() > 0
+                && touches.iter_just_pressed().count() == touches.iter().count());
+
+        if input_just_activated {
+            has_input = true;
+            #[allow(unused_mut, unused_assignments)]
+            let mut should_get_input = true;
+            #[cfg(feature = "bevy_egui")]
+            {
+                // Images are usually shown with egui, so egui only blocks them if asked to
+                should_get_input = match egui_blocking.get(entity) {
+                    Ok(blocking) => !blocking.is_blocking(),
+                    Err(_) if pan_orbit.input_region.is_some() => true,
+                    Err(_) => !egui_wants_focus.prev && !egui_wants_focus.curr,
+                };
+            }
+            if should_get_input {
+                // First check if cursor is in the same window as this camera
+                let Some(window) = window_of(camera, pan_orbit) else {
+                    // Window does not exist - maybe it was closed and the camera not cleaned up
+                    continue;
+                };
+
+                // Is the cursor/touch in this window?
+                // Note: there's a bug in winit that causes `window.cursor_position()` to return
+                // a `Some` value even if the cursor is not in this window, in very specific cases.
+                // See: https://github.com/Plonq/bevy_panorbit_camera/issues/22
+                if let Some(input_position) = window.cursor_position().or(touches
+                    .iter_just_pressed()
+                    .collect::<Vec<_>>()
+                    .first()
+                    .map(|touch| touch.position()))
+                {
+                    // Now check if cursor is within this camera's viewport, or its image
+                    let rect = input::rendered_rect(camera, pan_orbit.input_region.as_ref());
+                    if let Some(Rect { min, max }) = rect {
+                        // Window coordinates have Y starting at the bottom, so we need to reverse
+                        // the y component before comparing with the viewport rect
+                        let cursor_in_vp = input_position.x > min.x
+                            && input_position.x < max.x
+                            && input_position.y > min.y
+                            && input_position.y < max.y;
+
+                        // Only set if camera order is higher. This may overwrite a previous value
+                        // in the case the viewport is overlapping another viewport.
+                        let priority = (pan_orbit.input_region.is_some(), camera.order);
+                        if cursor_in_vp && priority >= max_cam_priority {
+                            new_resource = camera_data(entity, camera, Some(window));
+                            max_cam_priority = priority;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if has_input {
+        active_cam.set_if_neq(new_resource);
+    }
+}
+
+/// Set up cameras that haven't been initialized yet, i.e. that were just spawned, or had
+/// `initialized` set back to `false`, so they're rendered from the right place on their very first
+/// frame. This runs before `pan_orbit_camera`, and also before transform propagation in
+/// `PostUpdate` if the camera systems run in another schedule, which may not run every frame.
+fn initialize_cameras(
+    mut orbit_cameras: Query<(&mut PanOrbitCamera, &mut Transform, &mut Projection)>,
+) {
+    for (mut pan_orbit, mut transform, mut projection) in orbit_cameras.iter_mut() {
+        if pan_orbit.initialized || pan_orbit.cutscene == CutsceneState::Active {
+            continue;
+        }
+        // Calculate yaw, pitch, and radius from the camera's position. If user sets all
+        // these explicitly, this calculation is wasted, but that's okay since it will only run
+        // once on init.
+        let (yaw, pitch, radius) = util::calculate_from_translation_and_focus(
+            if transform.translation == Vec3::ZERO {
+                pan_orbit.position
+            } else {
+                pan_orbit.from_transform_space(transform.translation)
+            },
+            pan_orbit.focus,
+            pan_orbit.orbit_axis(),
+        );
+        let yaw = *pan_orbit.yaw.get_or_insert(yaw);
+        let pitch = *pan_orbit.pitch.get_or_insert(pitch);
+        let radius = *pan_orbit.radius.get_or_insert(radius);
+
+        // Apply limits
+        let yaw = yaw.clamp_optional(pan_orbit.yaw_lower_limit, pan_orbit.yaw_upper_limit);
+        let pitch = pitch.clamp_optional(pan_orbit.pitch_lower_limit, pan_orbit.pitch_upper_limit);
+        let radius =
+            radius.clamp_optional(Some(pan_orbit.zoom_lower_limit), pan_orbit.zoom_upper_limit);
+        let focus = apply_focus_bounds(
+            pan_orbit.focus,
+            pan_orbit.focus_bounds_origin,
+            pan_orbit.focus_bounds_shape,
+        );
+
+        // Set initial values
+        pan_orbit.yaw = Some(yaw);
+        pan_orbit.pitch = Some(pitch);
+        pan_orbit.radius = Some(radius);
+        pan_orbit.target_yaw = yaw;
+        pan_orbit.target_pitch = pitch;
+        pan_orbit.target_roll = pan_orbit.roll;
+        pan_orbit.target_radius = radius;
+        pan_orbit.target_focus = focus;
+        if pan_orbit.home_view.is_none() {
+            pan_orbit.save_home_view();
+        }
+
+        let axis = pan_orbit.orbit_axis();
+        let roll = pan_orbit.roll;
+        let auto_clip_planes = pan_orbit.auto_clip_planes;
+        let world_offset = pan_orbit.world_offset;
+        util::update_orbit_transform(
+            yaw,
+            pitch,
+            roll,
+            None,
+            radius,
+            focus,
+            &mut transform,
+            &mut pan_orbit.position,
+            &mut projection,
+            axis,
+            auto_clip_planes,
+            world_offset,
+        );
+        pan_orbit.applied_world_offset = Some(world_offset);
+
+        pan_orbit.initialized = true;
+    }
+}
+
+/// Move `focus` to the nearest point inside `shape`, placed at `origin`
+fn apply_focus_bounds(focus: DVec3, origin: DVec3, shape: Option<FocusBoundsShape>) -> DVec3 {
+    let Some(shape) = shape else {
+        return focus;
+    };
+
+    match shape {
+        FocusBoundsShape::Cuboid(shape) => {
+            let point = focus - origin;
+            point.clamp(-shape.half_size.as_dvec3(), shape.half_size.as_dvec3()) + origin
+        }
+        FocusBoundsShape::Sphere(shape) => {
+            let point = focus - origin;
+            let distance_squared = point.length_squared();
+
+            let closest_point = if distance_squared <= (shape.radius as f64).powi(2) {
+                // The point is inside the sphere.
+                point
+            } else {
+                // The point is outside the sphere.
+                // Find the closest point on the surface of the sphere.
+                let dir_to_point = point / distance_squared.sqrt();
+                shape.radius as f64 * dir_to_point
+            };
+            closest_point + origin
+        }
+    }
+}
+
+/// The point under `position` in the viewport, on the plane through the target focus that faces
+/// the camera, for zooming towards
+fn zoom_point(
+    pan_orbit: &PanOrbitCamera,
+    camera: &Camera,
+    transform: &Transform,
+    position: Vec2,
+) -> Option<DVec3> {
+    let ray = camera
+        .viewport_to_world(&GlobalTransform::from(*transform), position)
+        .ok()?;
+    util::ray_plane_intersection(
+        pan_orbit.from_transform_space(ray.origin),
+        ray.direction.as_dvec3(),
+        pan_orbit.target_focus,
+        transform.back().as_dvec3(),
+    )
+}
+
+/// Main system for processing input and converting to transformations
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn pan_orbit_camera(
+    active_cam: Res<ActiveCameraData>,
+    mouse_key_tracker: Res<MouseKeyTracker>,
+    touch_tracker: Res<TouchTracker>,
+    gamepad_tracker: Res<GamepadTracker>,
+    edge_pan_tracker: Res<EdgePanTracker>,
+    mut orbit_cameras: Query<(
+        Entity,
+        &mut PanOrbitCamera,
+        &mut TimeSinceInput,
+        &mut Transform,
+        &mut Projection,
+        &Camera,
+        Option<&SensitivityCurves>,
+    )>,
+    curves: Option<Res<Assets<ResponseCurve>>>,
+    time: CameraTime,
+    mut average_frame_time: Local<f32>,
+) {
+    // Input pacing tells a hitch apart from a low frame rate by the frame times before it
+    let recent_frame_time = *average_frame_time;
+    *average_frame_time = pacing::average_frame_time(recent_frame_time, time.delta_secs());
+
+    for (
+        entity,
+        mut pan_orbit,
+        mut time_since_input,
+        mut transform,
+        mut projection,
+        camera,
+        sensitivity_curves,
+    ) in orbit_cameras.iter_mut()
+    {
+        // A cutscene is in control, so leave the camera alone
+        if pan_orbit.cutscene == CutsceneState::Active {
+            continue;
+        }
+
+        // Closures that apply limits to the yaw, pitch, and zoom values
+        let apply_zoom_limits = {
+            let zoom_upper_limit = pan_orbit.zoom_upper_limit;
+            let zoom_lower_limit = pan_orbit.zoom_lower_limit;
+            move |zoom: f64| zoom.clamp_optional(Some(zoom_lower_limit), zoom_upper_limit)
+        };
+
+        let apply_yaw_limits = {
+            let yaw_upper_limit = pan_orbit.yaw_upper_limit;
+            let yaw_lower_limit = pan_orbit.yaw_lower_limit;
+            move |yaw: f64| yaw.clamp_optional(yaw_lower_limit, yaw_upper_limit)
+        };
+
+        let apply_pitch_limits = {
+            let pitch_upper_limit = pan_orbit.pitch_upper_limit;
+            let pitch_lower_limit = pan_orbit.pitch_lower_limit;
+            move |pitch: f64| pitch.clamp_optional(pitch_lower_limit, pitch_upper_limit)
+        };
+
+        let apply_focus_limits = {
+            let origin = pan_orbit.focus_bounds_origin;
+            let shape = pan_orbit.focus_bounds_shape;
+            move |focus: DVec3| apply_focus_bounds(focus, origin, shape)
+        };
+
+        // Re-derive the orbit state from where the cutscene left the camera
+        if let CutsceneState::Resuming {
+            from: None,
+            duration,
+            elapsed,
+        } = pan_orbit.cutscene
+        {
+            let radius = match *projection {
+                Projection::Orthographic(ref p) => p.scale as f64,
+                _ => pan_orbit.radius.unwrap_or(pan_orbit.target_radius),
+            };
+            let translation = pan_orbit.from_transform_space(transform.translation);
+            let focus = translation
+                + transform.forward().as_dvec3() * util::camera_distance(radius, &projection);
+            let (yaw, pitch, _) = util::calculate_from_translation_and_focus(
+                translation,
+                focus,
+                pan_orbit.orbit_axis(),
+            );
+            let yaw = util::nearest_equivalent_angle(pan_orbit.yaw.unwrap_or(yaw), yaw);
+            pan_orbit.yaw = Some(yaw);
+            pan_orbit.pitch = Some(pitch);
+            pan_orbit.radius = Some(radius);
+            pan_orbit.focus = focus;
+            let roll = pan_orbit.target_roll;
+            pan_orbit.set_target_pose(CameraPose {
+                focus,
+                yaw,
+                pitch,
+                roll,
+                orientation: Some(transform.rotation.as_dquat()),
+                radius,
+            });
+            pan_orbit.orientation = pan_orbit.target_orientation;
+            pan_orbit.cutscene = CutsceneState::Resuming {
+                from: Some(*transform),
+                duration,
+                elapsed,
+            };
+            pan_orbit.force_update = true;
+        }
+
+        // 1 - Get Input
 
+        // Speed-based input, like keys and gamepad sticks, doesn't jump ahead after a long frame
+        let input_dt = match pan_orbit.input_pacing {
+            Some(pacing) => pacing.input_dt(time.delta_secs_f64(), recent_frame_time),
+            None => time.delta_secs_f64(),
+        };
         let mut orbit = DVec2::ZERO;
         let mut pan = DVec2::ZERO;
+        // How many logical pixels the cursor or fingers moved for each unit of `pan`, before
+        // sensitivity
+        let mut pan_cursor_scale = 1.0;
+        let mut roll = 0.0;
+        let mut zoom_drag = 0.0;
+        let mut zoom_drag_start = None;
         let mut scroll_line = 0.0;
         let mut scroll_pixel = 0.0;
+        let mut scroll_fov = 0.0;
         let mut orbit_button_changed = false;
+        let mut cursor_position = None;
+        let mut reset_requested = false;
+        let mut fly_direction = Vec3::ZERO;
+        let mut toggle_fly = false;
+        let mut touch_yaw = 0.0;
+        let mut orbit_held = false;
+        let mut pan_held = false;
+        let mut press_started = false;
+        let mut box_zoom = None;
+        let mut box_zoom_rect = None;
+        let mut gesture = CameraGesture::None;
+        let mut key_orbit = DVec2::ZERO;
+        let mut key_pan = DVec2::ZERO;
+        let mut key_zoom = 0.0;
+        let mut input_device = None;
 
         // The reason we only skip getting input if the camera is inactive/disabled is because
         // it might still be moving (lerping towards target values) when the user is not
         // actively controlling it.
-        if pan_orbit.enabled && active_cam.entity == Some(entity) {
+        if pan_orbit.accepts_input() && active_cam.entity == Some(entity) {
             let zoom_direction = match pan_orbit.reversed_zoom {
                 true => -1.0,
                 false => 1.0,
             };
 
-            orbit = mouse_key_tracker.orbit.as_dvec2() * pan_orbit.orbit_sensitivity;
-            pan = mouse_key_tracker.pan.as_dvec2() * pan_orbit.pan_sensitivity;
+            // Mouse motion is in physical pixels, but window and viewport sizes are logical
+            let mouse_scale = match pan_orbit.adaptive_sensitivity {
+                true => 1.0 / active_cam.scale_factor.unwrap_or(1.0) as f64,
+                false => 1.0,
+            };
+            orbit = mouse_key_tracker.orbit.as_dvec2() * mouse_scale * pan_orbit.orbit_sensitivity;
+            pan = mouse_key_tracker.pan.as_dvec2() * mouse_scale * pan_orbit.pan_sensitivity;
+            pan_cursor_scale = 1.0 / (active_cam.scale_factor.unwrap_or(1.0) as f64 * mouse_scale);
+            roll = mouse_key_tracker.roll as f64 * mouse_scale * pan_orbit.orbit_sensitivity;
+            zoom_drag = mouse_key_tracker.zoom_drag as f64
+                * mouse_scale
+                * zoom_direction
+                * pan_orbit.zoom_sensitivity;
+            zoom_drag_start = mouse_key_tracker.zoom_drag_start;
             scroll_line =
                 mouse_key_tracker.scroll_line as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
             scroll_pixel =
                 mouse_key_tracker.scroll_pixel as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+            scroll_fov =
+                mouse_key_tracker.scroll_fov as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
             orbit_button_changed = mouse_key_tracker.orbit_button_changed;
+            cursor_position = mouse_key_tracker.cursor_position;
+            reset_requested = mouse_key_tracker.reset_requested;
+            fly_direction = mouse_key_tracker.fly_direction;
+            toggle_fly = mouse_key_tracker.toggle_fly;
+            let drag_action = mouse_key_tracker.drag.map(|drag| drag.action);
+            orbit_held = drag_action == Some(DragAction::Orbit);
+            pan_held = drag_action == Some(DragAction::Pan);
+            press_started = mouse_key_tracker.button_just_pressed;
+            box_zoom = mouse_key_tracker.box_zoom;
+            box_zoom_rect = mouse_key_tracker.box_zoom_rect;
+            if let Some(controls) = pan_orbit.keyboard_controls {
+                // Keys either move by a step per press, or at a speed for as long as they're held
+                let (orbit_scale, pan_scale, zoom_scale) = match mouse_key_tracker.key_step {
+                    true => (controls.orbit_step, controls.pan_step, controls.zoom_step),
+                    false => {
+                        let dt = input_dt;
+                        let orbit_speed = controls
+                            .orbit_speed
+                            .speed(mouse_key_tracker.key_orbit_held_for);
+                        let pan_speed =
+                            controls.pan_speed.speed(mouse_key_tracker.key_pan_held_for);
+                        (orbit_speed * dt, pan_speed * dt, controls.zoom_speed * dt)
+                    }
+                };
+                key_orbit = mouse_key_tracker.key_orbit.as_dvec2()
+                    * orbit_scale
+                    * pan_orbit.orbit_sensitivity;
+                key_pan =
+                    mouse_key_tracker.key_pan.as_dvec2() * pan_scale * pan_orbit.pan_sensitivity;
+                key_zoom =
+                    mouse_key_tracker.key_zoom as f64 * zoom_scale * pan_orbit.zoom_sensitivity;
+            }
+            if drag_action.is_some()
+                || orbit != DVec2::ZERO
+                || pan != DVec2::ZERO
+                || roll != 0.0
+                || scroll_line != 0.0
+                || scroll_pixel != 0.0
+                || scroll_fov != 0.0
+            {
+                input_device = Some(InputDevice::Mouse);
+            }
+            if key_orbit != DVec2::ZERO || key_pan != DVec2::ZERO || key_zoom != 0.0 {
+                input_device = Some(InputDevice::Keyboard);
+            }
+            // A press without moving is a click, e.g. to pick something, not a drag
+            let drag_gesture = mouse_key_tracker
+                .drag
+                .filter(|drag| drag.moved)
+                .map(|drag| drag.action);
+            gesture = match drag_gesture {
+                Some(DragAction::Orbit | DragAction::Roll) => CameraGesture::Orbit,
+                Some(DragAction::Pan) => CameraGesture::Pan,
+                Some(DragAction::BoxZoom | DragAction::Zoom) => CameraGesture::Zoom,
+                None if scroll_line != 0.0 || scroll_pixel != 0.0 || scroll_fov != 0.0 => {
+                    CameraGesture::Zoom
+                }
+                None => CameraGesture::None,
+            };
 
             if pan_orbit.touch_enabled {
-                let (touch_orbit, touch_pan, touch_zoom_pixel) = match pan_orbit.touch_controls {
-                    TouchControls::OneFingerOrbit => match touch_tracker.get_touch_gestures() {
-                        TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0),
-                        TouchGestures::OneFinger(one_finger_gestures) => {
-                            (one_finger_gestures.motion, Vec2::ZERO, 0.0)
-                        }
-                        TouchGestures::TwoFinger(two_finger_gestures) => (
-                            Vec2::ZERO,
-                            two_finger_gestures.motion,
-                            two_finger_gestures.pinch * 0.015,
-                        ),
-                    },
-                    TouchControls::TwoFingerOrbit => match touch_tracker.get_touch_gestures() {
-                        TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0),
-                        TouchGestures::OneFinger(one_finger_gestures) => {
-                            (Vec2::ZERO, one_finger_gestures.motion, 0.0)
-                        }
-                        TouchGestures::TwoFinger(two_finger_gestures) => (
-                            two_finger_gestures.motion,
-                            Vec2::ZERO,
-                            two_finger_gestures.pinch * 0.015,
-                        ),
-                    },
-                };
+                let touch = pan_orbit
+                    .touch_controls
+                    .process(&touch_tracker.get_touch_gestures());
+                orbit += touch.orbit.as_dvec2() * pan_orbit.orbit_sensitivity;
+                pan += touch.pan.as_dvec2() * pan_orbit.pan_sensitivity;
+                // Touch positions are already in logical pixels
+                if touch.pan != Vec2::ZERO {
+                    pan_cursor_scale = 1.0;
+                }
+                scroll_pixel += touch.zoom as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+                touch_yaw = touch.yaw as f64;
+                if touch_tracker.is_touching() {
+                    input_device = Some(InputDevice::Touch);
+                    orbit_held = true;
+                    pan_held = true;
+                    // Keep showing the last gesture while the fingers are still
+                    gesture = if touch.zoom != 0.0 {
+                        CameraGesture::Zoom
+                    } else if touch.pan != Vec2::ZERO {
+                        CameraGesture::Pan
+                    } else if touch.orbit != Vec2::ZERO || touch.yaw != 0.0 {
+                        CameraGesture::Orbit
+                    } else {
+                        pan_orbit.gesture
+                    };
+                }
+                press_started |= touch_tracker.touch_just_started();
+            }
+        }
+
+        // Pan while the cursor is near the edge of the viewport. This is in multiples of the
+        // radius, or the equivalent distance for orthographic cameras.
+        let edge_pan = match (pan_orbit.edge_pan, edge_pan_tracker.directions.get(&entity)) {
+            (Some(settings), Some(direction))
+                if pan_orbit.accepts_input()
+                    && pan_orbit.pan_enabled
+                    && pan_orbit.control_mode != ControlMode::Fly =>
+            {
+                direction.as_dvec2() * settings.speed * input_dt
+            }
+            _ => DVec2::ZERO,
+        };
+        if gesture == CameraGesture::None && edge_pan != DVec2::ZERO {
+            gesture = CameraGesture::Pan;
+        }
+
+        if pan_orbit.gesture != gesture {
+            pan_orbit.gesture = gesture;
+        }
+
+        // Gamepad input isn't limited to the active camera, so each camera shapes it with its own
+        // curve
+        let gamepad_curve = sensitivity_curves
+            .and_then(|sensitivity_curves| sensitivity_curves.gamepad.as_ref())
+            .zip(curves.as_ref())
+            .and_then(|(handle, curves)| curves.get(handle));
+        let shape_stick = |stick: Vec2| gamepad_curve.map_or(stick, |curve| curve.apply(stick));
+        let mut gamepad_orbit = DVec2::ZERO;
+        let mut gamepad_pan = DVec2::ZERO;
+        let mut gamepad_zoom = 0.0;
+        if let Some(controls) = pan_orbit
+            .gamepad_controls
+            .filter(|_| pan_orbit.accepts_input())
+        {
+            gamepad_orbit =
+                util::apply_dead_zone(shape_stick(gamepad_tracker.orbit), controls.dead_zone)
+                    .as_dvec2()
+                    * controls.orbit_sensitivity;
+            gamepad_pan =
+                util::apply_dead_zone(shape_stick(gamepad_tracker.pan), controls.dead_zone)
+                    .as_dvec2()
+                    * controls.pan_sensitivity;
+            gamepad_zoom = gamepad_tracker.zoom as f64 * controls.zoom_sensitivity;
+            if gamepad_orbit != DVec2::ZERO || gamepad_pan != DVec2::ZERO || gamepad_zoom != 0.0 {
+                input_device = Some(InputDevice::Gamepad);
+            }
+        }
+        if let Some(device) = input_device.filter(|device| *device != pan_orbit.input_device) {
+            pan_orbit.input_device = device;
+        }
+
+        // Drop input for actions the user isn't allowed to do, along with any momentum they had
+        let orbit_mask = pan_orbit.orbit_mask();
+        orbit *= orbit_mask;
+        key_orbit *= orbit_mask;
+        gamepad_orbit *= orbit_mask;
+        if orbit_mask.x == 0.0 {
+            touch_yaw = 0.0;
+        }
+        if !pan_orbit.orbit_enabled {
+            roll = 0.0;
+        }
+        if !pan_orbit.pan_enabled {
+            (pan, key_pan, gamepad_pan) = (DVec2::ZERO, DVec2::ZERO, DVec2::ZERO);
+        }
+        if !pan_orbit.zoom_enabled {
+            (scroll_line, scroll_pixel, scroll_fov, zoom_drag) = (0.0, 0.0, 0.0, 0.0);
+            (key_zoom, gamepad_zoom, box_zoom) = (0.0, 0.0, None);
+        }
+        let orbit_velocity = pan_orbit.orbit_velocity * orbit_mask;
+        if pan_orbit.orbit_velocity != orbit_velocity {
+            pan_orbit.orbit_velocity = orbit_velocity;
+        }
+        if !pan_orbit.pan_enabled && pan_orbit.pan_velocity != DVec3::ZERO {
+            pan_orbit.pan_velocity = DVec3::ZERO;
+        }
+        if !pan_orbit.zoom_enabled && pan_orbit.zoom_velocity != 0.0 {
+            pan_orbit.zoom_velocity = 0.0;
+        }
+
+        let interacted = gesture != CameraGesture::None
+            || press_started
+            || reset_requested
+            || toggle_fly
+            || orbit != DVec2::ZERO
+            || pan != DVec2::ZERO
+            || roll != 0.0
+            || touch_yaw != 0.0
+            || scroll_line != 0.0
+            || scroll_pixel != 0.0
+            || scroll_fov != 0.0
+            || fly_direction != Vec3::ZERO
+            || key_orbit != DVec2::ZERO
+            || key_pan != DVec2::ZERO
+            || key_zoom != 0.0
+            || edge_pan != DVec2::ZERO
+            || gamepad_orbit != DVec2::ZERO
+            || gamepad_pan != DVec2::ZERO
+            || gamepad_zoom != 0.0;
+        time_since_input.0 = match interacted {
+            true => 0.0,
+            false => time_since_input.0 + time.delta_secs(),
+        };
 
-                orbit += touch_orbit.as_dvec2() * pan_orbit.orbit_sensitivity;
-                pan += touch_pan.as_dvec2() * pan_orbit.pan_sensitivity;
-                scroll_pixel +=
-                    touch_zoom_pixel as f64 * zoom_direction * pan_orbit.zoom_sensitivity;
+        // Spread mouse and touch input from long frames over the following frames
+        if let Some(pacing) = pan_orbit.input_pacing {
+            let input = PendingInput {
+                orbit,
+                pan,
+                roll,
+                zoom_drag,
+                touch_yaw,
+                scroll_line,
+                scroll_pixel,
+                scroll_fov,
+                remaining: 0.0,
+            };
+            let mut pending_input = pan_orbit.pending_input;
+            let paced = pending_input.pace(input, time.delta_secs(), pacing, recent_frame_time);
+            if pan_orbit.pending_input != pending_input {
+                pan_orbit.pending_input = pending_input;
             }
+            (orbit, pan, roll, touch_yaw) = (paced.orbit, paced.pan, paced.roll, paced.touch_yaw);
+            zoom_drag = paced.zoom_drag;
+            (scroll_line, scroll_pixel, scroll_fov) =
+                (paced.scroll_line, paced.scroll_pixel, paced.scroll_fov);
+        } else if pan_orbit.pending_input != PendingInput::default() {
+            pan_orbit.pending_input = PendingInput::default();
         }
 
         // 2 - Process input into target yaw/pitch, or focus, radius
 
-        if orbit_button_changed {
-            // Only check for upside down when orbiting started or ended this frame,
-            // so we don't reverse the yaw direction while the user is still dragging
-            let wrapped_pitch = (pan_orbit.target_pitch % TAU).abs();
-            pan_orbit.is_upside_down = wrapped_pitch > TAU / 4.0 && wrapped_pitch < 3.0 * TAU / 4.0;
+        let orbiting = orbit_held || key_orbit != DVec2::ZERO || gamepad_orbit != DVec2::ZERO;
+        if orbit_button_changed || !orbiting {
+            // Don't check for upside down while the user is orbiting, so the yaw direction doesn't
+            // reverse when going over the top, whichever device they're orbiting with
+            let is_upside_down = util::is_upside_down(pan_orbit.target_pitch);
+            if pan_orbit.is_upside_down != is_upside_down {
+                pan_orbit.is_upside_down = is_upside_down;
+            }
         }
 
         let mut has_moved = false;
+        if toggle_fly {
+            pan_orbit.control_mode = match pan_orbit.control_mode {
+                ControlMode::Orbit => ControlMode::Fly,
+                ControlMode::Fly => {
+                    // Re-derive the focus from where the camera ended up, and settle the
+                    // targets so the camera doesn't keep drifting after switching
+                    let distance = util::camera_distance(
+                        pan_orbit.radius.unwrap_or(pan_orbit.target_radius),
+                        &projection,
+                    );
+                    pan_orbit.focus = pan_orbit.from_transform_space(transform.translation)
+                        + transform.forward().as_dvec3() * distance;
+                    pan_orbit.target_focus = pan_orbit.focus;
+                    if let Some(yaw) = pan_orbit.yaw {
+                        pan_orbit.target_yaw = yaw;
+                    }
+                    if let Some(pitch) = pan_orbit.pitch {
+                        pan_orbit.target_pitch = pitch;
+                    }
+                    ControlMode::Orbit
+                }
+            };
+        }
+        let is_flying = pan_orbit.control_mode == ControlMode::Fly;
+        let mut fly_translation = DVec3::ZERO;
+        if is_flying {
+            scroll_line = 0.0;
+            scroll_pixel = 0.0;
+            if fly_direction != Vec3::ZERO {
+                fly_translation = transform.rotation.as_dquat()
+                    * fly_direction.normalize().as_dvec3()
+                    * pan_orbit.fly_speed
+                    * input_dt;
+                has_moved = true;
+            }
+        }
+        // Like cancelling gestures, freezing only applies to the camera receiving input
+        if mouse_key_tracker.focus_lost
+            && active_cam.entity == Some(entity)
+            && pan_orbit.focus_loss_policy == FocusLossPolicy::Freeze
+        {
+            pan_orbit.stop_motion();
+        }
+        if press_started && pan_orbit.momentum.enabled && pan_orbit.momentum.stop_on_press {
+            pan_orbit.stop_motion();
+        }
+        if reset_requested {
+            pan_orbit.reset_to_home(true);
+            has_moved = true;
+        }
+        if pan_orbit.box_zoom_rect != box_zoom_rect {
+            pan_orbit.box_zoom_rect = box_zoom_rect;
+        }
+        // The radius before zooming, which `ZoomMode::Fov` turns into a change of field of view
+        let radius_before_zoom = (pan_orbit.target_radius, pan_orbit.radius);
+        if let (Some(rect), Some(vp_size)) = (box_zoom, active_cam.viewport_size) {
+            // Ignore clicks and tiny rectangles, which are almost certainly accidental
+            if rect.width() > MIN_BOX_ZOOM_SIZE && rect.height() > MIN_BOX_ZOOM_SIZE && !is_flying {
+                // Centre the focus on the middle of the rectangle, keeping it the same distance
+                // from the camera, then zoom in until the rectangle fills the viewport
+                let new_focus = camera
+                    .viewport_to_world(&GlobalTransform::from(*transform), rect.center())
+                    .ok()
+                    .and_then(|ray| {
+                        util::ray_plane_intersection(
+                            pan_orbit.from_transform_space(ray.origin),
+                            ray.direction.as_dvec3(),
+                            pan_orbit.focus,
+                            transform.back().as_dvec3(),
+                        )
+                    });
+                if let (Some(focus), Some(yaw), Some(pitch), Some(radius)) =
+                    (new_focus, pan_orbit.yaw, pan_orbit.pitch, pan_orbit.radius)
+                {
+                    // Fill the part of the viewport that isn't covered by UI
+                    let extent = util::safe_region(vp_size, &pan_orbit.framing_insets).0;
+                    let ratio = rect.size() / (vp_size * extent.as_vec2());
+                    let scale = match pan_orbit.framing_fit {
+                        FramingFit::Both => ratio.max_element(),
+                        FramingFit::Vertical => ratio.y,
+                        FramingFit::Horizontal => ratio.x,
+                    } as f64;
+                    let pose = CameraPose {
+                        focus,
+                        yaw,
+                        pitch,
+                        roll: pan_orbit.roll,
+                        orientation: pan_orbit.orientation,
+                        radius: apply_zoom_limits(radius * scale),
+                    };
+                    let pose = pan_orbit.safe_region_pose(pose, camera, &projection);
+                    pan_orbit.animate_to(
+                        pose,
+                        Duration::from_secs_f32(BOX_ZOOM_DURATION),
+                        EaseFunction::CubicOut,
+                    );
+                }
+            }
+        }
+        let orbit_start = DVec2::new(pan_orbit.target_yaw, pan_orbit.target_pitch);
+        let pan_start = pan_orbit.target_focus;
+        let mut trackball_rotation = DQuat::IDENTITY;
         if orbit.length_squared() > 0.0 {
             // Use window size for rotation otherwise the sensitivity
             // is far too high for small viewports
-            if let Some(win_size) = active_cam.window_size {
+            let win_size = match pan_orbit.adaptive_sensitivity {
+                true => active_cam.window_size,
+                false => Some(RAW_SENSITIVITY_REFERENCE_SIZE),
+            };
+            if let Some(win_size) =
+                win_size.filter(|_| pan_orbit.orbit_mode == OrbitMode::Trackball)
+            {
+                // Turn around the camera's own axes, so the handedness of `axis` and being upside
+                // down don't matter
+                let delta_x = orbit.x / win_size.x as f64 * PI * 2.0;
+                let delta_y = orbit.y / win_size.y as f64 * PI;
+                trackball_rotation =
+                    DQuat::from_rotation_y(-delta_x) * DQuat::from_rotation_x(-delta_y);
+                has_moved = true;
+            } else if let Some(win_size) = win_size {
                 let delta_x = {
                     let delta = orbit.x / win_size.x as f64 * PI * 2.0;
-                    if pan_orbit.is_upside_down {
+                    // Mirrored axes reverse which way yaw turns the camera
+                    if pan_orbit.is_upside_down != util::is_left_handed(pan_orbit.axis) {
                         -delta
                     } else {
                         delta
@@ -680,16 +3519,78 @@ fn pan_orbit_camera(
                 has_moved = true;
             }
         }
-        if pan.length_squared() > 0.0 {
+        if roll != 0.0 {
+            let win_size = match pan_orbit.adaptive_sensitivity {
+                true => active_cam.window_size,
+                false => Some(RAW_SENSITIVITY_REFERENCE_SIZE),
+            };
+            if let Some(win_size) = win_size {
+                // Dragging across the window rolls as far as it would yaw
+                let delta = roll / win_size.x as f64 * PI * 2.0;
+                if pan_orbit.orbit_mode == OrbitMode::Trackball {
+                    trackball_rotation *= DQuat::from_rotation_z(delta);
+                } else {
+                    pan_orbit.target_roll += delta;
+                }
+                has_moved = true;
+            }
+        }
+        if touch_yaw != 0.0 {
+            // Like mouse orbiting, being upside down or mirrored axes reverse which way yaw turns
+            // the view
+            let yaw_direction =
+                match pan_orbit.is_upside_down != util::is_left_handed(pan_orbit.axis) {
+                    true => -1.0,
+                    false => 1.0,
+                };
+            pan_orbit.target_yaw += touch_yaw * yaw_direction;
+            has_moved = true;
+        }
+        let pan_plane = match pan_orbit.pan_mode {
+            PanMode::Plane { point, normal } if !is_flying => Some((point, normal.normalize())),
+            _ => None,
+        };
+        if let Some((point, normal)) = pan_plane.filter(|_| pan.length_squared() > 0.0) {
+            // Move the focus by how far the point under the cursor moved along the plane. Without
+            // a cursor, e.g. with touch, drag from the middle of the viewport instead.
+            let viewport_center = active_cam.viewport_size.map(|size| size / 2.0);
+            let plane_hit = |position: Vec2| {
+                let ray = camera
+                    .viewport_to_world(&GlobalTransform::from(*transform), position)
+                    .ok()?;
+                let direction = ray.direction.as_dvec3();
+                if direction.dot(normal).abs() < MIN_PAN_PLANE_ANGLE_COS {
+                    return None;
+                }
+                let origin = pan_orbit.from_transform_space(ray.origin);
+                util::ray_plane_intersection(origin, direction, point, normal)
+            };
+            // Follow how far the cursor actually moved on screen, and apply the sensitivity to the
+            // distance moved on the plane instead
+            let sensitivity = pan_orbit.pan_sensitivity;
+            let cursor_delta = pan / sensitivity * pan_cursor_scale;
+            if let Some(to) = cursor_position.or(viewport_center) {
+                let from = to - cursor_delta.as_vec2();
+                if let (Some(from), Some(to)) = (plane_hit(from), plane_hit(to)) {
+                    pan_orbit.target_focus += (from - to) * sensitivity;
+                    has_moved = true;
+                }
+            }
+        } else if pan.length_squared() > 0.0 {
             // Make panning distance independent of resolution and FOV,
-            if let Some(vp_size) = active_cam.viewport_size {
+            let vp_size = match pan_orbit.adaptive_sensitivity {
+                true => active_cam.viewport_size,
+                false => Some(RAW_SENSITIVITY_REFERENCE_SIZE),
+            };
+            if let Some(vp_size) = vp_size {
                 let mut multiplier = 1.0;
                 match *projection {
                     Projection::Perspective(ref p) => {
                         pan *= (Vec2::new(p.fov * p.aspect_ratio, p.fov) / vp_size).as_dvec2();
-                        // Make panning proportional to distance away from focus point
+                        // Make panning proportional to distance away from focus point, or from the
+                        // geometry being dragged
                         if let Some(radius) = pan_orbit.radius {
-                            multiplier = radius;
+                            multiplier = pan_orbit.cursor_depth.unwrap_or(radius);
                         }
                     }
                     Projection::Orthographic(ref p) => {
@@ -700,14 +3601,87 @@ fn pan_orbit_camera(
                 let right = transform.rotation.as_dquat() * pan_orbit.pan_axis[0] * -pan.x;
                 let up = transform.rotation.as_dquat() * pan_orbit.pan_axis[1] * pan.y;
                 let translation = (right + up) * multiplier;
-                pan_orbit.target_focus += translation;
+                if is_flying {
+                    fly_translation += translation;
+                } else {
+                    pan_orbit.target_focus += translation;
+                }
                 has_moved = true;
             }
         }
+        let momentum = pan_orbit.momentum;
+        if momentum.enabled && !is_flying {
+            let pan_orbit = &mut *pan_orbit;
+            let dt = time.delta_secs_f64();
+            if dt > 0.0 {
+                // While dragging, track how fast the drag is moving the camera. After it's
+                // released, keep moving at that speed, slowing down according to the friction.
+                let orbit_delta =
+                    DVec2::new(pan_orbit.target_yaw, pan_orbit.target_pitch) - orbit_start;
+                let orbit_settings = momentum.settings(&momentum.orbit);
+                if orbit_held {
+                    pan_orbit.orbit_velocity = pan_orbit
+                        .orbit_velocity
+                        .lerp(orbit_delta / dt, VELOCITY_SMOOTHING);
+                } else if let Some((friction, max_speed)) =
+                    orbit_settings.filter(|_| pan_orbit.orbit_velocity != DVec2::ZERO)
+                {
+                    if let Some(max_speed) = max_speed {
+                        pan_orbit.orbit_velocity =
+                            pan_orbit.orbit_velocity.clamp_length_max(max_speed);
+                    }
+                    pan_orbit.target_yaw += pan_orbit.orbit_velocity.x * dt;
+                    pan_orbit.target_pitch += pan_orbit.orbit_velocity.y * dt;
+                    pan_orbit.orbit_velocity *= (-friction * dt).exp();
+                    if pan_orbit.orbit_velocity.length() < MIN_ORBIT_VELOCITY {
+                        pan_orbit.orbit_velocity = DVec2::ZERO;
+                    }
+                    has_moved = true;
+                } else {
+                    pan_orbit.orbit_velocity = DVec2::ZERO;
+                }
+                let pan_delta = pan_orbit.target_focus - pan_start;
+                let pan_settings = momentum.settings(&momentum.pan);
+                if pan_held {
+                    pan_orbit.pan_velocity = pan_orbit
+                        .pan_velocity
+                        .lerp(pan_delta / dt, VELOCITY_SMOOTHING);
+                } else if let Some((friction, max_speed)) =
+                    pan_settings.filter(|_| pan_orbit.pan_velocity != DVec3::ZERO)
+                {
+                    if let Some(max_speed) = max_speed {
+                        pan_orbit.pan_velocity = pan_orbit
+                            .pan_velocity
+                            .clamp_length_max(max_speed * pan_orbit.target_radius);
+                    }
+                    pan_orbit.target_focus += pan_orbit.pan_velocity * dt;
+                    pan_orbit.pan_velocity *= (-friction * dt).exp();
+                    // Panning speed scales with the radius, so the cutoff should too
+                    if pan_orbit.pan_velocity.length() < MIN_PAN_VELOCITY * pan_orbit.target_radius
+                    {
+                        pan_orbit.pan_velocity = DVec3::ZERO;
+                    }
+                    has_moved = true;
+                } else {
+                    pan_orbit.pan_velocity = DVec3::ZERO;
+                }
+            }
+        }
         if (scroll_line + scroll_pixel).abs() > 0.0 {
-            // Calculate the impact of scrolling on the reference value
-            let line_delta = -scroll_line * (pan_orbit.target_radius) * 0.2;
-            let pixel_delta = -scroll_pixel * (pan_orbit.target_radius) * 0.2;
+            // Calculate the impact of scrolling on the reference value. Slow down near the
+            // geometry under the cursor.
+            let step = match (pan_orbit.cursor_depth, &*projection) {
+                (Some(depth), Projection::Perspective(_)) => depth.min(pan_orbit.target_radius),
+                _ => pan_orbit.target_radius,
+            };
+            let line_delta = -scroll_line * step * 0.2;
+            let pixel_delta = -scroll_pixel * step * 0.2;
+
+            // Find the point under the cursor before the zoom changes anything
+            let zoom_point = cursor_position
+                .filter(|_| pan_orbit.zoom_to_cursor)
+                .and_then(|cursor| zoom_point(&pan_orbit, camera, &transform, cursor));
+            let old_target_radius = pan_orbit.target_radius;
 
             // Update the target value
             pan_orbit.target_radius += line_delta + pixel_delta;
@@ -717,20 +3691,316 @@ fn pan_orbit_camera(
                 .radius
                 .map(|value| apply_zoom_limits(value + pixel_delta));
 
+            // Move the focus towards the point under the cursor by the same proportion as the
+            // radius changed, which keeps that point stationary on screen
+            if let Some(zoom_point) = zoom_point {
+                let new_target_radius = apply_zoom_limits(pan_orbit.target_radius);
+                let factor = 1.0 - new_target_radius / old_target_radius;
+                let offset = (zoom_point - pan_orbit.target_focus) * factor;
+                pan_orbit.target_focus += offset;
+                if pixel_delta != 0.0 {
+                    pan_orbit.focus += offset;
+                }
+            }
+
+            has_moved = true;
+        }
+
+        if zoom_drag != 0.0 && !is_flying {
+            let win_size = match pan_orbit.adaptive_sensitivity {
+                true => active_cam.window_size,
+                false => Some(RAW_SENSITIVITY_REFERENCE_SIZE),
+            };
+            if let Some(win_size) = win_size {
+                // Zoom by a proportion of the radius, towards the point under where the drag
+                // started, keeping that point stationary on screen like zooming to the cursor
+                let zoom_point = zoom_drag_start
+                    .and_then(|start| zoom_point(&pan_orbit, camera, &transform, start));
+                let old_target_radius = pan_orbit.target_radius;
+                let amount = zoom_drag / win_size.y as f64 * ZOOM_DRAG_PER_HEIGHT;
+                pan_orbit.target_radius *= (-amount).exp();
+                if let Some(zoom_point) = zoom_point {
+                    let new_target_radius = apply_zoom_limits(pan_orbit.target_radius);
+                    let factor = 1.0 - new_target_radius / old_target_radius;
+                    let offset = (zoom_point - pan_orbit.target_focus) * factor;
+                    pan_orbit.target_focus += offset;
+                }
+                has_moved = true;
+            }
+        }
+
+        if scroll_fov != 0.0 && pan_orbit.projection_switch.is_none() {
+            if let Projection::Perspective(ref mut p) = *projection {
+                // Scrolling up narrows the field of view, like zooming in
+                let fov = p.fov * (-scroll_fov * 0.1).exp() as f32;
+                p.fov = pan_orbit.clamp_fov(fov);
+                has_moved = true;
+            }
+        }
+
+        let dt = time.delta_secs_f64();
+        // Gamepad input is a speed rather than a distance, so it's scaled by the frame time
+        if gamepad_orbit.length_squared() > 0.0 {
+            let yaw_direction =
+                match pan_orbit.is_upside_down != util::is_left_handed(pan_orbit.axis) {
+                    true => -1.0,
+                    false => 1.0,
+                };
+            pan_orbit.target_yaw -= gamepad_orbit.x * yaw_direction * input_dt;
+            // Pushing the stick up moves the camera down, so it looks up, like dragging upwards
+            pan_orbit.target_pitch -= gamepad_orbit.y * input_dt;
+            has_moved = true;
+        }
+        if key_orbit.length_squared() > 0.0 {
+            // Right moves the camera to the right around the focus
+            let yaw_direction =
+                match pan_orbit.is_upside_down != util::is_left_handed(pan_orbit.axis) {
+                    true => -1.0,
+                    false => 1.0,
+                };
+            pan_orbit.target_yaw += key_orbit.x * yaw_direction;
+            pan_orbit.target_pitch += key_orbit.y;
+            has_moved = true;
+        }
+        if gamepad_pan.length_squared() > 0.0 {
+            let rotation = transform.rotation.as_dquat();
+            if is_flying {
+                fly_translation += rotation
+                    * DVec3::new(gamepad_pan.x, 0.0, -gamepad_pan.y)
+                    * pan_orbit.fly_speed
+                    * input_dt;
+            } else {
+                let radius = pan_orbit.radius.unwrap_or(pan_orbit.target_radius);
+                let distance = util::pan_distance(radius, &projection, pan_orbit.perspective_fov);
+                let right = rotation * pan_orbit.pan_axis[0] * gamepad_pan.x;
+                let up = rotation * pan_orbit.pan_axis[1] * gamepad_pan.y;
+                pan_orbit.target_focus += (right + up) * distance * input_dt;
+            }
+            has_moved = true;
+        }
+        if gamepad_zoom != 0.0 && !is_flying {
+            pan_orbit.target_radius -= gamepad_zoom * pan_orbit.target_radius * input_dt;
+            has_moved = true;
+        }
+        if key_zoom != 0.0 && !is_flying {
+            pan_orbit.target_radius -= key_zoom * pan_orbit.target_radius;
+            has_moved = true;
+        }
+        // Zoom momentum follows pinches and trackpad zooms, in the same way as drags
+        let zoom_settings = momentum.settings(&momentum.zoom).filter(|_| !is_flying);
+        if zoom_settings.is_none() || dt <= 0.0 {
+            pan_orbit.zoom_velocity = 0.0;
+        } else if scroll_pixel != 0.0 {
+            let zoom_delta = (apply_zoom_limits(pan_orbit.target_radius)
+                / apply_zoom_limits(radius_before_zoom.0))
+            .ln();
+            pan_orbit.zoom_velocity = pan_orbit
+                .zoom_velocity
+                .lerp(zoom_delta / dt, VELOCITY_SMOOTHING);
+        } else if let Some((friction, max_speed)) =
+            zoom_settings.filter(|_| pan_orbit.zoom_velocity != 0.0)
+        {
+            if let Some(max_speed) = max_speed {
+                pan_orbit.zoom_velocity = pan_orbit.zoom_velocity.clamp(-max_speed, max_speed);
+            }
+            let old_target_radius = pan_orbit.target_radius;
+            pan_orbit.target_radius =
+                apply_zoom_limits(old_target_radius * (pan_orbit.zoom_velocity * dt).exp());
+            pan_orbit.zoom_velocity *= (-friction * dt).exp();
+            // Stop at the zoom limits rather than pushing against them
+            if pan_orbit.zoom_velocity.abs() < MIN_ZOOM_VELOCITY
+                || pan_orbit.target_radius == old_target_radius
+            {
+                pan_orbit.zoom_velocity = 0.0;
+            }
+            has_moved = true;
+        }
+        if pan_orbit.projection_switch.is_none() {
+            if let Projection::Perspective(ref mut p) = *projection {
+                let (target_radius, radius) = radius_before_zoom;
+                match pan_orbit.zoom_mode {
+                    ZoomMode::Dolly => {}
+                    ZoomMode::Fov if pan_orbit.target_radius != target_radius => {
+                        // Narrow the field of view as much as moving the camera would have
+                        // shrunk the view at the focus, and put the camera back
+                        let tan =
+                            (p.fov as f64 / 2.0).tan() * pan_orbit.target_radius / target_radius;
+                        p.fov = pan_orbit.clamp_fov((2.0 * tan.atan()) as f32);
+                        pan_orbit.target_radius = target_radius;
+                        pan_orbit.radius = radius;
+                    }
+                    ZoomMode::Fov => {}
+                    ZoomMode::DollyZoom => {
+                        // Stop where the field of view would go past its limits
+                        if let Some(radius) = pan_orbit.radius {
+                            let view_size = radius * (p.fov as f64 / 2.0).tan();
+                            let (fov_lower_limit, fov_upper_limit) = pan_orbit.fov_limits();
+                            let min_radius = view_size / (fov_upper_limit as f64 / 2.0).tan();
+                            let max_radius = view_size / (fov_lower_limit as f64 / 2.0).tan();
+                            pan_orbit.target_radius =
+                                pan_orbit.target_radius.clamp(min_radius, max_radius);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Pan while the cursor is near the edge of the viewport, or with the keyboard. Both are
+        // in multiples of the radius, or the equivalent distance for orthographic cameras.
+        let screen_pan = match is_flying {
+            true => DVec2::ZERO,
+            false => edge_pan + key_pan,
+        };
+        if screen_pan != DVec2::ZERO {
+            let rotation = transform.rotation.as_dquat();
+            let (right, up) = match pan_orbit.pan_mode {
+                PanMode::Plane { normal, .. } => {
+                    // Move across the plane, so the top edge moves forward rather than up
+                    let normal = normal.normalize();
+                    let right = rotation * DVec3::X;
+                    let right = (right - normal * right.dot(normal)).normalize_or_zero();
+                    (right, normal.cross(right))
+                }
+                PanMode::ViewPlane => (
+                    rotation * pan_orbit.pan_axis[0],
+                    rotation * pan_orbit.pan_axis[1],
+                ),
+            };
+            let radius = pan_orbit.radius.unwrap_or(pan_orbit.target_radius);
+            let distance = util::pan_distance(radius, &projection, pan_orbit.perspective_fov);
+            pan_orbit.target_focus += (right * screen_pan.x + up * screen_pan.y) * distance;
             has_moved = true;
         }
 
+        // Play the animation, unless the user has taken over
+        if let Some(mut animation) = pan_orbit.animation {
+            if has_moved {
+                pan_orbit.animation = None;
+            } else {
+                animation.elapsed += time.delta_secs();
+                let pose = animation.sample();
+                pan_orbit.set_target_pose(pose);
+                pan_orbit.focus = pose.focus;
+                // The targets' yaw and pitch match the orientation the pose was given
+                pan_orbit.yaw = Some(pan_orbit.target_yaw);
+                pan_orbit.pitch = Some(pan_orbit.target_pitch);
+                pan_orbit.roll = pose.roll;
+                pan_orbit.orientation = pan_orbit.target_orientation;
+                pan_orbit.radius = Some(pose.radius);
+                pan_orbit.animation = (!animation.is_finished()).then_some(animation);
+                has_moved = true;
+            }
+        }
+
+        // Spin while idle, but not while the camera is being animated elsewhere
+        if let Some(auto_rotate) = pan_orbit.auto_rotate {
+            if time_since_input.0 >= auto_rotate.delay
+                && pan_orbit.animation.is_none()
+                && !is_flying
+            {
+                pan_orbit.target_yaw += auto_rotate.speed * time.delta_secs_f64();
+                has_moved = true;
+            }
+        }
+
         // 3 - Apply constraints
 
-        pan_orbit.target_yaw = apply_yaw_limits(pan_orbit.target_yaw);
-        pan_orbit.target_pitch = apply_pitch_limits(pan_orbit.target_pitch);
+        let trackball = pan_orbit.orbit_mode == OrbitMode::Trackball;
+        if trackball {
+            let axis = pan_orbit.orbit_axis();
+            // Anything other than a trackball drag changes yaw and pitch, so carry those changes
+            // over to the orientation, keeping its roll. On switching to trackball, start from the
+            // turntable's roll.
+            let sync = |orientation: Option<DQuat>, yaw: f64, pitch: f64, roll: f64| {
+                let Some(orientation) = orientation else {
+                    return util::orbit_rotation(yaw, pitch, axis) * DQuat::from_rotation_z(roll);
+                };
+                let (old_yaw, old_pitch) = util::yaw_pitch_from_rotation(orientation, axis);
+                if (old_yaw, old_pitch) == (yaw, pitch) {
+                    return orientation;
+                }
+                let roll = util::orbit_rotation(old_yaw, old_pitch, axis).inverse() * orientation;
+                (util::orbit_rotation(yaw, pitch, axis) * roll).normalize()
+            };
+            let target_orientation = sync(
+                pan_orbit.target_orientation,
+                pan_orbit.target_yaw,
+                pan_orbit.target_pitch,
+                pan_orbit.target_roll,
+            );
+            pan_orbit.set_target_orientation(target_orientation * trackball_rotation);
+            if let (Some(yaw), Some(pitch)) = (pan_orbit.yaw, pan_orbit.pitch) {
+                let orientation = sync(pan_orbit.orientation, yaw, pitch, pan_orbit.roll);
+                let (yaw, pitch) = util::yaw_pitch_from_rotation(orientation, axis);
+                pan_orbit.orientation = Some(orientation);
+                pan_orbit.yaw = Some(yaw);
+                pan_orbit.pitch = Some(pitch);
+            }
+        } else if pan_orbit.orientation.is_some() || pan_orbit.target_orientation.is_some() {
+            // Switched back to turntable, so drop the trackball's roll
+            pan_orbit.orientation = None;
+            pan_orbit.target_orientation = None;
+            pan_orbit.force_update = true;
+        }
+
+        if !trackball {
+            pan_orbit.target_yaw = apply_yaw_limits(pan_orbit.target_yaw);
+            pan_orbit.target_pitch = apply_pitch_limits(pan_orbit.target_pitch);
+        }
         pan_orbit.target_radius = apply_zoom_limits(pan_orbit.target_radius);
         pan_orbit.target_focus = apply_focus_limits(pan_orbit.target_focus);
 
-        if !pan_orbit.allow_upside_down {
+        if let (Some(shape), true, Projection::Perspective(_)) = (
+            pan_orbit.focus_bounds_shape,
+            pan_orbit.bound_camera_position,
+            &*projection,
+        ) {
+            let direction = util::orbit_offset(
+                pan_orbit.target_yaw,
+                pan_orbit.target_pitch,
+                pan_orbit.orbit_axis(),
+            )
+            .normalize();
+            let max_radius = util::max_distance_in_bounds(
+                pan_orbit.target_focus - pan_orbit.focus_bounds_origin,
+                direction,
+                shape,
+            );
+            // The lower zoom limit wins, so the radius can't collapse to zero at the boundary
+            pan_orbit.target_radius = pan_orbit
+                .target_radius
+                .min(max_radius)
+                .max(pan_orbit.zoom_lower_limit);
+        }
+
+        if trackball {
+            // Tumbling is handled by the orientation
+        } else if !pan_orbit.allow_upside_down {
             pan_orbit.target_pitch = pan_orbit.target_pitch.clamp(-PI / 2.0, PI / 2.0);
+        } else if pan_orbit.pitch_upper_limit.is_none() && pan_orbit.pitch_lower_limit.is_none() {
+            // Keep pitch within a single turn when tumbling over the top, shifting the current
+            // value by the same amount so smoothing isn't affected
+            let wrapped = util::nearest_equivalent_angle(0.0, pan_orbit.target_pitch);
+            let shift = wrapped - pan_orbit.target_pitch;
+            if shift != 0.0 {
+                pan_orbit.target_pitch = wrapped;
+                pan_orbit.pitch = pan_orbit.pitch.map(|pitch| pitch + shift);
+            }
         }
 
+        // Narrow or widen the field of view while switching projections, moving the camera to
+        // make up for it. Only borrow the camera mutably while switching, so it isn't marked as
+        // changed every frame.
+        let distance_scale = match pan_orbit.projection_switch {
+            Some(_) => update_projection_switch(
+                &mut pan_orbit,
+                &mut projection,
+                camera.logical_viewport_size(),
+                time.delta_secs(),
+            ),
+            None => 1.0,
+        };
+
         // 4 - Update the camera's transform based on current values
 
         if let (Some(yaw), Some(pitch), Some(radius)) =
@@ -743,56 +4013,424 @@ fn pan_orbit_camera(
                 // of smoothly stopping
                 || pan_orbit.target_yaw != yaw
                 || pan_orbit.target_pitch != pitch
+                || pan_orbit.target_roll != pan_orbit.roll
                 || pan_orbit.target_radius != radius
                 || pan_orbit.target_focus != pan_orbit.focus
+                || pan_orbit.target_orientation != pan_orbit.orientation
                 || pan_orbit.force_update
+                || pan_orbit.applied_world_offset != Some(pan_orbit.world_offset)
+                || pan_orbit
+                    .obstruction_distance
+                    .is_some_and(|distance| distance < radius)
             {
+                let axis = pan_orbit.orbit_axis();
+                let smoothing = pan_orbit.smoothing();
+                let curves = pan_orbit.smoothing_curves;
+                let mut velocity = pan_orbit.spring_velocity;
                 // Interpolate towards the target values
-                let dt = time.delta_secs_f64();
-                let new_yaw = util::lerp_and_snap_f64(
-                    yaw,
-                    pan_orbit.target_yaw,
-                    pan_orbit.orbit_smoothness,
-                    dt,
-                );
-                let new_pitch = util::lerp_and_snap_f64(
-                    pitch,
-                    pan_orbit.target_pitch,
-                    pan_orbit.orbit_smoothness,
+                let new_orientation = match (pan_orbit.orientation, pan_orbit.target_orientation) {
+                    (Some(from), Some(to)) => Some(curves.orbit.step_dquat(
+                        from,
+                        to,
+                        &mut velocity.orientation,
+                        smoothing.orbit,
+                        dt,
+                    )),
+                    _ => None,
+                };
+                let (new_yaw, new_pitch) = match new_orientation {
+                    Some(orientation) => util::yaw_pitch_from_rotation(orientation, axis),
+                    None => (
+                        curves.orbit.step_f64(
+                            yaw,
+                            pan_orbit.target_yaw,
+                            &mut velocity.yaw,
+                            smoothing.orbit,
+                            dt,
+                        ),
+                        curves.orbit.step_f64(
+                            pitch,
+                            pan_orbit.target_pitch,
+                            &mut velocity.pitch,
+                            smoothing.orbit,
+                            dt,
+                        ),
+                    ),
+                };
+                let new_roll = curves.orbit.step_f64(
+                    pan_orbit.roll,
+                    pan_orbit.target_roll,
+                    &mut velocity.roll,
+                    smoothing.orbit,
                     dt,
                 );
-                let new_radius = util::lerp_and_snap_f64(
+                let new_radius = curves.zoom.step_f64(
                     radius,
                     pan_orbit.target_radius,
-                    pan_orbit.zoom_smoothness,
-                    dt,
-                );
-                let new_focus = util::lerp_and_snap_dvec3(
-                    pan_orbit.focus,
-                    pan_orbit.target_focus,
-                    pan_orbit.pan_smoothness,
+                    &mut velocity.radius,
+                    smoothing.zoom,
                     dt,
                 );
+                if pan_orbit.zoom_mode == ZoomMode::DollyZoom
+                    && pan_orbit.projection_switch.is_none()
+                    && new_radius != radius
+                    && new_radius > 0.0
+                {
+                    if let Projection::Perspective(ref mut p) = *projection {
+                        // Keep the view the same size at the focus
+                        let tan = (p.fov as f64 / 2.0).tan() * radius / new_radius;
+                        p.fov = pan_orbit.clamp_fov((2.0 * tan.atan()) as f32);
+                    }
+                }
+                // Keep the camera in front of anything between it and the focus
+                let new_radius = match (pan_orbit.obstruction_distance, &*projection) {
+                    (Some(distance), Projection::Perspective(_)) => new_radius.min(distance),
+                    _ => new_radius,
+                };
+                let new_focus = if is_flying {
+                    // Rotate around the camera's position rather than the focus, by deriving the
+                    // focus from the (moved) camera position and the new rotation
+                    let distance = util::camera_distance(new_radius, &projection);
+                    let eye = pan_orbit.position + fly_translation;
+                    let offset = |yaw, pitch| util::orbit_offset(yaw, pitch, axis) * distance;
+                    pan_orbit.target_focus =
+                        eye - offset(pan_orbit.target_yaw, pan_orbit.target_pitch);
+                    eye - offset(new_yaw, new_pitch)
+                } else {
+                    curves.pan.step_dvec3(
+                        pan_orbit.focus,
+                        pan_orbit.target_focus,
+                        &mut velocity.focus,
+                        smoothing.pan,
+                        dt,
+                    )
+                };
+                pan_orbit.spring_velocity = velocity;
 
-                let axis = pan_orbit.axis;
+                let auto_clip_planes = pan_orbit.auto_clip_planes;
+                let world_offset = pan_orbit.world_offset;
                 util::update_orbit_transform(
                     new_yaw,
                     new_pitch,
-                    new_radius,
+                    new_roll,
+                    new_orientation,
+                    new_radius * distance_scale,
                     new_focus,
                     &mut transform,
                     &mut pan_orbit.position,
                     &mut projection,
                     axis,
+                    auto_clip_planes,
+                    world_offset,
                 );
+                pan_orbit.applied_world_offset = Some(world_offset);
 
                 // Update the current values
                 pan_orbit.yaw = Some(new_yaw);
                 pan_orbit.pitch = Some(new_pitch);
+                pan_orbit.roll = new_roll;
+                pan_orbit.orientation = new_orientation;
                 pan_orbit.radius = Some(new_radius);
                 pan_orbit.focus = new_focus;
                 pan_orbit.force_update = false;
             }
         }
+
+        // Blend from the cutscene's final transform to the orbit transform
+        if let CutsceneState::Resuming {
+            from: Some(from),
+            duration,
+            elapsed,
+        } = pan_orbit.cutscene
+        {
+            let elapsed = elapsed + time.delta_secs();
+            let t = if duration > 0.0 {
+                (elapsed / duration).min(1.0)
+            } else {
+                1.0
+            };
+            let t = util::smoothstep(t);
+            transform.translation = from.translation.lerp(transform.translation, t);
+            transform.rotation = from.rotation.slerp(transform.rotation, t);
+            if t >= 1.0 {
+                pan_orbit.cutscene = CutsceneState::Inactive;
+            } else {
+                pan_orbit.cutscene = CutsceneState::Resuming {
+                    from: Some(from),
+                    duration,
+                    elapsed,
+                };
+                // Keep recalculating the orbit transform so there's something to blend to
+                pan_orbit.force_update = true;
+            }
+        }
+    }
+}
+
+/// Advance `PanOrbitCamera::projection_switch`, narrowing or widening the perspective field of
+/// view and swapping the projection at the orthographic end. Returns how much further away the
+/// camera needs to be than `radius` to keep the focus the same size on screen.
+fn update_projection_switch(
+    pan_orbit: &mut PanOrbitCamera,
+    projection: &mut Projection,
+    viewport_size: Option<Vec2>,
+    delta: f32,
+) -> f64 {
+    let Some(switch) = pan_orbit.projection_switch else {
+        return 1.0;
+    };
+    // Remember the settings of the projection being switched away from
+    let progress = match (switch.progress, &*projection) {
+        (Some(progress), _) => progress,
+        (None, Projection::Orthographic(p)) => {
+            let saved = &mut pan_orbit.saved_projections;
+            saved.orthographic_scaling_mode = p.scaling_mode;
+            saved.orthographic_near = p.near;
+            saved.orthographic_far = p.far;
+            saved.orthographic_viewport_origin = p.viewport_origin;
+            1.0
+        }
+        (None, Projection::Perspective(p)) => {
+            pan_orbit.perspective_fov = p.fov;
+            pan_orbit.saved_projections.perspective_near = p.near;
+            pan_orbit.saved_projections.perspective_far = p.far;
+            0.0
+        }
+    };
+    let step = match pan_orbit.projection_switch_duration.as_secs_f32() {
+        duration if duration > 0.0 => delta / duration,
+        _ => 1.0,
+    };
+    let progress = match switch.to {
+        ProjectionMode::Perspective => (progress - step).max(0.0),
+        ProjectionMode::Orthographic => (progress + step).min(1.0),
+    };
+    // The radius is the distance at which the focus plane would have the same height on screen
+    // with `perspective_fov`, which during the switch is further away the narrower the fov gets
+    let perspective_tan = (pan_orbit.perspective_fov as f64 / 2.0).tan();
+    let narrowest_tan = (PROJECTION_SWITCH_MIN_FOV / 2.0).tan();
+    // Convert the radius, and the zoom limits along with it, between perspective distance and
+    // orthographic scale
+    let scale_radius = |pan_orbit: &mut PanOrbitCamera, scale: f64| {
+        pan_orbit.radius = pan_orbit.radius.map(|radius| radius * scale);
+        pan_orbit.target_radius *= scale;
+        pan_orbit.zoom_lower_limit *= scale;
+        pan_orbit.zoom_upper_limit = pan_orbit.zoom_upper_limit.map(|limit| limit * scale);
+    };
+    let saved = pan_orbit.saved_projections;
+    pan_orbit.force_update = true;
+
+    if let Projection::Orthographic(p) = &*projection {
+        if switch.to == ProjectionMode::Orthographic {
+            pan_orbit.projection_switch = None;
+            return 1.0;
+        }
+        // Start from a perspective that looks just like the orthographic projection
+        let height_per_scale = match p.scale > 0.0 && p.area.height() > 0.0 {
+            true => (p.area.height() / p.scale) as f64,
+            false => 1.0,
+        };
+        let aspect_ratio = match p.area.height() > 0.0 {
+            true => p.area.width() / p.area.height(),
+            false => PerspectiveProjection::default().aspect_ratio,
+        };
+        scale_radius(pan_orbit, height_per_scale / (2.0 * perspective_tan));
+        *projection = Projection::Perspective(PerspectiveProjection {
+            fov: PROJECTION_SWITCH_MIN_FOV as f32,
+            aspect_ratio,
+            near: saved.perspective_near,
+            far: saved.perspective_far,
+        });
+    }
+
+    if progress >= 1.0 {
+        // The perspective is barely noticeable now, so swap to the orthographic projection, with
+        // a scale that shows the same height at the focus
+        let aspect_ratio = match &*projection {
+            Projection::Perspective(p) => p.aspect_ratio,
+            Projection::Orthographic(_) => PerspectiveProjection::default().aspect_ratio,
+        };
+        let mut orthographic = OrthographicProjection {
+            scaling_mode: saved.orthographic_scaling_mode,
+            near: saved.orthographic_near,
+            far: saved.orthographic_far,
+            viewport_origin: saved.orthographic_viewport_origin,
+            ..OrthographicProjection::default_3d()
+        };
+        let size = viewport_size.unwrap_or(Vec2::new(aspect_ratio, 1.0));
+        orthographic.update(size.x.max(1e-6), size.y.max(1e-6));
+        let height_per_scale = match orthographic.area.height() > 0.0 {
+            true => orthographic.area.height() as f64,
+            false => 1.0,
+        };
+        scale_radius(pan_orbit, 2.0 * perspective_tan / height_per_scale);
+        orthographic.scale = pan_orbit.radius.unwrap_or(pan_orbit.target_radius) as f32;
+        orthographic.update(size.x.max(1e-6), size.y.max(1e-6));
+        *projection = Projection::Orthographic(orthographic);
+        pan_orbit.projection_switch = None;
+        return 1.0;
+    }
+
+    let t = util::smoothstep(progress) as f64;
+    let tan = perspective_tan + (narrowest_tan - perspective_tan) * t;
+    let distance_scale = perspective_tan / tan;
+    if let Projection::Perspective(p) = projection {
+        p.fov = (2.0 * tan.atan()) as f32;
+        // The camera moves back as the fov narrows, so the far plane has to move back with it
+        p.far = saved.perspective_far * distance_scale as f32;
+    }
+    pan_orbit.projection_switch = match switch.to == ProjectionMode::Perspective && progress <= 0.0
+    {
+        true => None,
+        false => Some(ProjectionSwitch {
+            progress: Some(progress),
+            ..switch
+        }),
+    };
+    distance_scale
+}
+
+#[cfg(test)]
+mod camera_pose_tests {
+    use super::*;
+
+    #[test]
+    fn target_pose_round_trips_roll_and_orientation() {
+        let mut pan_orbit = PanOrbitCamera {
+            orbit_mode: OrbitMode::Trackball,
+            ..default()
+        };
+        pan_orbit.set_target_orientation(DQuat::from_euler(EulerRot::YXZ, 0.4, -0.3, 0.7));
+        pan_orbit.target_roll = 0.2;
+        let pose = pan_orbit.target_pose();
+
+        let mut restored = PanOrbitCamera {
+            orbit_mode: OrbitMode::Trackball,
+            ..default()
+        };
+        restored.set_target_pose(pose);
+        let restored = restored.target_pose();
+        assert_eq!(restored.roll, pose.roll);
+        assert!((restored.yaw - pose.yaw).abs() < 1e-9);
+        assert!((restored.pitch - pose.pitch).abs() < 1e-9);
+        let angle = restored
+            .orientation
+            .unwrap()
+            .angle_between(pose.orientation.unwrap());
+        assert!(angle < 1e-6);
+    }
+
+    #[test]
+    fn reset_to_home_restores_roll() {
+        let mut pan_orbit = PanOrbitCamera {
+            target_roll: 0.5,
+            ..default()
+        };
+        pan_orbit.save_home_view();
+        pan_orbit.target_roll = 0.0;
+        pan_orbit.reset_to_home(false);
+        assert_eq!(pan_orbit.target_roll, 0.5);
+        assert_eq!(pan_orbit.roll, 0.5);
+    }
+
+    #[test]
+    fn standard_views_are_upright() {
+        let mut pan_orbit = PanOrbitCamera {
+            orbit_mode: OrbitMode::Trackball,
+            target_roll: 0.5,
+            ..default()
+        };
+        pan_orbit.set_target_orientation(DQuat::from_rotation_z(0.5));
+        pan_orbit.set_view(StandardView::Front, false);
+        assert_eq!(pan_orbit.target_roll, 0.0);
+        assert_eq!(pan_orbit.target_orientation, None);
+    }
+
+    #[test]
+    fn lerp_blends_orientation() {
+        let from = CameraPose {
+            orientation: Some(DQuat::IDENTITY),
+            ..default()
+        };
+        let to = CameraPose {
+            roll: 1.0,
+            orientation: Some(DQuat::from_rotation_z(1.0)),
+            ..default()
+        };
+        let halfway = from.lerp(&to, 0.5);
+        assert_eq!(halfway.roll, 0.5);
+        let angle = halfway
+            .orientation
+            .unwrap()
+            .angle_between(DQuat::from_rotation_z(0.5));
+        assert!(angle < 1e-6);
+    }
+}
+
+#[cfg(test)]
+mod interaction_state_tests {
+    use super::*;
+    use bevy::input::mouse::{MouseButtonInput, MouseMotion};
+    use bevy::input::{ButtonState, InputPlugin};
+
+    fn app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            WindowPlugin {
+                primary_window: None,
+                ..default()
+            },
+            PanOrbitCameraPlugin::default(),
+        ));
+        let camera = app
+            .world_mut()
+            .spawn((PanOrbitCamera::default(), Camera3d::default()))
+            .id();
+        app.world_mut()
+            .resource_mut::<ActivePanOrbitCamera>()
+            .entity = Some(camera);
+        app.update();
+        (app, camera)
+    }
+
+    fn press(app: &mut App, state: ButtonState) {
+        app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn orbits_started(app: &App) -> usize {
+        app.world().resource::<Events<OrbitStarted>>().len()
+    }
+
+    #[test]
+    fn click_without_moving_is_not_a_drag() {
+        let (mut app, camera) = app();
+        press(&mut app, ButtonState::Pressed);
+        app.update();
+        let pan_orbit = app.world().get::<PanOrbitCamera>(camera).unwrap();
+        assert_eq!(pan_orbit.state(), InteractionState::Idle);
+        press(&mut app, ButtonState::Released);
+        app.update();
+        assert_eq!(orbits_started(&app), 0);
+        assert_eq!(app.world().resource::<Events<OrbitEnded>>().len(), 0);
+    }
+
+    #[test]
+    fn drag_is_reported_once_it_moves() {
+        let (mut app, camera) = app();
+        press(&mut app, ButtonState::Pressed);
+        app.update();
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(20.0, 0.0),
+        });
+        app.update();
+        let pan_orbit = app.world().get::<PanOrbitCamera>(camera).unwrap();
+        assert_eq!(pan_orbit.state(), InteractionState::Orbiting);
+        assert_eq!(orbits_started(&app), 1);
     }
 }
@@ -0,0 +1,105 @@
+use std::f64::consts::PI;
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::CameraPose;
+
+/// Limits on how fast a programmatic move can go and how hard it can speed up and slow down, for
+/// physically plausible camera moves in e.g. simulation and training applications. Instead of
+/// following an easing curve over a fixed duration, the move takes as long as the limits need,
+/// starting and ending at rest. See `PanOrbitCamera::animate_with_limits` and
+/// `CameraViews::limits`.
+///
+/// Orbiting, panning, and zooming all keep to their own limits, and stay in step so they start
+/// and finish together.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{CameraViews, MotionLimits, MotionShape, PanOrbitCamera};
+/// // Recall saved views like a real camera on a crane, in a scene measured in meters
+/// let camera = PanOrbitCamera {
+///     saved_views: CameraViews {
+///         limits: Some(MotionLimits {
+///             max_speed: 3.0,
+///             max_acceleration: 1.5,
+///             shape: MotionShape::SCurve,
+///             ..Default::default()
+///         }),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotionLimits {
+    /// The fastest the focus moves, and the radius changes, in units per second.
+    /// Defaults to `5.0`.
+    pub max_speed: f64,
+    /// The fastest the focus and radius speed up or slow down, in units per second squared.
+    /// Defaults to `10.0`.
+    pub max_acceleration: f64,
+    /// The fastest the camera orbits, in radians per second.
+    /// Defaults to `PI` (half a turn per second).
+    pub max_angular_speed: f64,
+    /// The fastest the camera's orbiting speeds up or slows down, in radians per second squared.
+    /// Defaults to `2.0 * PI`.
+    pub max_angular_acceleration: f64,
+    /// How the speed changes over the move.
+    /// Defaults to `MotionShape::SCurve`.
+    pub shape: MotionShape,
+}
+
+impl Default for MotionLimits {
+    fn default() -> Self {
+        Self {
+            max_speed: 5.0,
+            max_acceleration: 10.0,
+            max_angular_speed: PI,
+            max_angular_acceleration: 2.0 * PI,
+            shape: MotionShape::SCurve,
+        }
+    }
+}
+
+impl MotionLimits {
+    /// The limits on speed and acceleration for the move from `from` to `to`, as proportions of
+    /// the whole move per second, taking the tightest limit of orbiting, panning, and zooming.
+    /// Infinite if the poses are the same.
+    pub(crate) fn normalized(&self, from: &CameraPose, to: &CameraPose) -> (f64, f64) {
+        let angle = match (from.orientation, to.orientation) {
+            (Some(from), Some(to)) => from.angle_between(to),
+            _ => DVec3::new(
+                to.yaw - from.yaw,
+                to.pitch - from.pitch,
+                to.roll - from.roll,
+            )
+            .length(),
+        };
+        let distance = from
+            .focus
+            .distance(to.focus)
+            .max((to.radius - from.radius).abs());
+        let ratio = |limit: f64, distance: f64| match distance > 0.0 {
+            true => limit / distance,
+            false => f64::INFINITY,
+        };
+        (
+            ratio(self.max_speed, distance).min(ratio(self.max_angular_speed, angle)),
+            ratio(self.max_acceleration, distance).min(ratio(self.max_angular_acceleration, angle)),
+        )
+    }
+}
+
+/// How the speed of a move changes within `MotionLimits`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum MotionShape {
+    /// Speed up at the maximum acceleration, hold the top speed, then slow down at the maximum
+    /// acceleration. The quickest move, but acceleration switches on and off abruptly.
+    Trapezoidal,
+    /// Like `Trapezoidal`, but the acceleration rises and falls smoothly, so the move has no
+    /// sudden jolts. Speeding up and slowing down take twice as long.
+    #[default]
+    SCurve,
+}
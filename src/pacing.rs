@@ -0,0 +1,267 @@
+use bevy::math::DVec2;
+use bevy::prelude::*;
+
+/// How a `PanOrbitCamera` copes with long frames, e.g. a hitch while loading assets, or a slow
+/// WASM build.
+///
+/// Mouse and touch input is a distance moved since the last frame, so applying a long frame's
+/// worth of it at once makes the camera jump. Instead, only a normal frame's worth of it is
+/// applied straight away, and the rest is kept in `PanOrbitCamera::pending_input` and applied
+/// evenly over the following frames, at the speed the user moved. Keyboard and gamepad input is a
+/// speed, so for those, a long frame counts as a normal one.
+///
+/// A frame is only long if it takes more than both `max_frame_time` and twice the recent average
+/// frame time. A steady low frame rate, e.g. in a slow WASM build, moves the camera at full speed
+/// without delay, and only sudden hitches, e.g. while loading assets, are spread out.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{InputPacing, PanOrbitCamera};
+/// // Spread out input from frames slower than 30 FPS, catching up within a quarter of a second
+/// let camera = PanOrbitCamera {
+///     input_pacing: Some(InputPacing {
+///         max_frame_time: 1.0 / 30.0,
+///         max_catch_up: 0.25,
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputPacing {
+    /// The longest frame, in seconds, whose input is always applied in full straight away. Longer
+    /// frames are too, when the frame rate has been that low for a while.
+    /// Defaults to `0.1` (10 frames per second).
+    pub max_frame_time: f32,
+    /// The longest time, in seconds, that input from a long frame is spread over. Input from
+    /// longer frames is applied faster, so the camera never lags further behind than this.
+    /// Defaults to `0.5`.
+    pub max_catch_up: f32,
+}
+
+impl Default for InputPacing {
+    fn default() -> Self {
+        Self {
+            max_frame_time: 0.1,
+            max_catch_up: 0.5,
+        }
+    }
+}
+
+/// How far the average frame time moves towards each new frame's time
+const FRAME_TIME_SMOOTHING: f32 = 0.05;
+
+/// How many times longer than the average frame time a frame has to be to count as a hitch
+const HITCH_FACTOR: f32 = 2.0;
+
+/// The shortest time, in seconds, left to apply pending input over
+const MIN_REMAINING: f32 = 1e-6;
+
+impl InputPacing {
+    /// The longest frame whose input is applied in full straight away, given the recent
+    /// `average_frame_time`
+    pub(crate) fn max_step(&self, average_frame_time: f32) -> f32 {
+        self.max_frame_time.max(average_frame_time * HITCH_FACTOR)
+    }
+
+    /// How much time speed-based input, like keys and gamepad sticks, should move the camera for
+    pub(crate) fn input_dt(&self, dt: f64, average_frame_time: f32) -> f64 {
+        dt.min(self.max_step(average_frame_time) as f64)
+    }
+}
+
+/// The running average frame time, after a frame that took `dt` seconds
+pub(crate) fn average_frame_time(average: f32, dt: f32) -> f32 {
+    match average > 0.0 {
+        true => average + (dt - average) * FRAME_TIME_SMOOTHING,
+        false => dt,
+    }
+}
+
+/// Mouse and touch input that a `PanOrbitCamera` hasn't applied yet, after a long frame. See
+/// `InputPacing`. The values are after sensitivity is applied.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingInput {
+    /// Orbit drag, in pixels.
+    pub orbit: DVec2,
+    /// Pan drag, in pixels.
+    pub pan: DVec2,
+    /// Roll drag, in pixels.
+    pub roll: f64,
+    /// Zoom drag, in pixels.
+    pub zoom_drag: f64,
+    /// Two finger twist, in radians.
+    pub touch_yaw: f64,
+    /// Scrolling, in lines.
+    pub scroll_line: f64,
+    /// Scrolling and pinching, in pixels.
+    pub scroll_pixel: f64,
+    /// Scrolling bound to the field of view, in lines.
+    pub scroll_fov: f64,
+    /// How long is left, in seconds, to apply the rest of the input.
+    pub remaining: f32,
+}
+
+impl PendingInput {
+    /// Take this frame's `input`, which arrived over `dt` seconds, and return how much of it, and
+    /// of the input left over from earlier frames, to apply this frame, given the recent
+    /// `average_frame_time`
+    pub(crate) fn pace(
+        &mut self,
+        input: PendingInput,
+        dt: f32,
+        pacing: InputPacing,
+        average_frame_time: f32,
+    ) -> PendingInput {
+        let step = dt.min(pacing.max_step(average_frame_time));
+        let mut applied = PendingInput::default();
+
+        // Carry on applying what's left over, at an even speed
+        if self.remaining > 0.0 {
+            let remaining = self.remaining - step;
+            // Finish off rather than leave a sliver behind from rounding errors
+            let fraction = match remaining > MIN_REMAINING {
+                true => step / self.remaining,
+                false => 1.0,
+            };
+            applied = self.scaled(fraction as f64);
+            *self = match remaining > MIN_REMAINING {
+                true => PendingInput {
+                    remaining,
+                    ..self.scaled(1.0 - fraction as f64)
+                },
+                false => PendingInput::default(),
+            };
+        }
+
+        // Apply a long frame's input at the speed it arrived, and keep the rest for later
+        let catch_up = dt.min(pacing.max_catch_up) - step;
+        if dt > step && catch_up > 0.0 {
+            let now = (step / dt) as f64;
+            applied = applied.plus(input.scaled(now));
+            *self = self.plus(input.scaled(1.0 - now));
+            self.remaining = self.remaining.max(catch_up);
+        } else {
+            applied = applied.plus(input);
+        }
+        applied
+    }
+
+    fn scaled(&self, factor: f64) -> PendingInput {
+        PendingInput {
+            orbit: self.orbit * factor,
+            pan: self.pan * factor,
+            roll: self.roll * factor,
+            zoom_drag: self.zoom_drag * factor,
+            touch_yaw: self.touch_yaw * factor,
+            scroll_line: self.scroll_line * factor,
+            scroll_pixel: self.scroll_pixel * factor,
+            scroll_fov: self.scroll_fov * factor,
+            remaining: self.remaining,
+        }
+    }
+
+    fn plus(&self, other: PendingInput) -> PendingInput {
+        PendingInput {
+            orbit: self.orbit + other.orbit,
+            pan: self.pan + other.pan,
+            roll: self.roll + other.roll,
+            zoom_drag: self.zoom_drag + other.zoom_drag,
+            touch_yaw: self.touch_yaw + other.touch_yaw,
+            scroll_line: self.scroll_line + other.scroll_line,
+            scroll_pixel: self.scroll_pixel + other.scroll_pixel,
+            scroll_fov: self.scroll_fov + other.scroll_fov,
+            remaining: self.remaining,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: f32 = 1.0 / 60.0;
+
+    fn orbit(x: f64) -> PendingInput {
+        PendingInput {
+            orbit: DVec2::new(x, 0.0),
+            ..Default::default()
+        }
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn applies_short_frames_in_full() {
+        let mut pending = PendingInput::default();
+        let applied = pending.pace(orbit(10.0), FRAME, InputPacing::default(), FRAME);
+        assert_eq!(applied.orbit.x, 10.0);
+        assert_eq!(pending, PendingInput::default());
+    }
+
+    #[test]
+    fn splits_long_frame() {
+        let mut pending = PendingInput::default();
+        let applied = pending.pace(orbit(90.0), 0.3, InputPacing::default(), FRAME);
+        // A normal frame's worth now, at the speed the user moved
+        assert_close(applied.orbit.x, 30.0);
+        assert_close(pending.orbit.x, 60.0);
+        assert_close(pending.remaining as f64, 0.2);
+    }
+
+    #[test]
+    fn drains_pending_input_over_following_frames() {
+        let pacing = InputPacing::default();
+        let mut pending = PendingInput::default();
+        let mut total = pending.pace(orbit(90.0), 0.3, pacing, FRAME).orbit.x;
+        for _ in 0..2 {
+            let applied = pending.pace(PendingInput::default(), 0.1, pacing, FRAME);
+            assert_close(applied.orbit.x, 30.0);
+            total += applied.orbit.x;
+        }
+        assert_close(total, 90.0);
+        assert_eq!(pending, PendingInput::default());
+    }
+
+    #[test]
+    fn catches_up_within_max_catch_up() {
+        let pacing = InputPacing::default();
+        let mut pending = PendingInput::default();
+        let mut total = pending.pace(orbit(100.0), 2.0, pacing, FRAME).orbit.x;
+        assert_close(total, 5.0);
+        assert_close(pending.remaining as f64, 0.4);
+        for _ in 0..4 {
+            total += pending
+                .pace(PendingInput::default(), 0.1, pacing, FRAME)
+                .orbit
+                .x;
+        }
+        assert_close(total, 100.0);
+        assert_eq!(pending, PendingInput::default());
+    }
+
+    #[test]
+    fn does_not_slow_down_steady_low_frame_rate() {
+        let pacing = InputPacing::default();
+        let mut pending = PendingInput::default();
+        let applied = pending.pace(orbit(10.0), 0.2, pacing, 0.2);
+        assert_eq!(applied.orbit.x, 10.0);
+        assert_eq!(pending, PendingInput::default());
+        assert_eq!(pacing.input_dt(0.2, 0.2), 0.2);
+        assert_close(pacing.input_dt(1.0, 0.2), 0.4);
+    }
+
+    #[test]
+    fn paces_fov_scrolling() {
+        let mut pending = PendingInput::default();
+        let input = PendingInput {
+            scroll_fov: 9.0,
+            ..Default::default()
+        };
+        let applied = pending.pace(input, 0.3, InputPacing::default(), FRAME);
+        assert_close(applied.scroll_fov, 3.0);
+        assert_close(pending.scroll_fov, 6.0);
+    }
+}
@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use bevy::math::{DQuat, DVec3};
+use bevy::picking::focus::HoverMap;
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, RayCastSettings, RayCastVisibility};
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+
+use crate::input::MouseKeyTracker;
+use crate::traits::OptionalClamp;
+use crate::{
+    util, ActiveCameraData, CameraGesture, IgnoreCameraCollision, PanOrbitCamera, ResetGesture,
+};
+
+/// Double-clicking a mesh makes a `PanOrbitCamera` orbit around the point that was clicked. The
+/// camera turns to look at the point without moving, so there's no visible jump, and it can also
+/// move to a set distance from the point with `reframe_radius`.
+///
+/// Add this alongside `PanOrbitCamera`. Requires the `bevy_picking` feature. When the
+/// camera's `reset_gesture` is a double-click of the same button, double-clicking a mesh picks
+/// the pivot, and double-clicking empty space still resets the camera.
+///
+/// Orthographic cameras pan to the clicked point instead, keeping their rotation and scale. Either
+/// way, the focus moves, so picking is ignored while the camera's `pan_enabled` is `false`.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitPickPivot {
+    /// The mouse button to double-click.
+    /// Defaults to `MouseButton::Left`.
+    pub button: MouseButton,
+    /// If set, the camera also moves to this distance from the clicked point. Otherwise it stays
+    /// where it is.
+    /// Defaults to `None`.
+    pub reframe_radius: Option<f64>,
+    /// How long the camera takes to turn to the new pivot, in seconds.
+    /// Defaults to `0.3`.
+    pub duration: f32,
+}
+
+impl Default for PanOrbitPickPivot {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            reframe_radius: None,
+            duration: 0.3,
+        }
+    }
+}
+
+/// Keeps a `PanOrbitCamera`'s focus from sinking below the ground, which makes orbiting feel wrong
+/// in terrain scenes. This can happen after panning along the view plane or zooming to the
+/// cursor. Once the user stops panning or zooming, a ray is cast down onto the focus, and if it
+/// hits a surface above the focus, the focus is raised onto it. Down is the opposite of the
+/// camera's `axis[1]`.
+///
+/// Add this alongside `PanOrbitCamera`. Requires the `bevy_picking` feature. Meshes with
+/// `IgnoreCameraCollision` are ignored.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PanOrbitPivotRaise {
+    /// How far above the surface to keep the focus.
+    /// Defaults to `0.0`.
+    pub clearance: f64,
+    /// How far below a surface the focus can be and still be raised onto it, as a multiple of the
+    /// camera's distance from the focus. Surfaces further above the focus are taken to be a ceiling rather than
+    /// the ground.
+    /// Defaults to `1.0`.
+    pub max_depth: f64,
+    /// The focus that was last checked, so that the ray is only cast when the focus moves.
+    last_focus: Option<DVec3>,
+}
+
+impl Default for PanOrbitPivotRaise {
+    fn default() -> Self {
+        Self {
+            clearance: 0.0,
+            max_depth: 1.0,
+            last_focus: None,
+        }
+    }
+}
+
+/// Raise the focus of each camera with `PanOrbitPivotRaise` onto the surface above it, if it has
+/// sunk below one
+pub fn raise_pivot(
+    mut cameras: Query<(&mut PanOrbitCamera, &mut PanOrbitPivotRaise, &Projection)>,
+    ignored: Query<(), With<IgnoreCameraCollision>>,
+    mut ray_cast: MeshRayCast,
+) {
+    for (mut pan_orbit, mut raise, projection) in cameras.iter_mut() {
+        // Wait until the user has finished, and don't fight animations
+        let moving = matches!(pan_orbit.gesture, CameraGesture::Pan | CameraGesture::Zoom);
+        if moving || pan_orbit.animation.is_some() || !pan_orbit.initialized {
+            continue;
+        }
+        let focus = pan_orbit.target_focus;
+        if raise.last_focus == Some(focus) {
+            continue;
+        }
+        raise.last_focus = Some(focus);
+        let up = pan_orbit.axis[1].normalize();
+        let Ok(down) = Dir3::new(-up.as_vec3()) else {
+            continue;
+        };
+        // Start above the deepest the focus could be, and cast down past it. Orthographic cameras
+        // use the radius as their scale, so go by their actual distance instead
+        let distance = util::camera_distance(pan_orbit.target_radius, projection);
+        let height = raise.max_depth.max(0.0) * distance;
+        let origin = pan_orbit.to_transform_space(focus + up * height);
+        let ray = Ray3d::new(origin.as_vec3(), down);
+        let filter = |entity| !ignored.contains(entity);
+        let settings = RayCastSettings::default()
+            .with_visibility(RayCastVisibility::Visible)
+            .with_filter(&filter);
+        let raise_by = ray_cast
+            .cast_ray(ray, &settings)
+            .first()
+            .map(|(_, hit)| height - hit.distance as f64 + raise.clearance)
+            .filter(|raise_by| *raise_by > 0.0);
+        if let Some(raise_by) = raise_by {
+            pan_orbit.target_focus += up * raise_by;
+            raise.last_focus = Some(pan_orbit.target_focus);
+        }
+    }
+}
+
+/// When the active camera's pick button is double-clicked, cast a ray under the cursor and
+/// animate the camera to orbit around the nearest hit
+pub fn pick_pivot(
+    mut mouse_key_tracker: ResMut<MouseKeyTracker>,
+    active_cam: Res<ActiveCameraData>,
+    mut cameras: Query<(
+        &mut PanOrbitCamera,
+        &PanOrbitPickPivot,
+        &Camera,
+        &Transform,
+        &Projection,
+    )>,
+    mut ray_cast: MeshRayCast,
+) {
+    let Some(entity) = active_cam.entity else {
+        return;
+    };
+    let Ok((mut pan_orbit, pick_pivot, camera, transform, projection)) = cameras.get_mut(entity)
+    else {
+        return;
+    };
+    if !pan_orbit.accepts_input()
+        || !pan_orbit.pan_enabled
+        || mouse_key_tracker.double_click != Some(pick_pivot.button)
+    {
+        return;
+    }
+    let Some(ray) = mouse_key_tracker.cursor_position.and_then(|cursor| {
+        // The global transform isn't propagated until after the camera has been updated
+        camera
+            .viewport_to_world(&GlobalTransform::from(*transform), cursor)
+            .ok()
+    }) else {
+        return;
+    };
+    let settings = RayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+    let Some(point) = ray_cast
+        .cast_ray(ray, &settings)
+        .first()
+        .map(|(_, hit)| pan_orbit.from_transform_space(hit.point))
+    else {
+        return;
+    };
+
+    let mut pose = pan_orbit.target_pose();
+    pose.focus = point;
+    if let Projection::Perspective(_) = projection {
+        // Look at the point from where the camera is now
+        let (yaw, pitch, radius) = util::calculate_from_translation_and_focus(
+            pan_orbit.position,
+            point,
+            pan_orbit.orbit_axis(),
+        );
+        // Describe the same direction the way the camera does when it's upside down, so it
+        // doesn't flip over
+        (pose.yaw, pose.pitch) = if pan_orbit.target_pitch.cos() < 0.0 {
+            (yaw + PI, PI.copysign(pitch) - pitch)
+        } else {
+            (yaw, pitch)
+        };
+        // Turn a trackball camera the least amount to look at the point, keeping its roll
+        pose.orientation = pose.orientation.map(|orientation| {
+            let forward = -util::orbit_offset(yaw, pitch, pan_orbit.orbit_axis());
+            DQuat::from_rotation_arc(orientation * DVec3::NEG_Z, forward.normalize()) * orientation
+        });
+        pose.radius = pick_pivot
+            .reframe_radius
+            .unwrap_or(radius)
+            .clamp_optional(Some(pan_orbit.zoom_lower_limit), pan_orbit.zoom_upper_limit);
+    }
+    pan_orbit.animate_to(
+        pose,
+        Duration::from_secs_f32(pick_pivot.duration.max(0.0)),
+        EaseFunction::CubicOut,
+    );
+    // The double-click was used up by picking, so it shouldn't also reset the camera
+    mouse_key_tracker.reset_requested = false;
+}
+
+/// Cancel the active camera's double-click `reset_gesture` if the click landed on something, so
+/// that only double-clicking empty space resets the camera. Visible meshes under the cursor count,
+/// as does anything the app's picking backends report under the mouse, e.g. UI or sprites.
+pub fn ignore_reset_on_hits(
+    mut mouse_key_tracker: ResMut<MouseKeyTracker>,
+    active_cam: Res<ActiveCameraData>,
+    cameras: Query<(&PanOrbitCamera, &Camera, &Transform)>,
+    hover_map: Option<Res<HoverMap>>,
+    mut ray_cast: MeshRayCast,
+) {
+    if !mouse_key_tracker.reset_requested {
+        return;
+    }
+    let Some(entity) = active_cam.entity else {
+        return;
+    };
+    let Ok((pan_orbit, camera, transform)) = cameras.get(entity) else {
+        return;
+    };
+    if !matches!(pan_orbit.reset_gesture, Some(ResetGesture::DoubleClick(_))) {
+        return;
+    }
+    let hovered = hover_map.is_some_and(|hover_map| {
+        hover_map
+            .get(&PointerId::Mouse)
+            .is_some_and(|hits| !hits.is_empty())
+    });
+    let hit_mesh = mouse_key_tracker
+        .cursor_position
+        .and_then(|cursor| {
+            // The global transform isn't propagated until after the camera has been updated
+            camera
+                .viewport_to_world(&GlobalTransform::from(*transform), cursor)
+                .ok()
+        })
+        .is_some_and(|ray| {
+            let settings = RayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+            !ray_cast.cast_ray(ray, &settings).is_empty()
+        });
+    if hovered || hit_mesh {
+        mouse_key_tracker.reset_requested = false;
+    }
+}
@@ -0,0 +1,253 @@
+use bevy::math::{DQuat, DVec2, DVec3};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::pacing::PendingInput;
+use crate::PanOrbitCamera;
+
+/// Sent when a `PanOrbitCamera` is found with a NaN or infinite yaw, pitch, radius, focus, or
+/// similar, e.g. from the app writing a bad value, or a direction calculated from a zero-length
+/// vector. Rather than the camera disappearing for good, it's put back to the last state it had
+/// that was valid, or to the defaults if it never had one, and a warning is logged.
+///
+/// Use this to surface the problem, e.g. in a debug overlay. Cameras are checked every frame in
+/// `PanOrbitCameraSystemSet`, both before they're updated and afterwards, so that a `Transform`
+/// calculated from a value that can't be checked directly, e.g. a zero-length `axis`, is never
+/// rendered. A value that stays invalid, e.g. one the app keeps writing, is only reported once,
+/// until the camera has been valid for a whole frame.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct CameraRecovered {
+    /// The camera entity.
+    pub entity: Entity,
+    /// The names of the fields that were invalid, e.g. `target_yaw`, or `transform` when the
+    /// `Transform` calculated from the camera's state was.
+    pub fields: Vec<&'static str>,
+}
+
+/// The last valid state of each camera, and which cameras are being recovered
+#[derive(Resource, Default)]
+pub struct RecoveryTracker {
+    valid_states: HashMap<Entity, ValidState>,
+    /// Cameras that were invalid, and haven't been valid both before and after an update since
+    recovering: HashSet<Entity>,
+    /// Cameras that were valid before this frame's update
+    valid_before_update: HashSet<Entity>,
+}
+
+impl RecoveryTracker {
+    /// Put `pan_orbit` back to its last valid state, and report `fields` unless it's already being
+    /// recovered from an earlier problem
+    fn recover(
+        &mut self,
+        entity: Entity,
+        pan_orbit: &mut PanOrbitCamera,
+        transform: &mut Transform,
+        fields: Vec<&'static str>,
+        recovered: &mut EventWriter<CameraRecovered>,
+    ) {
+        if self.recovering.insert(entity) {
+            warn!(
+                "PanOrbitCamera on {entity} has an invalid {}, restoring its last valid state",
+                fields.join(", ")
+            );
+            recovered.send(CameraRecovered { entity, fields });
+        }
+        let last_valid = self.valid_states.get(&entity).copied().unwrap_or_default();
+        last_valid.restore(pan_orbit, transform);
+        // Whatever was moving the camera may keep producing the same values
+        pan_orbit.animation = None;
+        pan_orbit.pending_input = PendingInput::default();
+        pan_orbit.force_update = true;
+    }
+}
+
+/// The parts of a camera's state that can end up invalid, and the `Transform` calculated from
+/// them, from the last frame they were all valid
+#[derive(Copy, Clone)]
+pub struct ValidState {
+    focus: DVec3,
+    yaw: Option<f64>,
+    pitch: Option<f64>,
+    radius: Option<f64>,
+    roll: f64,
+    orientation: Option<DQuat>,
+    target_focus: DVec3,
+    target_yaw: f64,
+    target_pitch: f64,
+    target_radius: f64,
+    target_roll: f64,
+    target_orientation: Option<DQuat>,
+    orbit_velocity: DVec2,
+    pan_velocity: DVec3,
+    zoom_velocity: f64,
+    axis: [DVec3; 3],
+    pan_axis: [DVec3; 3],
+    site_rotation: f64,
+    world_offset: DVec3,
+    transform: Transform,
+}
+
+impl Default for ValidState {
+    /// The default camera's targets, for cameras that have never been valid
+    fn default() -> Self {
+        let pan_orbit = PanOrbitCamera::default();
+        Self {
+            yaw: Some(pan_orbit.target_yaw),
+            pitch: Some(pan_orbit.target_pitch),
+            radius: Some(pan_orbit.target_radius),
+            ..Self::capture(&pan_orbit, &Transform::IDENTITY)
+        }
+    }
+}
+
+impl ValidState {
+    fn capture(pan_orbit: &PanOrbitCamera, transform: &Transform) -> Self {
+        Self {
+            focus: pan_orbit.focus,
+            yaw: pan_orbit.yaw,
+            pitch: pan_orbit.pitch,
+            radius: pan_orbit.radius,
+            roll: pan_orbit.roll,
+            orientation: pan_orbit.orientation,
+            target_focus: pan_orbit.target_focus,
+            target_yaw: pan_orbit.target_yaw,
+            target_pitch: pan_orbit.target_pitch,
+            target_radius: pan_orbit.target_radius,
+            target_roll: pan_orbit.target_roll,
+            target_orientation: pan_orbit.target_orientation,
+            orbit_velocity: pan_orbit.orbit_velocity,
+            pan_velocity: pan_orbit.pan_velocity,
+            zoom_velocity: pan_orbit.zoom_velocity,
+            axis: pan_orbit.axis,
+            pan_axis: pan_orbit.pan_axis,
+            site_rotation: pan_orbit.site_rotation,
+            world_offset: pan_orbit.world_offset,
+            transform: *transform,
+        }
+    }
+
+    fn restore(&self, pan_orbit: &mut PanOrbitCamera, transform: &mut Transform) {
+        pan_orbit.focus = self.focus;
+        pan_orbit.yaw = self.yaw;
+        pan_orbit.pitch = self.pitch;
+        pan_orbit.radius = self.radius;
+        pan_orbit.roll = self.roll;
+        pan_orbit.orientation = self.orientation;
+        pan_orbit.target_focus = self.target_focus;
+        pan_orbit.target_yaw = self.target_yaw;
+        pan_orbit.target_pitch = self.target_pitch;
+        pan_orbit.target_radius = self.target_radius;
+        pan_orbit.target_roll = self.target_roll;
+        pan_orbit.target_orientation = self.target_orientation;
+        pan_orbit.orbit_velocity = self.orbit_velocity;
+        pan_orbit.pan_velocity = self.pan_velocity;
+        pan_orbit.zoom_velocity = self.zoom_velocity;
+        pan_orbit.axis = self.axis;
+        pan_orbit.pan_axis = self.pan_axis;
+        pan_orbit.site_rotation = self.site_rotation;
+        pan_orbit.world_offset = self.world_offset;
+        *transform = self.transform;
+    }
+
+    /// The names of the fields that are NaN or infinite, not counting the `Transform`
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        let finite = |value: Option<f64>| value.is_none_or(f64::is_finite);
+        let finite_quat = |value: Option<DQuat>| value.is_none_or(DQuat::is_finite);
+        [
+            ("focus", self.focus.is_finite()),
+            ("yaw", finite(self.yaw)),
+            ("pitch", finite(self.pitch)),
+            ("radius", finite(self.radius)),
+            ("roll", self.roll.is_finite()),
+            ("orientation", finite_quat(self.orientation)),
+            ("target_focus", self.target_focus.is_finite()),
+            ("target_yaw", self.target_yaw.is_finite()),
+            ("target_pitch", self.target_pitch.is_finite()),
+            ("target_radius", self.target_radius.is_finite()),
+            ("target_roll", self.target_roll.is_finite()),
+            ("target_orientation", finite_quat(self.target_orientation)),
+            ("orbit_velocity", self.orbit_velocity.is_finite()),
+            ("pan_velocity", self.pan_velocity.is_finite()),
+            ("zoom_velocity", self.zoom_velocity.is_finite()),
+            ("axis", self.axis.iter().all(|axis| axis.is_finite())),
+            (
+                "pan_axis",
+                self.pan_axis.iter().all(|axis| axis.is_finite()),
+            ),
+            ("site_rotation", self.site_rotation.is_finite()),
+            ("world_offset", self.world_offset.is_finite()),
+        ]
+        .into_iter()
+        .filter(|(_, valid)| !valid)
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+/// Put cameras with NaN or infinite state back to their last valid state before they're updated
+pub fn recover_invalid_cameras(
+    mut cameras: Query<(Entity, &mut PanOrbitCamera, &mut Transform)>,
+    mut tracker: ResMut<RecoveryTracker>,
+    mut recovered: EventWriter<CameraRecovered>,
+) {
+    let tracker = &mut *tracker;
+    tracker
+        .valid_states
+        .retain(|entity, _| cameras.contains(*entity));
+    tracker
+        .recovering
+        .retain(|entity| cameras.contains(*entity));
+    tracker.valid_before_update.clear();
+    for (entity, mut pan_orbit, mut transform) in cameras.iter_mut() {
+        let fields = ValidState::capture(&pan_orbit, &transform).invalid_fields();
+        if !fields.is_empty() {
+            tracker.recover(
+                entity,
+                &mut pan_orbit,
+                &mut transform,
+                fields,
+                &mut recovered,
+            );
+            continue;
+        }
+        tracker.valid_before_update.insert(entity);
+        if !transform.is_finite() && !pan_orbit.force_update {
+            // The state is fine, but the transform was set to something that wasn't, so
+            // calculate it again
+            pan_orbit.force_update = true;
+        }
+    }
+}
+
+/// Put cameras whose `Transform` was updated to NaN or infinite values back to their last valid
+/// state, so that they're never rendered that way, and remember the state of the ones that were
+/// valid both before and after the update
+pub fn recover_invalid_transforms(
+    mut cameras: Query<(Entity, &mut PanOrbitCamera, &mut Transform)>,
+    mut tracker: ResMut<RecoveryTracker>,
+    mut recovered: EventWriter<CameraRecovered>,
+) {
+    for (entity, mut pan_orbit, mut transform) in cameras.iter_mut() {
+        if !pan_orbit.initialized {
+            continue;
+        }
+        if transform.is_finite() {
+            if tracker.valid_before_update.contains(&entity) {
+                tracker.recovering.remove(&entity);
+                tracker
+                    .valid_states
+                    .insert(entity, ValidState::capture(&pan_orbit, &transform));
+            }
+            continue;
+        }
+        let mut fields = ValidState::capture(&pan_orbit, &transform).invalid_fields();
+        fields.push("transform");
+        tracker.recover(
+            entity,
+            &mut pan_orbit,
+            &mut transform,
+            fields,
+            &mut recovered,
+        );
+    }
+}
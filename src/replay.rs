@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use bevy::input::gestures::PinchGesture;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::clock::CameraTime;
+
+/// A synthetic input event, which drives `PanOrbitCamera`s exactly as the equivalent user input
+/// would, e.g. for end-to-end tests or tutorials that show how to navigate.
+///
+/// Send these as events, or play them back over time with `InputReplay`. They're turned into
+/// Bevy's own input events before Bevy processes input each frame, so `ButtonInput`, `Touches`,
+/// and everything else that reads input sees them too. Button and key presses are held until the
+/// matching release is sent, so spread gestures over several frames: a button that's pressed and
+/// released in the same frame never counts as held. Positions are in logical pixels, relative to
+/// the window's top left.
+///
+/// Events are sent to the primary window, unless there isn't one.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{InjectedInput, InputReplay};
+/// // Orbit by dragging 100 pixels to the right
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(InputReplay::new([
+///         (0.0, InjectedInput::CursorMoved(Vec2::new(400.0, 300.0))),
+///         (0.1, InjectedInput::MousePressed(MouseButton::Left)),
+///         (0.2, InjectedInput::MouseMoved(Vec2::new(100.0, 0.0))),
+///         (0.3, InjectedInput::MouseReleased(MouseButton::Left)),
+///     ]));
+/// }
+/// ```
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq)]
+pub enum InjectedInput {
+    /// Press a mouse button.
+    MousePressed(MouseButton),
+    /// Release a mouse button.
+    MouseReleased(MouseButton),
+    /// Move the mouse by the given amount, in physical pixels, as reported by `MouseMotion`. This
+    /// doesn't move the cursor, see `CursorMoved`.
+    MouseMoved(Vec2),
+    /// Move the cursor to the given position in the window. With a real window, this moves the
+    /// system cursor too.
+    CursorMoved(Vec2),
+    /// Scroll the mouse wheel or trackpad.
+    Scrolled {
+        /// Whether the amounts are in lines or pixels.
+        unit: MouseScrollUnit,
+        /// Horizontal scroll amount.
+        x: f32,
+        /// Vertical scroll amount.
+        y: f32,
+    },
+    /// A trackpad pinch, as reported by `PinchGesture`.
+    Pinched(f32),
+    /// Press a key.
+    KeyPressed(KeyCode),
+    /// Release a key.
+    KeyReleased(KeyCode),
+    /// A touch starting, moving, ending, or being cancelled.
+    Touch {
+        /// Identifies the finger across the events of one touch.
+        id: u64,
+        /// Whether the touch started, moved, ended, or was cancelled.
+        phase: TouchPhase,
+        /// Where the finger is.
+        position: Vec2,
+    },
+}
+
+/// A timed sequence of `InjectedInput` events, played back in real time. Insert this resource to
+/// start playing, e.g. to demonstrate navigation in a tutorial. It's removed once the last event
+/// has been sent.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct InputReplay {
+    /// The events still to send, with the time to send each one at, in seconds since the replay
+    /// started. Must be in order of time.
+    pub events: VecDeque<(f32, InjectedInput)>,
+    /// How long the replay has been playing, in seconds.
+    pub elapsed: f32,
+}
+
+impl InputReplay {
+    /// Create a replay of `events`, each with the time to send it at, in seconds.
+    pub fn new(events: impl IntoIterator<Item = (f32, InjectedInput)>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Send the events of the `InputReplay` that are due
+pub fn play_input_replay(
+    mut commands: Commands,
+    replay: Option<ResMut<InputReplay>>,
+    mut injected: EventWriter<InjectedInput>,
+    time: CameraTime,
+) {
+    let Some(mut replay) = replay else {
+        return;
+    };
+    replay.elapsed += time.delta_secs();
+    while let Some(&(at, event)) = replay.events.front() {
+        if at > replay.elapsed {
+            break;
+        }
+        injected.send(event);
+        replay.events.pop_front();
+    }
+    if replay.events.is_empty() {
+        commands.remove_resource::<InputReplay>();
+    }
+}
+
+/// Turn `InjectedInput` events into Bevy input events, before Bevy processes them
+#[allow(clippy::too_many_arguments)]
+pub fn inject_input(
+    mut injected: EventReader<InjectedInput>,
+    mut windows: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    mut mouse_buttons: EventWriter<MouseButtonInput>,
+    mut mouse_motion: EventWriter<MouseMotion>,
+    mut mouse_wheel: EventWriter<MouseWheel>,
+    mut pinches: EventWriter<PinchGesture>,
+    mut keys: EventWriter<KeyboardInput>,
+    mut touches: EventWriter<TouchInput>,
+) {
+    let mut window = windows.get_single_mut().ok();
+    let window_entity = window
+        .as_ref()
+        .map_or(Entity::PLACEHOLDER, |(entity, _)| *entity);
+    let button_state = |pressed: bool| match pressed {
+        true => ButtonState::Pressed,
+        false => ButtonState::Released,
+    };
+    for event in injected.read() {
+        match *event {
+            InjectedInput::MousePressed(button) | InjectedInput::MouseReleased(button) => {
+                let pressed = matches!(event, InjectedInput::MousePressed(_));
+                mouse_buttons.send(MouseButtonInput {
+                    button,
+                    state: button_state(pressed),
+                    window: window_entity,
+                });
+            }
+            InjectedInput::MouseMoved(delta) => {
+                mouse_motion.send(MouseMotion { delta });
+            }
+            InjectedInput::CursorMoved(position) => {
+                if let Some((_, window)) = window.as_mut() {
+                    window.set_cursor_position(Some(position));
+                }
+            }
+            InjectedInput::Scrolled { unit, x, y } => {
+                mouse_wheel.send(MouseWheel {
+                    unit,
+                    x,
+                    y,
+                    window: window_entity,
+                });
+            }
+            InjectedInput::Pinched(amount) => {
+                pinches.send(PinchGesture(amount));
+            }
+            InjectedInput::KeyPressed(key_code) | InjectedInput::KeyReleased(key_code) => {
+                let pressed = matches!(event, InjectedInput::KeyPressed(_));
+                keys.send(KeyboardInput {
+                    key_code,
+                    logical_key: Key::Unidentified(NativeKey::Unidentified),
+                    state: button_state(pressed),
+                    repeat: false,
+                    window: window_entity,
+                });
+            }
+            InjectedInput::Touch {
+                id,
+                phase,
+                position,
+            } => {
+                touches.send(TouchInput {
+                    phase,
+                    position,
+                    window: window_entity,
+                    force: None,
+                    id,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::time::Duration;
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::clock::CameraTime;
+use crate::PanOrbitCamera;
+
+/// A list of navigation steps that a `PanOrbitCamera` performs one after another, e.g. for demo
+/// scripts or automated capture runs. Add this alongside `PanOrbitCamera`, and it is removed
+/// once the last step has finished (unless it loops).
+///
+/// Steps animate the camera from wherever the previous step left it, so the user can still take
+/// over at any time, in which case the current step's movement stops but the script carries on
+/// with the next step once its time is up.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::math::DVec3;
+/// # use bevy_panorbit_camera::{NavigationScript, NavigationStep, PanOrbitCamera};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         PanOrbitCamera::default(),
+///         NavigationScript::new(vec![
+///             NavigationStep::OrbitBy { yaw: 1.0, pitch: 0.0, duration: 2.0 },
+///             NavigationStep::Wait { duration: 1.0 },
+///             NavigationStep::PanTo { focus: DVec3::new(1.0, 0.0, 0.0), duration: 1.0 },
+///             NavigationStep::ZoomTo { radius: 3.0, duration: 1.0 },
+///         ]),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct NavigationScript {
+    /// The steps to perform, in order.
+    pub steps: Vec<NavigationStep>,
+    /// Whether to start again from the first step after the last one.
+    /// Defaults to `false`.
+    pub looping: bool,
+    /// The easing curve used by steps that move the camera.
+    /// Defaults to `EaseFunction::CubicInOut`.
+    pub ease: EaseFunction,
+    /// Index of the step in progress.
+    current: usize,
+    /// How long the current step has been running, in seconds. `None` if it hasn't started.
+    elapsed: Option<f32>,
+}
+
+impl NavigationScript {
+    /// Create a script that performs `steps` once.
+    pub fn new(steps: Vec<NavigationStep>) -> Self {
+        Self {
+            steps,
+            looping: false,
+            ease: EaseFunction::CubicInOut,
+            current: 0,
+            elapsed: None,
+        }
+    }
+
+    /// Set whether the script starts again after the last step.
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Index of the step in progress.
+    pub fn current_step(&self) -> usize {
+        self.current
+    }
+}
+
+/// A single step of a `NavigationScript`. Durations are in seconds.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavigationStep {
+    /// Orbit by the given yaw and pitch, in radians.
+    OrbitBy {
+        /// Change in yaw.
+        yaw: f64,
+        /// Change in pitch.
+        pitch: f64,
+        /// How long the orbit takes.
+        duration: f32,
+    },
+    /// Move the focus to the given point.
+    PanTo {
+        /// The new focus.
+        focus: DVec3,
+        /// How long the pan takes.
+        duration: f32,
+    },
+    /// Zoom to the given radius (or scale, for orthographic projections).
+    ZoomTo {
+        /// The new radius.
+        radius: f64,
+        /// How long the zoom takes.
+        duration: f32,
+    },
+    /// Do nothing for a while.
+    Wait {
+        /// How long to wait.
+        duration: f32,
+    },
+    /// Switch to a perspective or orthographic projection. Takes effect immediately.
+    SetProjection(ScriptProjection),
+}
+
+impl NavigationStep {
+    /// How long this step takes, in seconds.
+    pub fn duration(&self) -> f32 {
+        match *self {
+            NavigationStep::OrbitBy { duration, .. }
+            | NavigationStep::PanTo { duration, .. }
+            | NavigationStep::ZoomTo { duration, .. }
+            | NavigationStep::Wait { duration } => duration,
+            NavigationStep::SetProjection(_) => 0.0,
+        }
+    }
+}
+
+/// The projection to switch to with `NavigationStep::SetProjection`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScriptProjection {
+    /// A perspective projection with the given vertical field of view, in radians.
+    Perspective {
+        /// The vertical field of view.
+        fov: f32,
+    },
+    /// An orthographic projection. Its scale is taken from the camera's radius.
+    Orthographic,
+}
+
+/// Advance each camera's `NavigationScript`, starting steps as the previous ones finish
+pub fn run_navigation_scripts(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &mut PanOrbitCamera,
+        &mut NavigationScript,
+        &mut Projection,
+    )>,
+    time: CameraTime,
+) {
+    for (entity, mut pan_orbit, mut script, mut projection) in cameras.iter_mut() {
+        if !pan_orbit.initialized {
+            continue;
+        }
+        // Loop so that instant steps don't each take a frame
+        let mut remaining = time.delta_secs();
+        let mut wrapped = false;
+        loop {
+            let Some(&step) = script.steps.get(script.current) else {
+                // Only wrap once per frame, in case every step is instant
+                if script.looping && !script.steps.is_empty() && !wrapped {
+                    script.current = 0;
+                    wrapped = true;
+                    continue;
+                }
+                if script.looping {
+                    script.current = 0;
+                    break;
+                }
+                commands.entity(entity).remove::<NavigationScript>();
+                break;
+            };
+            let elapsed = match script.elapsed {
+                Some(elapsed) => elapsed + remaining,
+                None => {
+                    start_step(step, script.ease, &mut pan_orbit, &mut projection);
+                    remaining
+                }
+            };
+            let duration = step.duration();
+            if elapsed < duration {
+                script.elapsed = Some(elapsed);
+                break;
+            }
+            remaining = elapsed - duration;
+            script.elapsed = None;
+            script.current += 1;
+        }
+    }
+}
+
+/// Begin performing `step`
+fn start_step(
+    step: NavigationStep,
+    ease: EaseFunction,
+    pan_orbit: &mut PanOrbitCamera,
+    projection: &mut Projection,
+) {
+    let mut pose = pan_orbit.settled_pose();
+    let duration = Duration::from_secs_f32(step.duration().max(0.0));
+    match step {
+        NavigationStep::OrbitBy { yaw, pitch, .. } => {
+            pose.yaw += yaw;
+            pose.pitch += pitch;
+        }
+        NavigationStep::PanTo { focus, .. } => pose.focus = focus,
+        NavigationStep::ZoomTo { radius, .. } => pose.radius = radius,
+        NavigationStep::Wait { .. } => return,
+        NavigationStep::SetProjection(kind) => {
+            let radius = pan_orbit.radius.unwrap_or(pan_orbit.target_radius);
+            *projection = match kind {
+                ScriptProjection::Perspective { fov } => {
+                    Projection::Perspective(PerspectiveProjection { fov, ..default() })
+                }
+                ScriptProjection::Orthographic => {
+                    Projection::Orthographic(OrthographicProjection {
+                        scale: radius as f32,
+                        ..OrthographicProjection::default_3d()
+                    })
+                }
+            };
+            pan_orbit.force_update = true;
+            return;
+        }
+    }
+    // Keep the exact yaw change, rather than taking the shortest way round
+    let yaw = pose.yaw;
+    pan_orbit.animate_to(pose, duration, ease);
+    if let Some(animation) = pan_orbit.animation.as_mut() {
+        animation.to.yaw = yaw;
+    }
+}
@@ -0,0 +1,226 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::{
+    GamepadControls, KeyboardControls, PanOrbitCamera, PanOrbitCameraSystemSet, PanOrbitInputMap,
+    TouchControls,
+};
+
+/// The user-facing settings of a `PanOrbitCamera`, i.e. its sensitivities, bindings, and limits,
+/// e.g. for a settings menu. Use `SharedSettings` to keep a whole set of cameras in sync with one
+/// of these, or `PanOrbitCamera::settings` and `PanOrbitCamera::apply_settings` to copy them
+/// between cameras yourself. Each field matches the `PanOrbitCamera` field of the same name.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanOrbitSettings {
+    /// See `PanOrbitCamera::orbit_sensitivity`.
+    pub orbit_sensitivity: f64,
+    /// See `PanOrbitCamera::pan_sensitivity`.
+    pub pan_sensitivity: f64,
+    /// See `PanOrbitCamera::zoom_sensitivity`.
+    pub zoom_sensitivity: f64,
+    /// See `PanOrbitCamera::trackpad_sensitivity`.
+    pub trackpad_sensitivity: f64,
+    /// See `PanOrbitCamera::reversed_zoom`.
+    pub reversed_zoom: bool,
+    /// See `PanOrbitCamera::input_map`.
+    pub input_map: PanOrbitInputMap,
+    /// See `PanOrbitCamera::touch_controls`.
+    pub touch_controls: TouchControls,
+    /// See `PanOrbitCamera::keyboard_controls`.
+    pub keyboard_controls: Option<KeyboardControls>,
+    /// See `PanOrbitCamera::gamepad_controls`.
+    pub gamepad_controls: Option<GamepadControls>,
+    /// See `PanOrbitCamera::yaw_upper_limit`.
+    pub yaw_upper_limit: Option<f64>,
+    /// See `PanOrbitCamera::yaw_lower_limit`.
+    pub yaw_lower_limit: Option<f64>,
+    /// See `PanOrbitCamera::pitch_upper_limit`.
+    pub pitch_upper_limit: Option<f64>,
+    /// See `PanOrbitCamera::pitch_lower_limit`.
+    pub pitch_lower_limit: Option<f64>,
+    /// See `PanOrbitCamera::zoom_upper_limit`.
+    pub zoom_upper_limit: Option<f64>,
+    /// See `PanOrbitCamera::zoom_lower_limit`.
+    pub zoom_lower_limit: f64,
+}
+
+impl Default for PanOrbitSettings {
+    fn default() -> Self {
+        PanOrbitCamera::default().settings()
+    }
+}
+
+impl PanOrbitCamera {
+    /// This camera's sensitivities, bindings, and limits. See `PanOrbitSettings`.
+    pub fn settings(&self) -> PanOrbitSettings {
+        PanOrbitSettings {
+            orbit_sensitivity: self.orbit_sensitivity,
+            pan_sensitivity: self.pan_sensitivity,
+            zoom_sensitivity: self.zoom_sensitivity,
+            trackpad_sensitivity: self.trackpad_sensitivity,
+            reversed_zoom: self.reversed_zoom,
+            input_map: self.input_map.clone(),
+            touch_controls: self.touch_controls,
+            keyboard_controls: self.keyboard_controls,
+            gamepad_controls: self.gamepad_controls,
+            yaw_upper_limit: self.yaw_upper_limit,
+            yaw_lower_limit: self.yaw_lower_limit,
+            pitch_upper_limit: self.pitch_upper_limit,
+            pitch_lower_limit: self.pitch_lower_limit,
+            zoom_upper_limit: self.zoom_upper_limit,
+            zoom_lower_limit: self.zoom_lower_limit,
+        }
+    }
+
+    /// Replace this camera's sensitivities, bindings, and limits with `settings`. The camera
+    /// keeps its current view, apart from moving back inside any new limits.
+    pub fn apply_settings(&mut self, settings: &PanOrbitSettings) {
+        self.orbit_sensitivity = settings.orbit_sensitivity;
+        self.pan_sensitivity = settings.pan_sensitivity;
+        self.zoom_sensitivity = settings.zoom_sensitivity;
+        self.trackpad_sensitivity = settings.trackpad_sensitivity;
+        self.reversed_zoom = settings.reversed_zoom;
+        self.input_map = settings.input_map.clone();
+        self.touch_controls = settings.touch_controls;
+        self.keyboard_controls = settings.keyboard_controls;
+        self.gamepad_controls = settings.gamepad_controls;
+        self.yaw_upper_limit = settings.yaw_upper_limit;
+        self.yaw_lower_limit = settings.yaw_lower_limit;
+        self.pitch_upper_limit = settings.pitch_upper_limit;
+        self.pitch_lower_limit = settings.pitch_lower_limit;
+        self.zoom_upper_limit = settings.zoom_upper_limit;
+        self.zoom_lower_limit = settings.zoom_lower_limit;
+    }
+}
+
+/// Settings shared by every `PanOrbitCamera` with the marker component `M`, e.g. all the
+/// viewports of an editor. Whenever this resource changes, e.g. from a settings menu, the new
+/// settings are applied to all of those cameras, and cameras spawned later get them too. Requires
+/// `PanOrbitSettingsPlugin::<M>`.
+///
+/// Cameras whose settings change are reported with `SettingsApplied`. Settings are applied before
+/// `PanOrbitCameraSystemSet`, in `PostUpdate` unless `PanOrbitSettingsPlugin::in_schedule` says
+/// otherwise.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{
+/// #     PanOrbitCameraPlugin, PanOrbitSettings, PanOrbitSettingsPlugin, SharedSettings,
+/// # };
+/// #[derive(Component)]
+/// struct EditorViewport;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(PanOrbitCameraPlugin::default())
+///         .add_plugins(PanOrbitSettingsPlugin::<EditorViewport>::default())
+///         .insert_resource(SharedSettings::<EditorViewport>::new(PanOrbitSettings {
+///             orbit_sensitivity: 1.5,
+///             ..default()
+///         }))
+///         .add_systems(Update, invert_zoom)
+///         .run();
+/// }
+///
+/// // Every viewport picks up the change, without a sync system of its own
+/// fn invert_zoom(
+///     key_input: Res<ButtonInput<KeyCode>>,
+///     mut shared: ResMut<SharedSettings<EditorViewport>>,
+/// ) {
+///     if key_input.just_pressed(KeyCode::KeyI) {
+///         shared.settings.reversed_zoom = !shared.settings.reversed_zoom;
+///     }
+/// }
+/// ```
+#[derive(Resource)]
+pub struct SharedSettings<M: Component> {
+    /// The settings for every camera with `M`.
+    pub settings: PanOrbitSettings,
+    marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Component> SharedSettings<M> {
+    /// Share `settings` between every camera with `M`.
+    pub fn new(settings: PanOrbitSettings) -> Self {
+        Self {
+            settings,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: Component> Default for SharedSettings<M> {
+    fn default() -> Self {
+        Self::new(PanOrbitSettings::default())
+    }
+}
+
+/// Sent when `SharedSettings` changes a `PanOrbitCamera`'s settings.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct SettingsApplied {
+    /// The camera entity.
+    pub entity: Entity,
+}
+
+/// Keeps every `PanOrbitCamera` with the marker component `M` in sync with
+/// `SharedSettings<M>`. Add one for each set of cameras that shares its settings, alongside
+/// `PanOrbitCameraPlugin`. If the camera systems run in another schedule (see
+/// `PanOrbitCameraPlugin::in_schedule`), pass the same schedule to `in_schedule` here, so the
+/// settings are applied before the cameras update.
+pub struct PanOrbitSettingsPlugin<M: Component> {
+    schedule: InternedScheduleLabel,
+    marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Component> Default for PanOrbitSettingsPlugin<M> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: Component> PanOrbitSettingsPlugin<M> {
+    /// Apply the settings in `schedule` instead of `PostUpdate`. This must be the schedule the
+    /// camera systems run in, as the settings are applied before `PanOrbitCameraSystemSet`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl<M: Component> Plugin for PanOrbitSettingsPlugin<M> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SettingsApplied>().add_systems(
+            self.schedule,
+            apply_shared_settings::<M>.before(PanOrbitCameraSystemSet),
+        );
+    }
+}
+
+/// Apply `SharedSettings<M>` to cameras with `M` when it changes, and to cameras that just got
+/// `M`
+fn apply_shared_settings<M: Component>(
+    shared: Option<Res<SharedSettings<M>>>,
+    mut cameras: Query<(Entity, &mut PanOrbitCamera, Ref<M>)>,
+    mut applied: EventWriter<SettingsApplied>,
+) {
+    let Some(shared) = shared else {
+        return;
+    };
+    for (entity, mut pan_orbit, marker) in cameras.iter_mut() {
+        if !shared.is_changed() && !marker.is_added() && !pan_orbit.is_added() {
+            continue;
+        }
+        // Avoid triggering change detection when nothing is different
+        if pan_orbit.settings() != shared.settings {
+            pan_orbit.apply_settings(&shared.settings);
+            applied.send(SettingsApplied { entity });
+        }
+    }
+}
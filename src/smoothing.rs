@@ -0,0 +1,182 @@
+use std::fmt;
+
+use bevy::math::{DQuat, DVec3};
+use bevy::prelude::*;
+
+use crate::util;
+
+/// How a `PanOrbitCamera` moves its current values towards their targets, for each of orbiting,
+/// panning, and zooming. How much smoothing is applied is still set by `orbit_smoothness`,
+/// `pan_smoothness`, and `zoom_smoothness` (or `device_smoothing`); the curve sets its shape.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{PanOrbitCamera, SmoothingCurve, SmoothingCurves};
+/// // Ease in and out of orbiting, and settle zooming quicker than the default
+/// let camera = PanOrbitCamera {
+///     smoothing_curves: SmoothingCurves {
+///         orbit: SmoothingCurve::Spring,
+///         zoom: SmoothingCurve::Exponential { exponent: 12.0 },
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothingCurves {
+    /// The curve for yaw, pitch, and roll.
+    /// Defaults to `SmoothingCurve::default()`.
+    pub orbit: SmoothingCurve,
+    /// The curve for the focus.
+    /// Defaults to `SmoothingCurve::default()`.
+    pub pan: SmoothingCurve,
+    /// The curve for the radius.
+    /// Defaults to `SmoothingCurve::default()`.
+    pub zoom: SmoothingCurve,
+}
+
+/// The shape of a `PanOrbitCamera`'s smoothing, see `SmoothingCurves`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SmoothingCurve {
+    /// Cover the same proportion of the remaining distance every second, so the camera starts
+    /// quickly and slows down as it arrives. After one second, `smoothness` to the power of
+    /// `exponent` of the distance is left, so higher exponents settle faster.
+    Exponential {
+        /// How quickly the curve settles for a given smoothness.
+        exponent: f64,
+    },
+    /// A critically damped spring, which speeds up gently and then settles without overshooting.
+    /// The spring is as stiff as `Exponential { exponent: 7.0 }` is fast, so it takes a little
+    /// longer to arrive.
+    Spring,
+    /// A function of the smoothness and the frame time, in seconds, that returns the proportion
+    /// of the remaining distance to cover this frame, from `0.0` to `1.0`. Can't be serialized,
+    /// so serializing a camera using it fails. Custom curves never compare equal, even to
+    /// themselves, as function pointers can't be compared reliably.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    Custom(#[reflect(ignore)] SmoothingFn),
+}
+
+impl Default for SmoothingCurve {
+    fn default() -> Self {
+        Self::Exponential { exponent: 7.0 }
+    }
+}
+
+impl SmoothingCurve {
+    /// Move `from` towards `to`, updating `velocity` if this is a spring
+    pub(crate) fn step_f64(
+        &self,
+        from: f64,
+        to: f64,
+        velocity: &mut f64,
+        smoothness: f64,
+        dt: f64,
+    ) -> f64 {
+        let Some(factor) = self.lerp_factor(smoothness, dt) else {
+            return util::spring_and_snap_f64(from, to, velocity, smoothness, dt);
+        };
+        *velocity = 0.0;
+        let mut new_value = from.lerp(to, factor);
+        if smoothness < 1.0 && util::approx_equal_f64(new_value, to) {
+            new_value = to;
+        }
+        new_value
+    }
+
+    /// Move `from` towards `to`, updating `velocity` if this is a spring
+    pub(crate) fn step_dvec3(
+        &self,
+        from: DVec3,
+        to: DVec3,
+        velocity: &mut DVec3,
+        smoothness: f64,
+        dt: f64,
+    ) -> DVec3 {
+        let Some(factor) = self.lerp_factor(smoothness, dt) else {
+            return util::spring_and_snap_dvec3(from, to, velocity, smoothness, dt);
+        };
+        *velocity = DVec3::ZERO;
+        let mut new_value = from.lerp(to, factor);
+        if smoothness < 1.0 && util::approx_equal_f64((new_value - to).length(), 0.0) {
+            new_value = to;
+        }
+        new_value
+    }
+
+    /// Rotate `from` towards `to`, updating the angular `velocity` if this is a spring
+    pub(crate) fn step_dquat(
+        &self,
+        from: DQuat,
+        to: DQuat,
+        velocity: &mut f64,
+        smoothness: f64,
+        dt: f64,
+    ) -> DQuat {
+        // Smooth the angle that's left to turn, and turn by however much of it was covered
+        let angle = from.angle_between(to);
+        let remaining = self.step_f64(angle, 0.0, velocity, smoothness, dt);
+        if remaining == 0.0 || angle == 0.0 {
+            return to;
+        }
+        from.slerp(to, 1.0 - remaining / angle)
+    }
+
+    /// The proportion of the remaining distance to cover this frame, or `None` for a spring,
+    /// which depends on its velocity
+    fn lerp_factor(&self, smoothness: f64, dt: f64) -> Option<f64> {
+        match *self {
+            SmoothingCurve::Exponential { exponent } => {
+                Some(1.0 - smoothness.powf(exponent).powf(dt))
+            }
+            SmoothingCurve::Spring => None,
+            SmoothingCurve::Custom(SmoothingFn(function)) => {
+                Some(function(smoothness, dt).clamp(0.0, 1.0))
+            }
+        }
+    }
+}
+
+/// A function for `SmoothingCurve::Custom`.
+#[derive(Copy, Clone)]
+pub struct SmoothingFn(pub fn(smoothness: f64, dt: f64) -> f64);
+
+impl Default for SmoothingFn {
+    /// The default exponential curve.
+    fn default() -> Self {
+        Self(|smoothness, dt| 1.0 - smoothness.powi(7).powf(dt))
+    }
+}
+
+impl fmt::Debug for SmoothingFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SmoothingFn(..)")
+    }
+}
+
+impl PartialEq for SmoothingFn {
+    /// Always `false`, see `SmoothingCurve::Custom`.
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+/// How fast each of a `PanOrbitCamera`'s values is moving towards its target under
+/// `SmoothingCurve::Spring`. Zero for the other curves.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringVelocity {
+    /// Radians per second.
+    pub yaw: f64,
+    /// Radians per second.
+    pub pitch: f64,
+    /// Radians per second.
+    pub roll: f64,
+    /// Radians per second, towards `target_orientation` in `OrbitMode::Trackball`.
+    pub orientation: f64,
+    /// Units (or orthographic scale) per second.
+    pub radius: f64,
+    /// Units per second.
+    pub focus: DVec3,
+}
@@ -0,0 +1,84 @@
+use bevy::math::DVec2;
+use bevy::prelude::*;
+
+use crate::traits::OptionalClamp;
+use crate::{util, ControlMode, CutsceneState, PanOrbitCamera, SnappedToView, TimeSinceInput};
+
+/// Settings for snapping the camera onto nearby axis-aligned views, i.e. `StandardView::Top`,
+/// `Front`, `Right`, and so on, so it's easy to get an exact view without a keyboard shortcut.
+///
+/// When the user stops orbiting (after any momentum) within `threshold` of one of these views, the
+/// camera gently turns onto it, using the orbit smoothing, and `SnappedToView` is sent. Orbiting
+/// away again breaks the snap straight away. Views are relative to `PanOrbitCamera::axis`, and
+/// views outside the yaw and pitch limits are skipped.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct StickyViews {
+    /// How close, in radians of both yaw and pitch, the camera must come to rest to a view to
+    /// snap onto it. Top and bottom views only need the pitch to be close, and snap the yaw to
+    /// the nearest quarter turn.
+    /// Defaults to `5.0` degrees.
+    pub threshold: f64,
+}
+
+impl Default for StickyViews {
+    fn default() -> Self {
+        Self {
+            threshold: 5f64.to_radians(),
+        }
+    }
+}
+
+/// Snap cameras with `sticky_views` that have come to rest near an axis-aligned view onto it
+pub fn snap_to_sticky_views(
+    mut cameras: Query<(Entity, &mut PanOrbitCamera, &TimeSinceInput)>,
+    mut snapped_to_view: EventWriter<SnappedToView>,
+) {
+    for (entity, mut pan_orbit, time_since_input) in cameras.iter_mut() {
+        let Some(sticky) = pan_orbit.sticky_views else {
+            continue;
+        };
+        let (yaw, pitch) = (pan_orbit.target_yaw, pan_orbit.target_pitch);
+        let nearest = util::nearest_axis_view(yaw, pitch, sticky.threshold);
+        // Any movement away from the exact view breaks the snap
+        let snapped = nearest
+            .filter(|(_, view_yaw, view_pitch)| *view_yaw == yaw && *view_pitch == pitch)
+            .map(|(view, ..)| view);
+        if pan_orbit.snapped_view != snapped {
+            pan_orbit.snapped_view = snapped;
+        }
+
+        let resting = time_since_input.0 > 0.0 && pan_orbit.orbit_velocity == DVec2::ZERO;
+        let auto_rotating = pan_orbit
+            .auto_rotate
+            .is_some_and(|auto_rotate| time_since_input.0 >= auto_rotate.delay);
+        if snapped.is_some()
+            || !resting
+            || auto_rotating
+            || !pan_orbit.initialized
+            || !pan_orbit.enabled
+            || pan_orbit.animation.is_some()
+            || pan_orbit.is_guided()
+            || pan_orbit.cutscene == CutsceneState::Active
+            || pan_orbit.control_mode == ControlMode::Fly
+            || pan_orbit.target_orientation.is_some()
+        {
+            continue;
+        }
+        let Some((view, view_yaw, view_pitch)) = nearest else {
+            continue;
+        };
+        let within_limits = view_yaw
+            .clamp_optional(pan_orbit.yaw_lower_limit, pan_orbit.yaw_upper_limit)
+            == view_yaw
+            && view_pitch.clamp_optional(pan_orbit.pitch_lower_limit, pan_orbit.pitch_upper_limit)
+                == view_pitch;
+        if !within_limits {
+            continue;
+        }
+        pan_orbit.target_yaw = view_yaw;
+        pan_orbit.target_pitch = view_pitch;
+        pan_orbit.snapped_view = Some(view);
+        snapped_to_view.send(SnappedToView { entity, view });
+    }
+}
@@ -0,0 +1,194 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+
+use crate::{ActiveCameraData, PanOrbitCamera};
+
+/// Links `PanOrbitCamera`s, so that moving one moves the others, e.g. the top, front, side, and
+/// perspective panes of a CAD layout. Add this alongside `PanOrbitCamera`, with the same `id` on
+/// each camera in the group.
+///
+/// Whichever camera in the group is receiving input (see `ActiveCameraData`) leads, and each of
+/// the others copies the parts of its view that are in both cameras' `channels`, so a camera
+/// neither shares nor takes what it leaves out of its own. The copied values replace
+/// the camera's own, smoothing included, so the group moves as one. Cameras in a group should
+/// share the same `axis`.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, SyncChannels, SyncGroup};
+/// fn setup(mut commands: Commands) {
+///     // The perspective view orbits freely, but follows the other views' focus
+///     commands.spawn((
+///         PanOrbitCamera::default(),
+///         SyncGroup::new(0).with_channels(SyncChannels {
+///             rotation: false,
+///             zoom: false,
+///             ..default()
+///         }),
+///     ));
+///     // The orthographic views share their focus and zoom, but keep their own direction
+///     commands.spawn((
+///         PanOrbitCamera::default(),
+///         Projection::from(OrthographicProjection::default_3d()),
+///         SyncGroup::new(0).with_channels(SyncChannels {
+///             rotation: false,
+///             ..default()
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct SyncGroup {
+    /// Which group the camera is in.
+    pub id: u32,
+    /// Which parts of the view this camera shares with the rest of the group, both when it's
+    /// leading and when it's following.
+    /// Defaults to all of them.
+    pub channels: SyncChannels,
+}
+
+impl SyncGroup {
+    /// Join group `id`, copying the whole view.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            channels: SyncChannels::default(),
+        }
+    }
+
+    /// Only copy the given parts of the view.
+    pub fn with_channels(mut self, channels: SyncChannels) -> Self {
+        self.channels = channels;
+        self
+    }
+}
+
+/// The parts of a `PanOrbitCamera`'s view that a `SyncGroup` shares.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncChannels {
+    /// The focus.
+    /// Defaults to `true`.
+    pub focus: bool,
+    /// The yaw, pitch, and roll.
+    /// Defaults to `true`.
+    pub rotation: bool,
+    /// The radius. This is the scale for orthographic cameras, so only share it between cameras
+    /// with the same kind of projection.
+    /// Defaults to `true`.
+    pub zoom: bool,
+}
+
+impl SyncChannels {
+    /// The channels shared by both `self` and `other`
+    fn intersection(self, other: Self) -> Self {
+        Self {
+            focus: self.focus && other.focus,
+            rotation: self.rotation && other.rotation,
+            zoom: self.zoom && other.zoom,
+        }
+    }
+}
+
+impl Default for SyncChannels {
+    fn default() -> Self {
+        Self {
+            focus: true,
+            rotation: true,
+            zoom: true,
+        }
+    }
+}
+
+/// Copy the view of the camera receiving input to the other cameras in its `SyncGroup`
+pub fn sync_cameras(
+    active_cam: Res<ActiveCameraData>,
+    mut cameras: Query<(Entity, &mut PanOrbitCamera, &SyncGroup)>,
+) {
+    let Some(leader) = active_cam.entity else {
+        return;
+    };
+    let Ok((_, leader_pan_orbit, &SyncGroup { id, channels })) = cameras.get(leader) else {
+        return;
+    };
+    let (Some(yaw), Some(pitch), Some(radius)) = (
+        leader_pan_orbit.yaw,
+        leader_pan_orbit.pitch,
+        leader_pan_orbit.radius,
+    ) else {
+        return;
+    };
+    let view = SyncedView {
+        focus: leader_pan_orbit.focus,
+        target_focus: leader_pan_orbit.target_focus,
+        yaw,
+        target_yaw: leader_pan_orbit.target_yaw,
+        pitch,
+        target_pitch: leader_pan_orbit.target_pitch,
+        roll: leader_pan_orbit.roll,
+        target_roll: leader_pan_orbit.target_roll,
+        radius,
+        target_radius: leader_pan_orbit.target_radius,
+    };
+
+    for (entity, mut pan_orbit, group) in cameras.iter_mut() {
+        // The camera sets its own view when it's initialised, so wait until it has
+        if entity == leader || group.id != id || !pan_orbit.initialized {
+            continue;
+        }
+        view.apply(&mut pan_orbit, channels.intersection(group.channels));
+    }
+}
+
+/// The current and target values of the leading camera in a `SyncGroup`
+struct SyncedView {
+    focus: DVec3,
+    target_focus: DVec3,
+    yaw: f64,
+    target_yaw: f64,
+    pitch: f64,
+    target_pitch: f64,
+    roll: f64,
+    target_roll: f64,
+    radius: f64,
+    target_radius: f64,
+}
+
+impl SyncedView {
+    /// Copy the chosen channels to `pan_orbit`, only triggering change detection if they differ
+    fn apply(&self, pan_orbit: &mut Mut<PanOrbitCamera>, channels: SyncChannels) {
+        let unchanged = (!channels.focus
+            || (pan_orbit.focus == self.focus && pan_orbit.target_focus == self.target_focus))
+            && (!channels.rotation
+                || (pan_orbit.yaw == Some(self.yaw)
+                    && pan_orbit.target_yaw == self.target_yaw
+                    && pan_orbit.pitch == Some(self.pitch)
+                    && pan_orbit.target_pitch == self.target_pitch
+                    && pan_orbit.roll == self.roll
+                    && pan_orbit.target_roll == self.target_roll))
+            && (!channels.zoom
+                || (pan_orbit.radius == Some(self.radius)
+                    && pan_orbit.target_radius == self.target_radius));
+        if unchanged {
+            return;
+        }
+        if channels.focus {
+            pan_orbit.focus = self.focus;
+            pan_orbit.target_focus = self.target_focus;
+        }
+        if channels.rotation {
+            pan_orbit.yaw = Some(self.yaw);
+            pan_orbit.target_yaw = self.target_yaw;
+            pan_orbit.pitch = Some(self.pitch);
+            pan_orbit.target_pitch = self.target_pitch;
+            pan_orbit.roll = self.roll;
+            pan_orbit.target_roll = self.target_roll;
+        }
+        if channels.zoom {
+            pan_orbit.radius = Some(self.radius);
+            pan_orbit.target_radius = self.target_radius;
+        }
+        pan_orbit.force_update = true;
+    }
+}
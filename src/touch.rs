@@ -2,28 +2,203 @@ use bevy::input::touch::Touch;
 use bevy::math::Vec2;
 use bevy::prelude::*;
 
-/// The control scheme to use for touch input. Given that some touch gestures don't make sense
-/// being changed (e.g. pinch to zoom), there is just a set if different schemes rather than
-/// full customization.
-#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
-pub enum TouchControls {
+use crate::input::{input_settings, InputSettings};
+use crate::{ActiveCameraData, GestureConflicts, TwoFingerGesture};
+
+/// An action that a touch gesture can perform.
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TouchAction {
+    /// The gesture does nothing.
+    #[default]
+    None,
+    /// Orbit the camera. Pinch and twist gestures orbit horizontally.
+    Orbit,
+    /// Pan the camera. Pinch and twist gestures pan horizontally.
+    Pan,
+    /// Zoom the camera. Drag gestures zoom in when moving up.
+    Zoom,
+    /// Orbit the camera horizontally only. A twist gesture turns the camera by the same angle
+    /// the fingers turned, so the scene appears to rotate with them.
+    Yaw,
+}
+
+/// A touch gesture's action and how strongly it is applied.
+#[derive(Reflect, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TouchGestureBinding {
+    /// What the gesture does.
+    pub action: TouchAction,
+    /// Multiplier for how strongly the gesture is applied. This is on top of the camera's
+    /// `orbit_sensitivity`, `pan_sensitivity`, and `zoom_sensitivity`.
+    pub sensitivity: f32,
+}
+
+impl TouchGestureBinding {
+    /// A binding for `action` with a sensitivity of `1.0`.
+    pub fn new(action: TouchAction) -> Self {
+        Self {
+            action,
+            sensitivity: 1.0,
+        }
+    }
+
+    /// Set the sensitivity.
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+}
+
+impl From<TouchAction> for TouchGestureBinding {
+    fn from(value: TouchAction) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The touch controls for a `PanOrbitCamera`, i.e. which action each gesture performs.
+///
+/// Two finger gestures are recognised independently: each of drag, pinch, and twist only starts
+/// once the fingers have moved far enough in that way, so e.g. a pinch doesn't also rotate the
+/// camera slightly unless the fingers clearly twist too. To only allow one at a time, see
+/// `GestureConflicts`.
+/// # Example
+/// ```
+/// # use bevy_panorbit_camera::{TouchAction, TouchControls, TouchGestureBinding};
+/// // Map style controls
+/// let touch_controls = TouchControls {
+///     one_finger: TouchAction::Pan.into(),
+///     two_finger: TouchAction::None.into(),
+///     twist: TouchGestureBinding::new(TouchAction::Yaw).with_sensitivity(0.8),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Reflect, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TouchControls {
+    /// Moving one finger.
+    /// Defaults to `TouchAction::Orbit`.
+    pub one_finger: TouchGestureBinding,
+    /// Moving two fingers together.
+    /// Defaults to `TouchAction::Pan`.
+    pub two_finger: TouchGestureBinding,
+    /// Moving two fingers closer together or further apart.
+    /// Defaults to `TouchAction::Zoom`.
+    pub pinch: TouchGestureBinding,
+    /// Rotating two fingers around each other.
+    /// Defaults to `TouchAction::None`.
+    pub twist: TouchGestureBinding,
+}
+
+impl Default for TouchControls {
+    fn default() -> Self {
+        Self::one_finger_orbit()
+    }
+}
+
+impl TouchControls {
     /// Touch controls where single finger orbits:
     ///  - One finger move: orbit
     ///  - Two finger move: pan
     ///  - Two finger pinch: zoom
-    #[default]
-    OneFingerOrbit,
+    pub fn one_finger_orbit() -> Self {
+        Self {
+            one_finger: TouchAction::Orbit.into(),
+            two_finger: TouchAction::Pan.into(),
+            pinch: TouchAction::Zoom.into(),
+            twist: TouchAction::None.into(),
+        }
+    }
+
     /// Touch controls where single finger pans:
     ///  - One finger move: pan
     ///  - Two finger move: orbit
     ///  - Two finger pinch: zoom
-    TwoFingerOrbit,
+    pub fn two_finger_orbit() -> Self {
+        Self {
+            one_finger: TouchAction::Pan.into(),
+            two_finger: TouchAction::Orbit.into(),
+            ..Self::one_finger_orbit()
+        }
+    }
+
+    /// The binding for a two finger gesture
+    fn two_finger_binding(&self, gesture: TwoFingerGesture) -> TouchGestureBinding {
+        match gesture {
+            TwoFingerGesture::Drag => self.two_finger,
+            TwoFingerGesture::Pinch => self.pinch,
+            TwoFingerGesture::Twist => self.twist,
+        }
+    }
+
+    /// Convert this frame's gestures into camera input according to these controls
+    pub(crate) fn process(&self, gestures: &TouchGestures) -> TouchInput {
+        let mut input = TouchInput::default();
+        match gestures {
+            TouchGestures::None => {}
+            TouchGestures::OneFinger(gestures) => {
+                input.add_drag(self.one_finger, gestures.motion);
+            }
+            TouchGestures::TwoFinger(gestures) => {
+                input.add_drag(self.two_finger, gestures.motion);
+                input.add_amount(self.pinch, gestures.pinch, None);
+                let arc_length = gestures.rotation * gestures.distance / 2.0;
+                input.add_amount(self.twist, arc_length, Some(gestures.rotation));
+            }
+        }
+        input
+    }
+}
+
+/// Camera input produced by touch gestures
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub(crate) struct TouchInput {
+    /// Orbit motion, in pixels
+    pub orbit: Vec2,
+    /// Pan motion, in pixels
+    pub pan: Vec2,
+    /// Zoom amount, in the same units as pixel-based scrolling
+    pub zoom: f32,
+    /// Yaw, in radians
+    pub yaw: f32,
+}
+
+impl TouchInput {
+    /// Scale from pixels of finger movement to pixel scroll units
+    const ZOOM_SCALE: f32 = 0.015;
+
+    fn add_drag(&mut self, binding: TouchGestureBinding, motion: Vec2) {
+        let motion = motion * binding.sensitivity;
+        match binding.action {
+            TouchAction::None => {}
+            TouchAction::Orbit => self.orbit += motion,
+            TouchAction::Pan => self.pan += motion,
+            TouchAction::Zoom => self.zoom -= motion.y * Self::ZOOM_SCALE,
+            TouchAction::Yaw => self.orbit.x += motion.x,
+        }
+    }
+
+    /// Add a scalar gesture, measured in pixels. `radians` is the angle for twist gestures.
+    fn add_amount(&mut self, binding: TouchGestureBinding, amount: f32, radians: Option<f32>) {
+        let amount = amount * binding.sensitivity;
+        match binding.action {
+            TouchAction::None => {}
+            TouchAction::Orbit => self.orbit.x += amount,
+            TouchAction::Pan => self.pan.x += amount,
+            TouchAction::Zoom => self.zoom += amount * Self::ZOOM_SCALE,
+            TouchAction::Yaw => match radians {
+                Some(radians) => self.yaw += radians * binding.sensitivity,
+                None => self.orbit.x += amount,
+            },
+        }
+    }
 }
 
 /// Holds information about current mobile gestures
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum TouchGestures {
     /// No mobile gestures
+    #[default]
     None,
     /// One finger mobile gestures
     OneFinger(OneFingerGestures),
@@ -39,7 +214,7 @@ pub struct OneFingerGestures {
 }
 
 /// Holds information pertaining to two finger gestures
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct TwoFingerGestures {
     /// The delta movement of both touches.
     /// Uses the midpoint between the touches to calculate movement. Thus, if the midpoint doesn't
@@ -50,20 +225,91 @@ pub struct TwoFingerGestures {
     pub pinch: f32,
     /// The delta angle of the two touches.
     /// Positive values correspond to rotating clockwise.
-    #[allow(dead_code)]
     pub rotation: f32,
+    /// The current distance between the two touches.
+    pub distance: f32,
 }
 
-/// Stores current and previous frame mobile data, and provides a method to get mobile gestures
+impl TwoFingerGestures {
+    /// How far the fingers moved in the way of `gesture`, in pixels. Twists are measured along
+    /// the arc the fingers move on, at the current distance between them.
+    fn magnitude(&self, gesture: TwoFingerGesture, distance: f32) -> f32 {
+        match gesture {
+            TwoFingerGesture::Drag => self.motion.length(),
+            TwoFingerGesture::Pinch => self.pinch.abs(),
+            TwoFingerGesture::Twist => (self.rotation * distance / 2.0).abs(),
+        }
+    }
+
+    /// Move the movement of `gesture` into `into`, leaving zero behind
+    fn take(&mut self, gesture: TwoFingerGesture, into: &mut TwoFingerGestures) {
+        match gesture {
+            TwoFingerGesture::Drag => into.motion += std::mem::take(&mut self.motion),
+            TwoFingerGesture::Pinch => into.pinch += std::mem::take(&mut self.pinch),
+            TwoFingerGesture::Twist => into.rotation += std::mem::take(&mut self.rotation),
+        }
+    }
+}
+
+/// The touch gestures that `PanOrbitCamera` responds to, updated each frame in
+/// `PanOrbitCameraSystemSet`. Read this resource to build other gestures that work alongside the
+/// built in ones, e.g. a three finger swipe to switch tools, from systems that run after that set.
+///
+/// Only one and two finger gestures are used by the plugin. While three or more fingers are
+/// touching the screen, the gestures are `TouchGestures::None`, so those touches can be handled
+/// from `Touches` without also moving the camera.
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::TouchTracker;
+/// fn three_finger_tap(touches: Res<Touches>, touch_tracker: Res<TouchTracker>) {
+///     let started = touches.iter_just_pressed().next().is_some();
+///     if started && touch_tracker.touch_count() == 3 {
+///         // Switch tools
+///     }
+/// }
+/// ```
 #[derive(Resource, Default, Debug)]
 pub struct TouchTracker {
     curr_pressed: (Option<Touch>, Option<Touch>),
     prev_pressed: (Option<Touch>, Option<Touch>),
+    touch_count: usize,
+    prev_touch_count: usize,
+    recognizer: TwoFingerRecognizer,
+    gestures: TouchGestures,
 }
 
 impl TouchTracker {
-    /// Calculate and return mobile gesture data for this frame
+    /// Return mobile gesture data for this frame
     pub fn get_touch_gestures(&self) -> TouchGestures {
+        self.gestures.clone()
+    }
+
+    /// Whether any fingers are touching the screen
+    pub fn is_touching(&self) -> bool {
+        self.touch_count > 0
+    }
+
+    /// Whether a finger touched the screen this frame, when none were touching it before
+    pub fn touch_just_started(&self) -> bool {
+        self.touch_count > 0 && self.prev_touch_count == 0
+    }
+
+    /// How many fingers are touching the screen, including any beyond the two that gestures use
+    pub fn touch_count(&self) -> usize {
+        self.touch_count
+    }
+
+    /// The touches that this frame's gestures were calculated from. This is empty while three or
+    /// more fingers are touching the screen.
+    pub fn gesture_touches(&self) -> impl Iterator<Item = &Touch> {
+        [&self.curr_pressed.0, &self.curr_pressed.1]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Calculate mobile gesture data for this frame, before two finger gesture recognition
+    fn raw_touch_gestures(&self) -> TouchGestures {
         // The below matches only match when the previous and current frames have the same number
         // of touches. This means that when the number of touches changes, there's one frame
         // where this will return `TouchGestures::None`. From my testing, this does not result
@@ -122,6 +368,7 @@ impl TouchTracker {
                     motion,
                     pinch,
                     rotation,
+                    distance: curr_dist,
                 })
             }
             // Three fingers and more not currently supported
@@ -130,9 +377,110 @@ impl TouchTracker {
     }
 }
 
-/// Read touch input and save it in TouchTracker resource for easy consumption by the main system
-pub fn touch_tracker(touches: Res<Touches>, mut touch_tracker: ResMut<TouchTracker>) {
+/// Recognises which two finger gestures are in progress. Each gesture's movement is held back
+/// until it passes `GestureConflicts::recognition_threshold`, then released all at once, after
+/// which it's passed through until the fingers are lifted.
+///
+/// With `GestureConflicts::exclusive_two_finger`, only the recognised gesture is passed through,
+/// and the others keep being held back until one moves far enough to take over.
+#[derive(Default, Debug)]
+struct TwoFingerRecognizer {
+    /// Movement of gestures that haven't been recognised yet
+    pending: TwoFingerGestures,
+    /// Whether the drag, pinch, and twist gestures have been recognised
+    recognized: (bool, bool, bool),
+    /// The only recognised gesture, with `exclusive_two_finger`
+    exclusive: Option<TwoFingerGesture>,
+}
+
+impl TwoFingerRecognizer {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn recognize(
+        &mut self,
+        gestures: TwoFingerGestures,
+        controls: &TouchControls,
+        conflicts: &GestureConflicts,
+    ) -> TwoFingerGestures {
+        let pending = &mut self.pending;
+        pending.motion += gestures.motion;
+        pending.pinch += gestures.pinch;
+        pending.rotation += gestures.rotation;
+        if conflicts.exclusive_two_finger {
+            return self.recognize_exclusive(gestures.distance, controls, conflicts);
+        }
+        let threshold = conflicts.recognition_threshold;
+        let passed = |gesture| pending.magnitude(gesture, gestures.distance) > threshold;
+        let (drag, pinch, twist) = &mut self.recognized;
+        *drag |= passed(TwoFingerGesture::Drag);
+        *pinch |= passed(TwoFingerGesture::Pinch);
+        *twist |= passed(TwoFingerGesture::Twist);
+
+        let mut recognized = TwoFingerGestures {
+            distance: gestures.distance,
+            ..default()
+        };
+        if *drag {
+            recognized.motion = std::mem::take(&mut pending.motion);
+        }
+        if *pinch {
+            recognized.pinch = std::mem::take(&mut pending.pinch);
+        }
+        if *twist {
+            recognized.rotation = std::mem::take(&mut pending.rotation);
+        }
+        recognized
+    }
+
+    /// Recognise one gesture at a time. Another gesture only takes over once it passes the
+    /// threshold multiplied by `hysteresis`, measured from when the current one was recognised.
+    fn recognize_exclusive(
+        &mut self,
+        distance: f32,
+        controls: &TouchControls,
+        conflicts: &GestureConflicts,
+    ) -> TwoFingerGestures {
+        let threshold = match self.exclusive {
+            Some(_) => conflicts.recognition_threshold * conflicts.hysteresis,
+            None => conflicts.recognition_threshold,
+        };
+        let candidates = TwoFingerGesture::ALL.into_iter().filter(|&gesture| {
+            Some(gesture) != self.exclusive
+                && controls.two_finger_binding(gesture).action != TouchAction::None
+                && self.pending.magnitude(gesture, distance) > threshold
+        });
+        if let Some(gesture) = conflicts.two_finger_winner(candidates) {
+            // The other gestures start again from zero, so they can't immediately take back over
+            let mut pending = TwoFingerGestures::default();
+            self.pending.take(gesture, &mut pending);
+            self.pending = pending;
+            self.exclusive = Some(gesture);
+        }
+
+        let mut recognized = TwoFingerGestures {
+            distance,
+            ..default()
+        };
+        if let Some(gesture) = self.exclusive {
+            self.pending.take(gesture, &mut recognized);
+        }
+        recognized
+    }
+}
+
+/// Read touch input and save it in TouchTracker resource for easy consumption by the main system.
+/// Two finger gestures are recognised according to the active camera's `gesture_conflicts`.
+pub fn touch_tracker(
+    touches: Res<Touches>,
+    mut touch_tracker: ResMut<TouchTracker>,
+    active_cam: Res<ActiveCameraData>,
+    orbit_cameras: Query<InputSettings>,
+) {
     let pressed: Vec<&Touch> = touches.iter().collect();
+    touch_tracker.prev_touch_count = touch_tracker.touch_count;
+    touch_tracker.touch_count = pressed.len();
 
     match pressed.len() {
         0 => {
@@ -150,6 +498,36 @@ pub fn touch_tracker(touches: Res<Touches>, mut touch_tracker: ResMut<TouchTrack
             touch_tracker.prev_pressed = touch_tracker.curr_pressed;
             touch_tracker.curr_pressed = (Some(*touch1), Some(*touch2));
         }
-        _ => {}
+        // Three fingers and more are left to the app. Forgetting the touches means the last two
+        // finger movement isn't repeated, and that gestures restart cleanly when a finger lifts.
+        _ => {
+            touch_tracker.curr_pressed = (None, None);
+            touch_tracker.prev_pressed = (None, None);
+        }
     }
+
+    touch_tracker.gestures = match touch_tracker.raw_touch_gestures() {
+        TouchGestures::TwoFinger(gestures) => {
+            let pan_orbit = active_cam
+                .entity
+                .and_then(|entity| orbit_cameras.get(entity).ok())
+                .map(input_settings);
+            let (controls, conflicts) = match pan_orbit {
+                Some(pan_orbit) => (
+                    pan_orbit.touch_controls,
+                    pan_orbit.gesture_conflicts.clone(),
+                ),
+                None => (TouchControls::default(), GestureConflicts::default()),
+            };
+            TouchGestures::TwoFinger(
+                touch_tracker
+                    .recognizer
+                    .recognize(gestures, &controls, &conflicts),
+            )
+        }
+        gestures => {
+            touch_tracker.recognizer.reset();
+            gestures
+        }
+    };
 }
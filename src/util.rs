@@ -1,8 +1,13 @@
+use std::f64::consts::TAU;
+use std::ops::{Add, Mul, Sub};
+
 use bevy::{
-    math::{DMat3, DQuat, DVec3},
+    math::{DMat3, DQuat, DVec2, DVec3},
     prelude::*,
 };
 
+use crate::{AutoClipPlanes, FocusBoundsShape, FramingFit, FramingInsets, StandardView};
+
 const EPSILON: f32 = 0.001;
 
 pub fn calculate_from_translation_and_focus(
@@ -10,45 +15,455 @@ pub fn calculate_from_translation_and_focus(
     focus: DVec3,
     axis: [DVec3; 3],
 ) -> (f64, f64, f64) {
-    let axis = DMat3::from_cols(axis[0], axis[1], axis[2]);
     let comp_vec = translation - focus;
     let mut radius = comp_vec.length();
     if radius == 0.0 {
         radius = 0.05; // Radius 0 causes problems
     }
-    let comp_vec = axis * comp_vec;
-    let yaw = comp_vec.x.atan2(comp_vec.z);
-    let pitch = (comp_vec.y / radius).asin();
+    // Express the offset in terms of the camera's axes, so this is the inverse of `orbit_offset`
+    let [x, y, z] = axis.map(|axis| comp_vec.dot(axis));
+    let yaw = x.atan2(z);
+    let pitch = (y / radius).asin();
     (yaw, pitch, radius)
 }
 
-/// Update `transform` based on yaw, pitch, and the camera's focus and radius
+/// Update `transform` based on yaw, pitch, roll, and the camera's focus and radius. If
+/// `orientation` is given (in trackball mode), it's used as the camera's rotation instead of yaw,
+/// pitch, and roll. If `clip_planes` is given, the projection's near and far planes are scaled
+/// to suit the radius. `position` is set in world space, and the translation is relative to
+/// `world_offset`.
+#[allow(clippy::too_many_arguments)]
 pub fn update_orbit_transform(
     yaw: f64,
     pitch: f64,
+    roll: f64,
+    orientation: Option<DQuat>,
     mut radius: f64,
     focus: DVec3,
     transform: &mut Transform,
     position: &mut DVec3,
     projection: &mut Projection,
     axis: [DVec3; 3],
+    clip_planes: Option<AutoClipPlanes>,
+    world_offset: DVec3,
 ) {
     let mut new_transform = Transform::IDENTITY;
     if let Projection::Orthographic(ref mut p) = *projection {
         p.scale = radius as f32;
-        // (near + far) / 2.0 ensures that objects near `focus` are not clipped
-        radius = (p.near as f64 + p.far as f64) / 2.0;
     }
-    let yaw_rot = DQuat::from_axis_angle(axis[1], yaw);
-    let pitch_rot = DQuat::from_axis_angle(axis[0], -pitch);
-    let new_rotation = yaw_rot * pitch_rot;
+    if let Some(clip_planes) = clip_planes {
+        scale_clip_planes(radius, projection, &clip_planes);
+    }
+    radius = camera_distance(radius, projection);
+    let (new_rotation, offset) = match orientation {
+        Some(orientation) => (orientation, orientation * DVec3::Z),
+        None => (
+            orbit_rotation(yaw, pitch, axis) * DQuat::from_rotation_z(roll),
+            orbit_offset(yaw, pitch, axis),
+        ),
+    };
     new_transform.rotation *= new_rotation.as_quat();
-    let new_position = focus + new_rotation * DVec3::new(0.0, 0.0, radius);
+    let new_position = focus + offset * radius;
     *position = new_position;
-    new_transform.translation += new_position.as_vec3();
+    new_transform.translation += (new_position - world_offset).as_vec3();
     *transform = new_transform;
 }
 
+/// The direction from the focus to the camera for the given yaw and pitch. At zero yaw and pitch
+/// this is `axis[2]`, positive pitch moves towards `axis[1]` (up), and positive yaw moves towards
+/// `axis[0]`.
+pub fn orbit_offset(yaw: f64, pitch: f64, axis: [DVec3; 3]) -> DVec3 {
+    let [x, y, z] = axis;
+    pitch.cos() * (yaw.sin() * x + yaw.cos() * z) + pitch.sin() * y
+}
+
+/// `axis` turned around its up axis by `angle`, in the direction of increasing yaw, so that
+/// `orbit_offset(yaw, pitch, rotate_axis_yaw(axis, angle))` equals
+/// `orbit_offset(yaw + angle, pitch, axis)`
+pub fn rotate_axis_yaw(axis: [DVec3; 3], angle: f64) -> [DVec3; 3] {
+    let [x, y, z] = axis;
+    let (sin, cos) = angle.sin_cos();
+    [cos * x - sin * z, y, sin * x + cos * z]
+}
+
+/// The compass direction the camera with `rotation` is facing, in radians clockwise from north
+/// between 0 and TAU, taking `-axis[2]` as north and `axis[0]` as east. When looking straight up
+/// or down, the top of the screen is used instead.
+pub fn compass_heading(rotation: DQuat, axis: [DVec3; 3]) -> f64 {
+    let heading = |direction: DVec3| {
+        let (east, north) = (direction.dot(axis[0]), -direction.dot(axis[2]));
+        (east.abs() + north.abs() > 1e-6).then(|| east.atan2(north).rem_euclid(TAU))
+    };
+    heading(rotation * DVec3::NEG_Z)
+        .or_else(|| heading(rotation * DVec3::Y))
+        .unwrap_or(0.0)
+}
+
+/// Whether `axis` is a mirrored (left-handed) basis, e.g. one with a single axis flipped
+pub fn is_left_handed(axis: [DVec3; 3]) -> bool {
+    axis[0].cross(axis[1]).dot(axis[2]) < 0.0
+}
+
+/// The camera's rotation for the given yaw and pitch, i.e. looking from
+/// `orbit_offset(yaw, pitch, axis)` towards the focus, with `axis[1]` as up. This works for
+/// mirrored axes as well, where the camera's right is the opposite of `axis[0]`.
+pub fn orbit_rotation(yaw: f64, pitch: f64, axis: [DVec3; 3]) -> DQuat {
+    let back = orbit_offset(yaw, pitch, axis).normalize();
+    let handedness = if is_left_handed(axis) { -1.0 } else { 1.0 };
+    // Depends only on yaw, so it stays valid when looking straight up or down, and when upside
+    // down
+    let right = (handedness * (yaw.cos() * axis[0] - yaw.sin() * axis[2])).normalize();
+    let up = back.cross(right);
+    DQuat::from_mat3(&DMat3::from_cols(right, up, back))
+}
+
+/// The yaw and pitch at which `orbit_rotation` looks in the same direction as `rotation`. Any
+/// roll is left out, and pitch is between -PI/2 and PI/2.
+pub fn yaw_pitch_from_rotation(rotation: DQuat, axis: [DVec3; 3]) -> (f64, f64) {
+    let back = rotation * DVec3::Z;
+    let [x, y, z] = axis.map(|axis| back.dot(axis));
+    (x.atan2(z), y.clamp(-1.0, 1.0).asin())
+}
+
+/// The radius (or orthographic scale) at which a sphere of `sphere_radius` around the focus
+/// exactly fits the extent of the view given by `fit`, within a region `extent` times the view's
+/// width and height, e.g. the part of the viewport left visible by `FramingInsets`. Returns `None`
+/// if the projection doesn't have a usable size yet.
+pub fn fit_radius(
+    sphere_radius: f64,
+    projection: &Projection,
+    fit: FramingFit,
+    extent: DVec2,
+) -> Option<f64> {
+    match *projection {
+        Projection::Perspective(ref p) => {
+            let tan_half_fov_y = (p.fov as f64 / 2.0).tan();
+            let half_fov_y = (tan_half_fov_y * extent.y).atan();
+            let half_fov_x = (tan_half_fov_y * p.aspect_ratio as f64 * extent.x).atan();
+            let half_fov = match fit {
+                FramingFit::Both => half_fov_x.min(half_fov_y),
+                FramingFit::Vertical => half_fov_y,
+                FramingFit::Horizontal => half_fov_x,
+            };
+            (half_fov > 0.0).then(|| sphere_radius / half_fov.sin())
+        }
+        Projection::Orthographic(ref p) => {
+            // The area scales linearly with the scale
+            let width = p.area.width() as f64 * extent.x;
+            let height = p.area.height() as f64 * extent.y;
+            let size = match fit {
+                FramingFit::Both => width.min(height),
+                FramingFit::Vertical => height,
+                FramingFit::Horizontal => width,
+            };
+            (size > 0.0 && p.scale > 0.0).then(|| 2.0 * sphere_radius * p.scale as f64 / size)
+        }
+    }
+}
+
+/// Half the width and height of the view at the focus, in world units, for a camera with the given
+/// radius (or orthographic scale)
+pub fn view_half_extents(radius: f64, projection: &Projection) -> DVec2 {
+    match *projection {
+        Projection::Perspective(ref p) => {
+            let half_height = radius * (p.fov as f64 / 2.0).tan();
+            DVec2::new(half_height * p.aspect_ratio as f64, half_height)
+        }
+        Projection::Orthographic(ref p) if p.scale > 0.0 => {
+            p.area.half_size().as_dvec2() * radius / p.scale as f64
+        }
+        Projection::Orthographic(_) => DVec2::ZERO,
+    }
+}
+
+/// The distance that keyboard, gamepad, and edge panning speeds, and the pivot indicator's size,
+/// are multiplied by, so they cover the same proportion of the view at any zoom. This is `radius` for perspective cameras. For
+/// orthographic cameras, where `radius` is the scale, it's the distance at which a perspective
+/// camera with a vertical field of view of `perspective_fov` would see as much, so switching
+/// projections doesn't change how fast the view pans.
+pub fn pan_distance(radius: f64, projection: &Projection, perspective_fov: f32) -> f64 {
+    match *projection {
+        Projection::Perspective(_) => radius,
+        Projection::Orthographic(_) => {
+            let half_fov = perspective_fov as f64 / 2.0;
+            let half_height = view_half_extents(radius, projection).y;
+            if half_height > 0.0 {
+                half_height / half_fov.tan()
+            } else {
+                radius
+            }
+        }
+    }
+}
+
+/// The region of a viewport of `viewport_size` left visible by `insets`. Returns its size as a
+/// fraction of the viewport's, and its centre relative to the viewport's centre, from `-1.0` to
+/// `1.0` with y up. The region is never smaller than a pixel.
+pub fn safe_region(viewport_size: Vec2, insets: &FramingInsets) -> (DVec2, DVec2) {
+    let size = viewport_size.max(Vec2::ONE);
+    let visible = Vec2::new(
+        size.x - insets.left - insets.right,
+        size.y - insets.top - insets.bottom,
+    )
+    .max(Vec2::ONE);
+    let min = Vec2::new(insets.left, insets.bottom);
+    let center = (min + visible / 2.0) / size * 2.0 - Vec2::ONE;
+    ((visible / size).as_dvec2(), center.as_dvec2())
+}
+
+/// The largest rect with `aspect_ratio` (width / height) that fits inside `rect`, centred in it,
+/// i.e. where an image with that aspect ratio is drawn with bars along its sides or top and bottom.
+pub fn fit_aspect_ratio(rect: Rect, aspect_ratio: f32) -> Rect {
+    let size = rect.size();
+    if size.x <= 0.0 || size.y <= 0.0 || aspect_ratio <= 0.0 || !aspect_ratio.is_finite() {
+        return rect;
+    }
+    let fitted = match size.x / size.y > aspect_ratio {
+        true => Vec2::new(size.y * aspect_ratio, size.y),
+        false => Vec2::new(size.x, size.x / aspect_ratio),
+    };
+    Rect::from_center_size(rect.center(), fitted)
+}
+
+/// Set the near and far planes of `projection` to suit a camera with the given radius (or
+/// orthographic scale). Orthographic cameras keep their near plane at zero, so that
+/// `camera_distance` puts the focus in the middle of the depth range.
+pub fn scale_clip_planes(radius: f64, projection: &mut Projection, clip_planes: &AutoClipPlanes) {
+    let half_extent = view_half_extents(radius, projection).max_element() as f32;
+    match *projection {
+        Projection::Perspective(ref mut p) => {
+            p.near = (radius as f32 * clip_planes.near_ratio).max(clip_planes.min_near);
+            p.far = (radius as f32 * clip_planes.far_ratio).max(p.near);
+        }
+        Projection::Orthographic(ref mut p) => {
+            let depth = (half_extent * clip_planes.far_ratio).max(clip_planes.min_near);
+            p.near = 0.0;
+            p.far = 2.0 * depth;
+        }
+    }
+}
+
+/// The actual distance between the camera and its focus. This is `radius` for perspective
+/// cameras, but orthographic cameras use `radius` as their scale, and sit halfway between the
+/// near and far planes so that objects near `focus` are not clipped.
+pub fn camera_distance(radius: f64, projection: &Projection) -> f64 {
+    match *projection {
+        Projection::Orthographic(ref p) => (p.near as f64 + p.far as f64) / 2.0,
+        _ => radius,
+    }
+}
+
+/// Smooth ease-in-out curve for `t` between `0.0` and `1.0`
+pub fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// The phases of a move over a distance of `1.0` that starts and ends at rest, within `max_speed`
+/// and `max_acceleration`: how long speeding up (and slowing down) takes, the top speed, and how
+/// long the top speed is held. With `s_curve`, acceleration rises and falls smoothly rather than
+/// switching on and off, so reaching the same speed takes twice as long.
+fn motion_phases(max_speed: f64, max_acceleration: f64, s_curve: bool) -> (f64, f64, f64) {
+    let ramp = if s_curve { 2.0 } else { 1.0 };
+    // Moves too short to reach `max_speed` turn round at a lower speed
+    let speed = max_speed.min((max_acceleration / ramp).sqrt());
+    if !speed.is_finite() || speed <= 0.0 {
+        return (0.0, speed, 0.0);
+    }
+    let accel_time = ramp * speed / max_acceleration;
+    let cruise_time = (1.0 - speed * accel_time).max(0.0) / speed;
+    (accel_time, speed, cruise_time)
+}
+
+/// How long a move over a distance of `1.0` takes, see `motion_progress`
+pub fn motion_duration(max_speed: f64, max_acceleration: f64, s_curve: bool) -> f64 {
+    let (accel_time, _, cruise_time) = motion_phases(max_speed, max_acceleration, s_curve);
+    2.0 * accel_time + cruise_time
+}
+
+/// How far, from `0.0` to `1.0`, a move has got after `time` seconds, speeding up at
+/// `max_acceleration` to at most `max_speed`, and slowing down in the same way. This is a
+/// trapezoidal speed profile, or with `s_curve`, one with sine-squared acceleration, which has no
+/// sudden changes in acceleration.
+pub fn motion_progress(time: f64, max_speed: f64, max_acceleration: f64, s_curve: bool) -> f64 {
+    let (accel_time, speed, cruise_time) = motion_phases(max_speed, max_acceleration, s_curve);
+    let duration = 2.0 * accel_time + cruise_time;
+    if duration <= 0.0 || time >= duration {
+        return 1.0;
+    }
+    // Distance covered after `time` seconds of speeding up
+    let speeding_up = |time: f64| {
+        if accel_time <= 0.0 {
+            return 0.0;
+        }
+        let x = (time / accel_time).clamp(0.0, 1.0);
+        let shape = match s_curve {
+            true => x * x / 2.0 + ((TAU * x).cos() - 1.0) / (TAU * TAU),
+            false => x * x / 2.0,
+        };
+        speed * accel_time * shape
+    };
+    let time = time.max(0.0);
+    if time <= accel_time + cruise_time {
+        speeding_up(time) + speed * (time - accel_time).max(0.0)
+    } else {
+        1.0 - speeding_up(duration - time)
+    }
+}
+
+/// Ignore stick input below `dead_zone`, and rescale the rest so it starts from zero at the edge
+/// of the dead zone and still reaches full deflection at a length of `1.0`.
+pub fn apply_dead_zone(value: Vec2, dead_zone: f32) -> Vec2 {
+    let length = value.length();
+    if length <= dead_zone || dead_zone >= 1.0 {
+        return Vec2::ZERO;
+    }
+    let rescaled = ((length - dead_zone) / (1.0 - dead_zone)).min(1.0);
+    value / length * rescaled
+}
+
+/// Parse the `(input, multiplier)` points of a response curve, one pair per line separated by
+/// whitespace, skipping blank lines and `#` comments. On failure, returns the line number
+/// (counting from 1) that couldn't be parsed.
+pub fn parse_curve_points(text: &str) -> Result<Vec<(f32, f32)>, usize> {
+    let mut points = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut numbers = line.split_whitespace().map(str::parse::<f32>);
+        match (numbers.next(), numbers.next(), numbers.next()) {
+            (None, _, _) => {}
+            (Some(Ok(input)), Some(Ok(multiplier)), None) => points.push((input, multiplier)),
+            _ => return Err(index + 1),
+        }
+    }
+    Ok(points)
+}
+
+/// The outward normal of the face of a unit cube centred on the origin that a ray hits first, or
+/// `None` if the ray misses the cube.
+#[cfg(feature = "view_cube")]
+pub fn ray_cube_face(origin: DVec3, direction: DVec3) -> Option<DVec3> {
+    // Slab method: intersect the ray with each pair of parallel faces
+    let inverse = direction.recip();
+    let t1 = (DVec3::splat(-0.5) - origin) * inverse;
+    let t2 = (DVec3::splat(0.5) - origin) * inverse;
+    let t_near = t1.min(t2).max_element();
+    let t_far = t1.max(t2).min_element();
+    if t_near > t_far || t_far < 0.0 {
+        return None;
+    }
+    // The face is on the axis where the hit point is furthest from the centre
+    let hit = origin + direction * t_near;
+    let abs = hit.abs();
+    let normal = if abs.x >= abs.y && abs.x >= abs.z {
+        DVec3::X * hit.x.signum()
+    } else if abs.y >= abs.z {
+        DVec3::Y * hit.y.signum()
+    } else {
+        DVec3::Z * hit.z.signum()
+    };
+    Some(normal)
+}
+
+/// The furthest distance from `point` along `direction` that stays inside `shape`, where both
+/// `point` and `shape` are relative to the shape's origin. `point` should be inside the shape, and
+/// `direction` normalized. Returns `0.0` if there is no such distance.
+pub fn max_distance_in_bounds(point: DVec3, direction: DVec3, shape: FocusBoundsShape) -> f64 {
+    let distance = match shape {
+        FocusBoundsShape::Sphere(sphere) => {
+            // Solve |point + direction * t| = radius for the positive t
+            let b = point.dot(direction);
+            let c = point.length_squared() - (sphere.radius as f64).powi(2);
+            -b + (b * b - c).max(0.0).sqrt()
+        }
+        FocusBoundsShape::Cuboid(cuboid) => {
+            // The nearest face that the ray leaves through
+            let half_size = cuboid.half_size.as_dvec3();
+            let exits = (direction.signum() * half_size - point) / direction;
+            [exits.x, exits.y, exits.z]
+                .into_iter()
+                .filter(|t| !t.is_nan())
+                .fold(f64::INFINITY, f64::min)
+        }
+    };
+    distance.max(0.0)
+}
+
+/// Whether a camera with the given pitch is upside down, i.e. has gone over the top or bottom of
+/// its focus. Works for any number of turns in either direction.
+pub fn is_upside_down(pitch: f64) -> bool {
+    let wrapped_pitch = (pitch % TAU).abs();
+    wrapped_pitch > TAU / 4.0 && wrapped_pitch < 3.0 * TAU / 4.0
+}
+
+/// Find where a ray intersects a plane. Returns `None` if the ray is parallel to the plane, or
+/// if the plane is behind the ray's origin.
+pub fn ray_plane_intersection(
+    origin: DVec3,
+    direction: DVec3,
+    plane_point: DVec3,
+    plane_normal: DVec3,
+) -> Option<DVec3> {
+    let denominator = direction.dot(plane_normal);
+    if denominator.abs() < f64::EPSILON {
+        return None;
+    }
+    let t = (plane_point - origin).dot(plane_normal) / denominator;
+    if t < 0.0 {
+        return None;
+    }
+    Some(origin + direction * t)
+}
+
+/// Returns the angle equivalent to `to` (i.e. differing by a multiple of TAU) that is closest
+/// to `from`.
+pub fn nearest_equivalent_angle(from: f64, to: f64) -> f64 {
+    to + ((from - to) / TAU).round() * TAU
+}
+
+/// The axis-aligned `StandardView` nearest to `yaw` and `pitch`, if they are both within
+/// `threshold` radians of it, with the view's own yaw and pitch wrapped to be nearest the given
+/// ones. Top and bottom views take the nearest quarter turn of yaw, so they're square to the axes.
+pub fn nearest_axis_view(yaw: f64, pitch: f64, threshold: f64) -> Option<(StandardView, f64, f64)> {
+    let quarter_turn = TAU / 4.0;
+    StandardView::ALL
+        .into_iter()
+        .filter(|view| *view != StandardView::Isometric)
+        .filter_map(|view| {
+            let (view_yaw, view_pitch) = view.yaw_pitch();
+            let view_pitch = nearest_equivalent_angle(pitch, view_pitch);
+            let (view_yaw, error) = match view {
+                StandardView::Top | StandardView::Bottom => (
+                    (yaw / quarter_turn).round() * quarter_turn,
+                    (pitch - view_pitch).abs(),
+                ),
+                _ => {
+                    let view_yaw = nearest_equivalent_angle(yaw, view_yaw);
+                    let error = (yaw - view_yaw).abs().max((pitch - view_pitch).abs());
+                    (view_yaw, error)
+                }
+            };
+            (error <= threshold).then_some((view, view_yaw, view_pitch, error))
+        })
+        .min_by(|(.., a), (.., b)| a.total_cmp(b))
+        .map(|(view, view_yaw, view_pitch, _)| (view, view_yaw, view_pitch))
+}
+
+/// Where `item` ranks in `priority`, lowest first. Items that aren't listed rank after all of
+/// the listed ones, in the order they appear in `all`.
+pub fn priority_rank<T: PartialEq>(priority: &[T], all: &[T], item: T) -> usize {
+    match priority.iter().position(|listed| *listed == item) {
+        Some(rank) => rank,
+        None => {
+            priority.len()
+                + all
+                    .iter()
+                    .position(|other| *other == item)
+                    .unwrap_or(all.len())
+        }
+    }
+}
+
 pub fn approx_equal(a: f32, b: f32) -> bool {
     (a - b).abs() < EPSILON
 }
@@ -57,6 +472,7 @@ pub fn approx_equal_f64(a: f64, b: f64) -> bool {
     (a - b).abs() < EPSILON as f64
 }
 
+#[allow(dead_code)]
 pub fn lerp_and_snap_f32(from: f32, to: f32, smoothness: f32, dt: f32) -> f32 {
     let t = smoothness.powi(7);
     let mut new_value = from.lerp(to, 1.0 - t.powf(dt));
@@ -75,6 +491,7 @@ pub fn lerp_and_snap_f64(from: f64, to: f64, smoothness: f64, dt: f64) -> f64 {
     new_value
 }
 
+#[allow(dead_code)]
 pub fn lerp_and_snap_vec3(from: Vec3, to: Vec3, smoothness: f32, dt: f32) -> Vec3 {
     let t = smoothness.powi(7);
     let mut new_value = from.lerp(to, 1.0 - t.powf(dt));
@@ -93,12 +510,74 @@ pub fn lerp_and_snap_dvec3(from: DVec3, to: DVec3, smoothness: f64, dt: f64) ->
     new_value
 }
 
+/// Move `from` towards `to` on a critically damped spring, updating `velocity`. The spring is as
+/// stiff as `lerp_and_snap_f64` is fast for the same smoothness. If `from` is already at `to`, e.g.
+/// because the camera was moved there directly, the spring comes to rest rather than overshooting.
+pub fn spring_and_snap_f64(
+    from: f64,
+    to: f64,
+    velocity: &mut f64,
+    smoothness: f64,
+    dt: f64,
+) -> f64 {
+    if smoothness <= 0.0 || from == to {
+        *velocity = 0.0;
+        return to;
+    }
+    let (offset, new_velocity) = spring_step(from - to, *velocity, smoothness, dt);
+    *velocity = new_velocity;
+    if smoothness < 1.0 && approx_equal_f64(offset, 0.0) && approx_equal_f64(new_velocity, 0.0) {
+        *velocity = 0.0;
+        return to;
+    }
+    to + offset
+}
+
+/// Move `from` towards `to` on a critically damped spring, updating `velocity`
+pub fn spring_and_snap_dvec3(
+    from: DVec3,
+    to: DVec3,
+    velocity: &mut DVec3,
+    smoothness: f64,
+    dt: f64,
+) -> DVec3 {
+    if smoothness <= 0.0 || from == to {
+        *velocity = DVec3::ZERO;
+        return to;
+    }
+    let (offset, new_velocity) = spring_step(from - to, *velocity, smoothness, dt);
+    *velocity = new_velocity;
+    if smoothness < 1.0
+        && approx_equal_f64(offset.length(), 0.0)
+        && approx_equal_f64(new_velocity.length(), 0.0)
+    {
+        *velocity = DVec3::ZERO;
+        return to;
+    }
+    to + offset
+}
+
+/// Advance a critically damped spring with the given offset from its rest position and velocity by
+/// `dt` seconds, returning the new offset and velocity. This is the exact solution, so it's stable
+/// at any frame rate.
+fn spring_step<T>(offset: T, velocity: T, smoothness: f64, dt: f64) -> (T, T)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>,
+{
+    // The same rate of decay as the exponential curve, i.e. `smoothness.powi(7).powf(dt)`
+    let omega = -7.0 * smoothness.ln();
+    let decay = (-omega * dt).exp();
+    let temp = (velocity + offset * omega) * dt;
+    let new_velocity = (velocity - temp * omega) * decay;
+    let new_offset = (offset + temp) * decay;
+    (new_offset, new_velocity)
+}
+
 #[cfg(test)]
 mod calculate_from_translation_and_focus_tests {
     use super::*;
     use core::f64;
     use float_cmp::approx_eq;
-    use std::f32::consts::PI;
     const AXIS: [DVec3; 3] = [DVec3::X, DVec3::Y, DVec3::Z];
     const AXIS_Z_UP: [DVec3; 3] = [DVec3::X, DVec3::Z, DVec3::Y];
 
@@ -196,6 +675,756 @@ mod calculate_from_translation_and_focus_tests {
     }
 }
 
+#[cfg(test)]
+mod update_orbit_transform_tests {
+    use super::*;
+
+    #[test]
+    fn translation_is_relative_to_world_offset() {
+        let focus = DVec3::new(1.0e9, 0.0, 1.0e9);
+        let offset = DVec3::new(1.0e9, 0.0, 1.0e9);
+        let mut transform = Transform::IDENTITY;
+        let mut position = DVec3::ZERO;
+        let mut projection = Projection::Perspective(PerspectiveProjection::default());
+        let axis = [DVec3::X, DVec3::Y, DVec3::Z];
+        update_orbit_transform(
+            0.0,
+            0.0,
+            0.0,
+            None,
+            2.5,
+            focus,
+            &mut transform,
+            &mut position,
+            &mut projection,
+            axis,
+            None,
+            offset,
+        );
+        assert_eq!(position, focus + DVec3::new(0.0, 0.0, 2.5));
+        assert_eq!(transform.translation, Vec3::new(0.0, 0.0, 2.5));
+    }
+}
+
+#[cfg(test)]
+mod ray_plane_intersection_tests {
+    use super::*;
+
+    #[test]
+    fn hits_plane_in_front() {
+        let hit = ray_plane_intersection(
+            DVec3::new(0.0, 0.0, 5.0),
+            DVec3::NEG_Z,
+            DVec3::ZERO,
+            DVec3::Z,
+        );
+        assert_eq!(hit, Some(DVec3::ZERO));
+    }
+
+    #[test]
+    fn hits_plane_at_an_angle() {
+        let direction = DVec3::new(1.0, 0.0, -1.0).normalize();
+        let hit =
+            ray_plane_intersection(DVec3::new(0.0, 0.0, 5.0), direction, DVec3::ZERO, DVec3::Z)
+                .unwrap();
+        assert!(approx_equal_f64(hit.x, 5.0));
+        assert!(approx_equal_f64(hit.z, 0.0));
+    }
+
+    #[test]
+    fn parallel_ray_misses() {
+        let hit =
+            ray_plane_intersection(DVec3::new(0.0, 0.0, 5.0), DVec3::X, DVec3::ZERO, DVec3::Z);
+        assert_eq!(hit, None);
+    }
+
+    #[test]
+    fn plane_behind_ray_misses() {
+        let hit =
+            ray_plane_intersection(DVec3::new(0.0, 0.0, 5.0), DVec3::Z, DVec3::ZERO, DVec3::Z);
+        assert_eq!(hit, None);
+    }
+}
+
+#[cfg(test)]
+mod nearest_equivalent_angle_tests {
+    use super::*;
+
+    #[test]
+    fn unchanged_when_already_nearest() {
+        assert!(approx_equal_f64(nearest_equivalent_angle(0.5, 1.0), 1.0));
+    }
+
+    #[test]
+    fn unwinds_multiple_turns() {
+        let out = nearest_equivalent_angle(3.0 * TAU + 0.1, 0.0);
+        assert!(approx_equal_f64(out, 3.0 * TAU));
+    }
+
+    #[test]
+    fn handles_negative_turns() {
+        let out = nearest_equivalent_angle(-2.0 * TAU - 0.1, 0.2);
+        assert!(approx_equal_f64(out, -2.0 * TAU + 0.2));
+    }
+}
+
+#[cfg(test)]
+mod nearest_axis_view_tests {
+    use super::*;
+
+    #[test]
+    fn finds_side_view_within_threshold() {
+        let out = nearest_axis_view(TAU / 4.0 + 0.02, -0.03, 0.05);
+        assert_eq!(out, Some((StandardView::Right, TAU / 4.0, 0.0)));
+    }
+
+    #[test]
+    fn none_outside_threshold() {
+        assert_eq!(nearest_axis_view(0.1, 0.0, 0.05), None);
+        assert_eq!(nearest_axis_view(0.0, 0.1, 0.05), None);
+    }
+
+    #[test]
+    fn wraps_to_nearest_turn() {
+        let out = nearest_axis_view(TAU + 0.01, 0.0, 0.05);
+        assert_eq!(out, Some((StandardView::Front, TAU, 0.0)));
+    }
+
+    #[test]
+    fn top_view_squares_yaw_to_quarter_turn() {
+        let out = nearest_axis_view(1.7, TAU / 4.0 - 0.01, 0.05);
+        assert_eq!(out, Some((StandardView::Top, TAU / 4.0, TAU / 4.0)));
+    }
+
+    #[test]
+    fn ignores_upside_down_views() {
+        // Pitched over the top to look at the back upside down, which isn't a standard view
+        assert_eq!(nearest_axis_view(0.0, TAU / 2.0, 0.05), None);
+    }
+}
+
+#[cfg(test)]
+mod priority_rank_tests {
+    use super::*;
+
+    const ALL: [char; 3] = ['a', 'b', 'c'];
+
+    #[test]
+    fn listed_items_rank_in_order() {
+        assert_eq!(priority_rank(&['c', 'a', 'b'], &ALL, 'c'), 0);
+        assert_eq!(priority_rank(&['c', 'a', 'b'], &ALL, 'b'), 2);
+    }
+
+    #[test]
+    fn unlisted_items_rank_last_in_default_order() {
+        assert_eq!(priority_rank(&['c'], &ALL, 'a'), 1);
+        assert_eq!(priority_rank(&['c'], &ALL, 'b'), 2);
+    }
+
+    #[test]
+    fn empty_priority_is_default_order() {
+        assert_eq!(priority_rank(&[], &ALL, 'b'), 1);
+    }
+}
+
+#[cfg(test)]
+mod motion_progress_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn trapezoid_reaches_top_speed() {
+        // 0.5s to reach speed 1.0, covering 0.25 each way, and 0.5s at top speed
+        assert!(approx_eq!(f64, motion_duration(1.0, 2.0, false), 1.5));
+        assert!(approx_eq!(f64, motion_progress(0.5, 1.0, 2.0, false), 0.25));
+        assert!(approx_eq!(f64, motion_progress(0.75, 1.0, 2.0, false), 0.5));
+        assert!(approx_eq!(
+            f64,
+            motion_progress(1.25, 1.0, 2.0, false),
+            0.9375
+        ));
+    }
+
+    #[test]
+    fn short_move_turns_round_early() {
+        // Speed is limited to sqrt(acceleration), so half the distance is covered speeding up
+        assert!(approx_eq!(f64, motion_duration(10.0, 4.0, false), 1.0));
+        assert!(approx_eq!(f64, motion_progress(0.5, 10.0, 4.0, false), 0.5));
+    }
+
+    #[test]
+    fn s_curve_is_smooth_and_symmetric() {
+        let duration = motion_duration(1.0, 2.0, true);
+        assert!(approx_eq!(f64, duration, 2.0));
+        let mut last = 0.0;
+        for i in 0..=100 {
+            let time = duration * i as f64 / 100.0;
+            let progress = motion_progress(time, 1.0, 2.0, true);
+            let mirrored = motion_progress(duration - time, 1.0, 2.0, true);
+            assert!(progress >= last);
+            assert!(approx_eq!(f64, progress + mirrored, 1.0, epsilon = 1e-9));
+            last = progress;
+        }
+        // Starts more gently than the trapezoid
+        assert!(motion_progress(0.1, 1.0, 2.0, true) < motion_progress(0.1, 1.0, 2.0, false));
+    }
+
+    #[test]
+    fn no_distance_finishes_at_once() {
+        assert_eq!(motion_duration(f64::INFINITY, f64::INFINITY, true), 0.0);
+        assert_eq!(
+            motion_progress(0.0, f64::INFINITY, f64::INFINITY, true),
+            1.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod apply_dead_zone_tests {
+    use super::*;
+
+    #[test]
+    fn input_inside_dead_zone_is_zero() {
+        assert_eq!(apply_dead_zone(Vec2::new(0.1, 0.05), 0.2), Vec2::ZERO);
+    }
+
+    #[test]
+    fn full_deflection_is_unchanged() {
+        assert_eq!(apply_dead_zone(Vec2::X, 0.2), Vec2::X);
+    }
+
+    #[test]
+    fn input_is_rescaled_from_edge_of_dead_zone() {
+        let out = apply_dead_zone(Vec2::new(0.0, 0.6), 0.2);
+        assert!(approx_equal(out.x, 0.0));
+        assert!(approx_equal(out.y, 0.5));
+    }
+
+    #[test]
+    fn zero_dead_zone_is_passthrough() {
+        assert_eq!(
+            apply_dead_zone(Vec2::new(0.3, 0.4), 0.0),
+            Vec2::new(0.3, 0.4)
+        );
+    }
+}
+
+#[cfg(test)]
+mod fit_aspect_ratio_tests {
+    use super::*;
+
+    #[test]
+    fn wide_rect_gets_bars_at_the_sides() {
+        let rect = fit_aspect_ratio(Rect::new(0.0, 0.0, 400.0, 100.0), 2.0);
+        assert_eq!(rect, Rect::new(100.0, 0.0, 300.0, 100.0));
+    }
+
+    #[test]
+    fn tall_rect_gets_bars_at_top_and_bottom() {
+        let rect = fit_aspect_ratio(Rect::new(10.0, 20.0, 110.0, 220.0), 2.0);
+        assert_eq!(rect, Rect::new(10.0, 95.0, 110.0, 145.0));
+    }
+
+    #[test]
+    fn matching_aspect_ratio_is_unchanged() {
+        let rect = Rect::new(0.0, 0.0, 160.0, 90.0);
+        assert_eq!(fit_aspect_ratio(rect, 16.0 / 9.0), rect);
+    }
+
+    #[test]
+    fn invalid_aspect_ratio_is_unchanged() {
+        let rect = Rect::new(0.0, 0.0, 160.0, 90.0);
+        assert_eq!(fit_aspect_ratio(rect, 0.0), rect);
+        assert_eq!(fit_aspect_ratio(rect, f32::NAN), rect);
+    }
+}
+
+#[cfg(test)]
+mod parse_curve_points_tests {
+    use super::*;
+
+    #[test]
+    fn parses_pairs_per_line() {
+        assert_eq!(
+            parse_curve_points("0.0 0.5\n1 2.0\n"),
+            Ok(vec![(0.0, 0.5), (1.0, 2.0)])
+        );
+    }
+
+    #[test]
+    fn skips_blank_lines_and_comments() {
+        assert_eq!(
+            parse_curve_points("# header\n\n  0 1  # start\n\t2\t3"),
+            Ok(vec![(0.0, 1.0), (2.0, 3.0)])
+        );
+    }
+
+    #[test]
+    fn reports_the_bad_line() {
+        assert_eq!(parse_curve_points("0 1\n2\n3 4"), Err(2));
+        assert_eq!(parse_curve_points("0 1\n2 3 4"), Err(2));
+        assert_eq!(parse_curve_points("zero one"), Err(1));
+    }
+}
+
+#[cfg(test)]
+mod orbit_rotation_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    const AXIS: [DVec3; 3] = [DVec3::X, DVec3::Y, DVec3::Z];
+    const AXIS_Z_UP: [DVec3; 3] = [DVec3::X, DVec3::Z, DVec3::Y];
+    const AXIS_MIRRORED: [DVec3; 3] = [DVec3::NEG_X, DVec3::Y, DVec3::Z];
+
+    fn assert_vec_eq(a: DVec3, b: DVec3) {
+        assert!(a.abs_diff_eq(b, 1e-9), "{a} != {b}");
+    }
+
+    #[test]
+    fn matches_yaw_then_pitch_rotation() {
+        let (yaw, pitch) = (0.7, -0.3);
+        let expected =
+            DQuat::from_axis_angle(DVec3::Y, yaw) * DQuat::from_axis_angle(DVec3::X, -pitch);
+        assert!(orbit_rotation(yaw, pitch, AXIS).abs_diff_eq(expected, 1e-9));
+    }
+
+    #[test]
+    fn looks_at_focus() {
+        for axis in [AXIS, AXIS_Z_UP, AXIS_MIRRORED] {
+            let rotation = orbit_rotation(0.4, 0.9, axis);
+            assert_vec_eq(rotation * DVec3::Z, orbit_offset(0.4, 0.9, axis));
+        }
+    }
+
+    #[test]
+    fn up_is_up_axis() {
+        for axis in [AXIS, AXIS_Z_UP, AXIS_MIRRORED] {
+            assert_vec_eq(orbit_rotation(1.2, 0.0, axis) * DVec3::Y, axis[1]);
+        }
+    }
+
+    #[test]
+    fn mirrored_right_is_flipped() {
+        assert!(is_left_handed(AXIS_MIRRORED));
+        assert!(is_left_handed(AXIS_Z_UP));
+        assert!(!is_left_handed(AXIS));
+        assert_vec_eq(orbit_rotation(0.0, 0.0, AXIS_MIRRORED) * DVec3::X, DVec3::X);
+    }
+
+    #[test]
+    fn inverse_of_calculate_from_translation_and_focus() {
+        for axis in [AXIS, AXIS_Z_UP, AXIS_MIRRORED] {
+            let translation = orbit_offset(-2.1, 0.6, axis) * 3.0;
+            let (yaw, pitch, radius) =
+                calculate_from_translation_and_focus(translation, DVec3::ZERO, axis);
+            assert!(approx_eq!(f64, yaw, -2.1, epsilon = 1e-9));
+            assert!(approx_eq!(f64, pitch, 0.6, epsilon = 1e-9));
+            assert!(approx_eq!(f64, radius, 3.0, epsilon = 1e-9));
+        }
+    }
+}
+
+#[cfg(test)]
+mod rotate_axis_yaw_tests {
+    use super::*;
+
+    const AXIS: [DVec3; 3] = [DVec3::X, DVec3::Y, DVec3::Z];
+    const AXIS_Z_UP: [DVec3; 3] = [DVec3::X, DVec3::Z, DVec3::Y];
+
+    #[test]
+    fn adds_to_yaw() {
+        for axis in [AXIS, AXIS_Z_UP] {
+            let rotated = rotate_axis_yaw(axis, 0.5);
+            let offset = orbit_offset(1.1, 0.3, rotated);
+            assert!(offset.abs_diff_eq(orbit_offset(1.6, 0.3, axis), 1e-9));
+            assert_eq!(is_left_handed(rotated), is_left_handed(axis));
+        }
+    }
+
+    #[test]
+    fn heading_is_clockwise_from_north() {
+        // At zero yaw the camera looks along -Z, which is north
+        let heading = compass_heading(orbit_rotation(0.0, 0.0, AXIS), AXIS);
+        assert!(heading.abs() < 1e-9);
+        // Yaw moves the camera towards east, so it looks west
+        let heading = compass_heading(orbit_rotation(TAU / 4.0, -0.5, AXIS), AXIS);
+        assert!((heading - TAU * 3.0 / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn heading_looking_down_uses_screen_up() {
+        let heading = compass_heading(orbit_rotation(0.0, TAU / 4.0, AXIS), AXIS);
+        assert!(heading.abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod yaw_pitch_from_rotation_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use std::f64::consts::PI;
+
+    const AXIS: [DVec3; 3] = [DVec3::X, DVec3::Y, DVec3::Z];
+    const AXIS_Z_UP: [DVec3; 3] = [DVec3::X, DVec3::Z, DVec3::Y];
+    const AXIS_MIRRORED: [DVec3; 3] = [DVec3::NEG_X, DVec3::Y, DVec3::Z];
+
+    #[test]
+    fn inverse_of_orbit_rotation() {
+        for axis in [AXIS, AXIS_Z_UP, AXIS_MIRRORED] {
+            let (yaw, pitch) = yaw_pitch_from_rotation(orbit_rotation(-2.1, 0.6, axis), axis);
+            assert!(approx_eq!(f64, yaw, -2.1, epsilon = 1e-9));
+            assert!(approx_eq!(f64, pitch, 0.6, epsilon = 1e-9));
+        }
+    }
+
+    #[test]
+    fn ignores_roll() {
+        let rotation = orbit_rotation(0.5, -0.4, AXIS) * DQuat::from_rotation_z(1.0);
+        let (yaw, pitch) = yaw_pitch_from_rotation(rotation, AXIS);
+        assert!(approx_eq!(f64, yaw, 0.5, epsilon = 1e-9));
+        assert!(approx_eq!(f64, pitch, -0.4, epsilon = 1e-9));
+    }
+
+    #[test]
+    fn upside_down() {
+        // Tumbling over the top looks back the other way, with the camera upside down
+        let (yaw, pitch) = yaw_pitch_from_rotation(orbit_rotation(0.0, 2.0, AXIS), AXIS);
+        assert!(approx_eq!(f64, yaw.abs(), PI, epsilon = 1e-9));
+        assert!(approx_eq!(f64, pitch, PI - 2.0, epsilon = 1e-9));
+    }
+}
+
+#[cfg(test)]
+mod fit_radius_tests {
+    use super::*;
+    use bevy::render::camera::ScalingMode;
+    use float_cmp::approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn perspective_fits_narrowest_fov() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: FRAC_PI_2 as f32,
+            aspect_ratio: 2.0,
+            ..default()
+        });
+        // Vertical FOV is narrower, and sin(45deg) * sqrt(2) = 1
+        let radius = fit_radius(1.0, &projection, FramingFit::Both, DVec2::ONE).unwrap();
+        assert!(approx_eq!(f64, radius, 2f64.sqrt(), epsilon = 1e-6));
+        let radius = fit_radius(1.0, &projection, FramingFit::Vertical, DVec2::ONE).unwrap();
+        assert!(approx_eq!(f64, radius, 2f64.sqrt(), epsilon = 1e-6));
+    }
+
+    #[test]
+    fn perspective_fits_horizontal_fov() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: FRAC_PI_2 as f32,
+            aspect_ratio: 2.0,
+            ..default()
+        });
+        // tan(half_fov_x) = 2, so sin(half_fov_x) = 2 / sqrt(5)
+        let radius = fit_radius(1.0, &projection, FramingFit::Horizontal, DVec2::ONE).unwrap();
+        assert!(approx_eq!(f64, radius, 5f64.sqrt() / 2.0, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn orthographic_fits_smallest_side() {
+        let mut ortho = OrthographicProjection {
+            scale: 2.0,
+            scaling_mode: ScalingMode::Fixed {
+                width: 4.0,
+                height: 2.0,
+            },
+            ..OrthographicProjection::default_3d()
+        };
+        ortho.area = Rect::new(-4.0, -2.0, 4.0, 2.0);
+        // At scale 2 the view is 4 high, so a sphere 4 across needs a scale of 2
+        let projection = Projection::Orthographic(ortho);
+        let radius = fit_radius(2.0, &projection, FramingFit::Both, DVec2::ONE).unwrap();
+        assert!(approx_eq!(f64, radius, 2.0));
+        // The view is 8 wide, so fitting the width only needs a scale of 1
+        let radius = fit_radius(2.0, &projection, FramingFit::Horizontal, DVec2::ONE).unwrap();
+        assert!(approx_eq!(f64, radius, 1.0));
+    }
+
+    #[test]
+    fn orthographic_without_area_is_none() {
+        let projection = Projection::Orthographic(OrthographicProjection {
+            area: Rect::default(),
+            ..OrthographicProjection::default_3d()
+        });
+        assert_eq!(
+            fit_radius(1.0, &projection, FramingFit::Both, DVec2::ONE),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod pan_distance_tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn perspective_is_radius() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: 1.2,
+            ..default()
+        });
+        assert_eq!(pan_distance(7.0, &projection, 0.5), 7.0);
+    }
+
+    #[test]
+    fn orthographic_matches_perspective_view() {
+        let fov = 0.9;
+        let radius = 10.0;
+        let half_height = radius * (fov as f64 / 2.0).tan();
+        // A scale that shows as much of the scene as the perspective camera does at its focus
+        let scale = half_height as f32;
+        let projection = Projection::Orthographic(OrthographicProjection {
+            scale,
+            area: Rect::new(-scale, -scale, scale, scale),
+            ..OrthographicProjection::default_3d()
+        });
+        let distance = pan_distance(scale as f64, &projection, fov);
+        assert!((distance - radius).abs() < 1e-4);
+    }
+
+    #[test]
+    fn orthographic_is_independent_of_scaling_mode() {
+        // The same view, with one world unit per pixel or per hundred pixels
+        let window_size = Projection::Orthographic(OrthographicProjection {
+            scale: 0.01,
+            area: Rect::new(-4.0, -3.0, 4.0, 3.0),
+            ..OrthographicProjection::default_3d()
+        });
+        let fixed = Projection::Orthographic(OrthographicProjection {
+            scale: 3.0,
+            area: Rect::new(-4.0, -3.0, 4.0, 3.0),
+            ..OrthographicProjection::default_3d()
+        });
+        assert!(approx_equal_f64(
+            pan_distance(0.01, &window_size, FRAC_PI_4),
+            pan_distance(3.0, &fixed, FRAC_PI_4)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod safe_region_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn no_insets_is_whole_viewport() {
+        let (extent, center) = safe_region(Vec2::new(800.0, 600.0), &FramingInsets::default());
+        assert_eq!(extent, DVec2::ONE);
+        assert_eq!(center, DVec2::ZERO);
+    }
+
+    #[test]
+    fn right_panel_shifts_centre_left() {
+        let insets = FramingInsets {
+            right: 200.0,
+            ..default()
+        };
+        let (extent, center) = safe_region(Vec2::new(800.0, 600.0), &insets);
+        assert_eq!(extent, DVec2::new(0.75, 1.0));
+        // The visible region spans x = 0 to 600, centred on 300, which is -0.25 in NDC
+        assert!(approx_eq!(f64, center.x, -0.25));
+        assert!(approx_eq!(f64, center.y, 0.0));
+    }
+
+    #[test]
+    fn insets_larger_than_viewport_leave_a_pixel() {
+        let insets = FramingInsets {
+            top: 1000.0,
+            ..default()
+        };
+        let (extent, _) = safe_region(Vec2::new(800.0, 500.0), &insets);
+        assert!(approx_eq!(f64, extent.y, 1.0 / 500.0, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn perspective_fits_narrower_region() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: FRAC_PI_2 as f32,
+            aspect_ratio: 1.0,
+            ..default()
+        });
+        // Half the height means tan(half_fov_y) = 0.5, so sin(half_fov_y) = 1 / sqrt(5)
+        let radius = fit_radius(1.0, &projection, FramingFit::Both, DVec2::new(1.0, 0.5)).unwrap();
+        assert!(approx_eq!(f64, radius, 5f64.sqrt(), epsilon = 1e-6));
+    }
+
+    #[test]
+    fn half_extents_scale_with_radius() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: FRAC_PI_2 as f32,
+            aspect_ratio: 2.0,
+            ..default()
+        });
+        let extents = view_half_extents(3.0, &projection);
+        assert!(approx_eq!(f64, extents.x, 6.0, epsilon = 1e-6));
+        assert!(approx_eq!(f64, extents.y, 3.0, epsilon = 1e-6));
+    }
+}
+
+#[cfg(test)]
+mod max_distance_in_bounds_tests {
+    use super::*;
+
+    #[test]
+    fn sphere_from_centre() {
+        let shape = FocusBoundsShape::Sphere(Sphere::new(2.0));
+        let distance = max_distance_in_bounds(DVec3::ZERO, DVec3::Z, shape);
+        assert!(approx_equal_f64(distance, 2.0));
+    }
+
+    #[test]
+    fn sphere_off_centre() {
+        let shape = FocusBoundsShape::Sphere(Sphere::new(2.0));
+        let distance = max_distance_in_bounds(DVec3::new(0.0, 0.0, 1.0), DVec3::NEG_Z, shape);
+        assert!(approx_equal_f64(distance, 3.0));
+    }
+
+    #[test]
+    fn cuboid_nearest_face() {
+        let shape = FocusBoundsShape::Cuboid(Cuboid::new(2.0, 4.0, 6.0));
+        let distance = max_distance_in_bounds(DVec3::ZERO, DVec3::X, shape);
+        assert!(approx_equal_f64(distance, 1.0));
+        let direction = DVec3::new(0.0, 1.0, 1.0).normalize();
+        let distance = max_distance_in_bounds(DVec3::ZERO, direction, shape);
+        assert!(approx_equal_f64(distance, 2.0 * 2f64.sqrt()));
+    }
+
+    #[test]
+    fn outside_is_zero() {
+        let shape = FocusBoundsShape::Cuboid(Cuboid::new(2.0, 2.0, 2.0));
+        let distance = max_distance_in_bounds(DVec3::new(3.0, 0.0, 0.0), DVec3::X, shape);
+        assert_eq!(distance, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod scale_clip_planes_tests {
+    use super::*;
+
+    #[test]
+    fn perspective_planes_scale_with_radius() {
+        let mut projection = Projection::Perspective(PerspectiveProjection::default());
+        scale_clip_planes(50.0, &mut projection, &AutoClipPlanes::default());
+        let Projection::Perspective(p) = projection else {
+            unreachable!()
+        };
+        assert!(approx_equal(p.near, 0.5));
+        assert!(approx_equal(p.far, 50_000.0));
+    }
+
+    #[test]
+    fn perspective_near_plane_is_limited() {
+        let mut projection = Projection::Perspective(PerspectiveProjection::default());
+        let clip_planes = AutoClipPlanes {
+            min_near: 0.01,
+            ..default()
+        };
+        scale_clip_planes(0.1, &mut projection, &clip_planes);
+        let Projection::Perspective(p) = projection else {
+            unreachable!()
+        };
+        assert!(approx_equal(p.near, 0.01));
+    }
+
+    #[test]
+    fn orthographic_focus_stays_in_middle_of_depth_range() {
+        let mut projection = Projection::Orthographic(OrthographicProjection {
+            area: Rect::new(-2.0, -1.0, 2.0, 1.0),
+            ..OrthographicProjection::default_3d()
+        });
+        let clip_planes = AutoClipPlanes {
+            far_ratio: 10.0,
+            ..default()
+        };
+        scale_clip_planes(1.0, &mut projection, &clip_planes);
+        assert!(approx_equal_f64(camera_distance(1.0, &projection), 20.0));
+        let Projection::Orthographic(p) = projection else {
+            unreachable!()
+        };
+        assert_eq!(p.near, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod is_upside_down_tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn upright() {
+        assert!(!is_upside_down(0.0));
+        assert!(!is_upside_down(PI * 0.49));
+        assert!(!is_upside_down(-PI * 0.49));
+        assert!(!is_upside_down(TAU + 0.1));
+    }
+
+    #[test]
+    fn over_the_top() {
+        assert!(is_upside_down(PI * 0.51));
+        assert!(is_upside_down(PI));
+        assert!(is_upside_down(-PI * 0.75));
+        assert!(is_upside_down(TAU + PI));
+    }
+}
+
+#[cfg(all(test, feature = "view_cube"))]
+mod ray_cube_face_tests {
+    use super::*;
+
+    #[test]
+    fn hits_facing_side() {
+        let face = ray_cube_face(DVec3::new(0.1, 0.2, 5.0), DVec3::NEG_Z);
+        assert_eq!(face, Some(DVec3::Z));
+        let face = ray_cube_face(DVec3::new(0.0, -5.0, 0.3), DVec3::Y);
+        assert_eq!(face, Some(DVec3::NEG_Y));
+    }
+
+    #[test]
+    fn hits_nearest_face_at_an_angle() {
+        let face = ray_cube_face(DVec3::new(5.0, 0.2, 0.0), DVec3::new(-1.0, -0.1, 0.0));
+        assert_eq!(face, Some(DVec3::X));
+    }
+
+    #[test]
+    fn misses() {
+        assert_eq!(ray_cube_face(DVec3::new(2.0, 0.0, 5.0), DVec3::NEG_Z), None);
+        // Cube is behind the ray
+        assert_eq!(ray_cube_face(DVec3::new(0.0, 0.0, 5.0), DVec3::Z), None);
+    }
+}
+
+#[cfg(test)]
+mod smoothstep_tests {
+    use super::*;
+
+    #[test]
+    fn endpoints_are_exact() {
+        assert_eq!(smoothstep(0.0), 0.0);
+        assert_eq!(smoothstep(1.0), 1.0);
+    }
+
+    #[test]
+    fn midpoint_is_half() {
+        assert_eq!(smoothstep(0.5), 0.5);
+    }
+
+    #[test]
+    fn input_is_clamped() {
+        assert_eq!(smoothstep(-1.0), 0.0);
+        assert_eq!(smoothstep(2.0), 1.0);
+    }
+}
+
 #[cfg(test)]
 mod approx_equal_tests {
     use super::*;
@@ -273,3 +1502,51 @@ mod lerp_and_snap_vec3_tests {
         assert_eq!(out, Vec3::X * 0.9991);
     }
 }
+
+#[cfg(test)]
+mod spring_and_snap_f64_tests {
+    use super::*;
+
+    #[test]
+    fn settles_on_target_without_overshooting() {
+        let mut value = 0.0;
+        let mut velocity = 0.0;
+        for _ in 0..600 {
+            value = spring_and_snap_f64(value, 1.0, &mut velocity, 0.8, 1.0 / 60.0);
+            assert!(value <= 1.0);
+        }
+        assert_eq!(value, 1.0);
+        assert_eq!(velocity, 0.0);
+    }
+
+    #[test]
+    fn starts_slower_than_exponential() {
+        let mut velocity = 0.0;
+        let spring = spring_and_snap_f64(0.0, 1.0, &mut velocity, 0.8, 1.0 / 60.0);
+        let exponential = lerp_and_snap_f64(0.0, 1.0, 0.8, 1.0 / 60.0);
+        assert!(spring > 0.0);
+        assert!(spring < exponential);
+        assert!(velocity > 0.0);
+    }
+
+    #[test]
+    fn is_independent_of_frame_rate() {
+        let mut fast = 0.0;
+        let mut fast_velocity = 0.0;
+        for _ in 0..4 {
+            fast = spring_and_snap_f64(fast, 1.0, &mut fast_velocity, 0.8, 0.025);
+        }
+        let mut slow_velocity = 0.0;
+        let slow = spring_and_snap_f64(0.0, 1.0, &mut slow_velocity, 0.8, 0.1);
+        assert!((fast - slow).abs() < 1e-9);
+        assert!((fast_velocity - slow_velocity).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_smoothness_snaps_immediately() {
+        let mut velocity = 3.0;
+        let out = spring_and_snap_f64(0.0, 1.0, &mut velocity, 0.0, 1.0 / 60.0);
+        assert_eq!(out, 1.0);
+        assert_eq!(velocity, 0.0);
+    }
+}
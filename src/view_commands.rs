@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+
+use crate::{PanOrbitCamera, ProjectionMode, StandardView};
+
+/// A command that changes a `PanOrbitCamera`'s view, e.g. from a toolbar button or a slice of a
+/// radial menu. `PanOrbitCamera::view_menu` lists the commands that apply to a camera, and they
+/// can be run with `ViewCommand::apply` or by sending `ApplyViewCommand`.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewCommand {
+    /// Rotate to a standard view, see `PanOrbitCamera::set_view`.
+    StandardView(StandardView),
+    /// Go back to the home view, see `PanOrbitCamera::reset_to_home`.
+    Home,
+    /// Go to the saved view with this name, see `PanOrbitCamera::recall_view`.
+    RecallView(String),
+    /// Switch between perspective and orthographic projections, see
+    /// `PanOrbitCamera::set_projection_mode`.
+    ToggleProjection,
+}
+
+impl ViewCommand {
+    /// Run the command on `pan_orbit`, transitioning smoothly to the new view. `projection` is the
+    /// camera's current projection, which decides which way `ToggleProjection` switches.
+    pub fn apply(&self, pan_orbit: &mut PanOrbitCamera, projection: &Projection) {
+        match self {
+            ViewCommand::StandardView(view) => pan_orbit.set_view(*view, true),
+            ViewCommand::Home => pan_orbit.reset_to_home(true),
+            ViewCommand::RecallView(name) => {
+                pan_orbit.recall_view(name);
+            }
+            ViewCommand::ToggleProjection => {
+                let mode = match projection_mode(pan_orbit, projection) {
+                    ProjectionMode::Perspective => ProjectionMode::Orthographic,
+                    ProjectionMode::Orthographic => ProjectionMode::Perspective,
+                };
+                pan_orbit.set_projection_mode(mode);
+            }
+        }
+    }
+}
+
+/// Which icon to show for a `ViewMenuItem`. This is only a hint, so UIs can use their own icons.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewIcon {
+    /// A standard view, e.g. a cube with the matching face highlighted.
+    StandardView(StandardView),
+    /// The home view, e.g. a house.
+    Home,
+    /// A saved view, e.g. a bookmark.
+    Bookmark,
+    /// Switching to a perspective projection.
+    Perspective,
+    /// Switching to an orthographic projection.
+    Orthographic,
+}
+
+/// An entry in a menu of view commands, with everything needed to show it. See
+/// `PanOrbitCamera::view_menu`.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewMenuItem {
+    /// A short, human readable name for the command.
+    pub label: String,
+    /// Which icon to show.
+    pub icon: ViewIcon,
+    /// The command to run when the item is picked.
+    pub command: ViewCommand,
+}
+
+impl PanOrbitCamera {
+    /// The view commands that apply to this camera, for building menus and toolbars without
+    /// knowing about each of them. The order is stable: the standard views, then the home view if
+    /// there is one, the saved views by name, and finally switching projection. `projection` is
+    /// the camera's current projection, which decides the label and icon of the projection item.
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::{ApplyViewCommand, PanOrbitCamera};
+    /// // Pick the first command with the number keys, as a stand-in for a real menu
+    /// fn view_hotkeys(
+    ///     key_input: Res<ButtonInput<KeyCode>>,
+    ///     cameras: Query<(Entity, &PanOrbitCamera, &Projection)>,
+    ///     mut view_commands: EventWriter<ApplyViewCommand>,
+    /// ) {
+    ///     let Ok((camera, pan_orbit, projection)) = cameras.get_single() else {
+    ///         return;
+    ///     };
+    ///     let menu = pan_orbit.view_menu(projection);
+    ///     if let (true, Some(item)) = (key_input.just_pressed(KeyCode::Digit1), menu.first()) {
+    ///         view_commands.send(ApplyViewCommand {
+    ///             camera,
+    ///             command: item.command.clone(),
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn view_menu(&self, projection: &Projection) -> Vec<ViewMenuItem> {
+        let mut items: Vec<ViewMenuItem> = StandardView::ALL
+            .into_iter()
+            .map(|view| ViewMenuItem {
+                label: view.name().to_string(),
+                icon: ViewIcon::StandardView(view),
+                command: ViewCommand::StandardView(view),
+            })
+            .collect();
+        if self.home_view.is_some() {
+            items.push(ViewMenuItem {
+                label: "Home".to_string(),
+                icon: ViewIcon::Home,
+                command: ViewCommand::Home,
+            });
+        }
+        let mut names: Vec<&String> = self.saved_views.views.keys().collect();
+        names.sort();
+        items.extend(names.into_iter().map(|name| ViewMenuItem {
+            label: name.clone(),
+            icon: ViewIcon::Bookmark,
+            command: ViewCommand::RecallView(name.clone()),
+        }));
+        let (label, icon) = match projection_mode(self, projection) {
+            ProjectionMode::Perspective => ("Orthographic", ViewIcon::Orthographic),
+            ProjectionMode::Orthographic => ("Perspective", ViewIcon::Perspective),
+        };
+        items.push(ViewMenuItem {
+            label: label.to_string(),
+            icon,
+            command: ViewCommand::ToggleProjection,
+        });
+        items
+    }
+}
+
+/// The projection the camera has, or is switching to
+fn projection_mode(pan_orbit: &PanOrbitCamera, projection: &Projection) -> ProjectionMode {
+    match (pan_orbit.projection_switch, projection) {
+        (Some(switch), _) => switch.to,
+        (None, Projection::Orthographic(_)) => ProjectionMode::Orthographic,
+        (None, _) => ProjectionMode::Perspective,
+    }
+}
+
+/// Run a `ViewCommand` on a camera, e.g. when a menu item is picked, without needing access to
+/// the camera in the UI code. Commands are ignored while the camera doesn't accept input, e.g.
+/// when it's disabled.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct ApplyViewCommand {
+    /// The camera to run the command on.
+    pub camera: Entity,
+    /// The command to run.
+    pub command: ViewCommand,
+}
+
+/// Run the `ApplyViewCommand`s sent since the last update
+pub fn apply_view_commands(
+    mut events: EventReader<ApplyViewCommand>,
+    mut cameras: Query<(&mut PanOrbitCamera, &Projection)>,
+) {
+    for event in events.read() {
+        let Ok((mut pan_orbit, projection)) = cameras.get_mut(event.camera) else {
+            continue;
+        };
+        if pan_orbit.accepts_input() {
+            event.command.apply(&mut pan_orbit, projection);
+        }
+    }
+}
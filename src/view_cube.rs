@@ -0,0 +1,293 @@
+use std::f64::consts::PI;
+
+use bevy::math::{DQuat, DVec2, DVec3};
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+
+use crate::input::viewport_cursor_position;
+use crate::{util, ActiveCameraData, PanOrbitCamera, StandardView};
+
+/// An orientation cube drawn in the top right corner of a `PanOrbitCamera`'s viewport, which
+/// rotates along with the camera. Clicking a face of the cube snaps the camera to look at that
+/// face, and dragging the cube orbits the camera. Both follow the camera's `orbit_enabled`,
+/// `lock_yaw`, and `lock_pitch`, and clicking a face only works while the camera can orbit freely.
+/// Requires the `view_cube` feature.
+///
+/// Spawn this on its own entity, pointing at the camera it should control. The entity becomes a
+/// camera that draws the cube on top of the target camera:
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, ViewCube};
+/// fn setup(mut commands: Commands) {
+///     let camera = commands.spawn(PanOrbitCamera::default()).id();
+///     commands.spawn(ViewCube::new(camera));
+/// }
+/// ```
+///
+/// The cube is drawn with `ViewCubeGizmoConfigGroup` on render layer `VIEW_CUBE_LAYER`, so
+/// avoid putting anything else on that layer. Clicks on the cube are only kept from reaching the
+/// camera beneath it when `ActiveCameraData` is managed by the plugin.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct ViewCube {
+    /// The `PanOrbitCamera` entity that the cube follows and controls.
+    pub target: Entity,
+    /// The width and height of the cube's viewport, in logical pixels.
+    /// Defaults to `120.0`.
+    pub size: f32,
+    /// The gap between the cube's viewport and the corner of the target's viewport, in logical
+    /// pixels.
+    /// Defaults to `10.0`.
+    pub margin: f32,
+    /// The outward normal of the face under the cursor, if any.
+    hovered_face: Option<DVec3>,
+    /// Where the current drag started, in the cube's viewport.
+    drag_start: Option<Vec2>,
+    /// The cursor position last frame during a drag.
+    last_cursor: Option<Vec2>,
+    /// Whether the current drag has moved far enough to orbit rather than click.
+    dragging: bool,
+}
+
+impl ViewCube {
+    /// Create a view cube for the `PanOrbitCamera` on `target`.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            size: 120.0,
+            margin: 10.0,
+            hovered_face: None,
+            drag_start: None,
+            last_cursor: None,
+            dragging: false,
+        }
+    }
+}
+
+/// The gizmo config group used to draw `ViewCube`s.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct ViewCubeGizmoConfigGroup;
+
+/// The render layer that `ViewCube`s are drawn on.
+pub const VIEW_CUBE_LAYER: usize = 31;
+
+/// Render order of view cube cameras, so they draw on top of the scene
+const VIEW_CUBE_ORDER: isize = 100;
+
+/// How far the cursor must move after pressing before it counts as a drag instead of a click
+const DRAG_THRESHOLD: f32 = 3.0;
+
+const EDGE_COLOR: Color = Color::WHITE;
+const HOVER_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+const AXIS_COLORS: [Color; 3] = [
+    Color::srgb(0.9, 0.25, 0.25),
+    Color::srgb(0.25, 0.8, 0.25),
+    Color::srgb(0.3, 0.45, 0.95),
+];
+
+/// Turn newly added view cubes into cameras that only see the cube
+pub fn setup_view_cube(mut commands: Commands, cubes: Query<Entity, Added<ViewCube>>) {
+    for entity in cubes.iter() {
+        commands.entity(entity).insert((
+            Camera3d::default(),
+            Camera {
+                order: VIEW_CUBE_ORDER,
+                clear_color: ClearColorConfig::None,
+                ..default()
+            },
+            Projection::Orthographic(OrthographicProjection {
+                // Fits the cube from any angle, since its diagonal is about 1.73
+                scaling_mode: ScalingMode::Fixed {
+                    width: 2.0,
+                    height: 2.0,
+                },
+                ..OrthographicProjection::default_3d()
+            }),
+            RenderLayers::layer(VIEW_CUBE_LAYER),
+        ));
+    }
+}
+
+/// Hover, click, and drag the view cubes. Clicks on a cube stop the camera underneath it from
+/// receiving input.
+pub fn view_cube_input(
+    mut active_cam: ResMut<ActiveCameraData>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    mut cubes: Query<(&mut ViewCube, &Camera, &GlobalTransform)>,
+    mut orbit_cameras: Query<&mut PanOrbitCamera>,
+) {
+    for (mut cube, camera, camera_transform) in cubes.iter_mut() {
+        let Ok(mut pan_orbit) = orbit_cameras.get_mut(cube.target) else {
+            continue;
+        };
+        let cursor = viewport_cursor_position(camera, None, &primary_windows, &other_windows)
+            .filter(|cursor| cursor.cmpge(Vec2::ZERO).all())
+            .filter(|cursor| cursor.cmplt(Vec2::splat(cube.size)).all());
+        cube.hovered_face = cursor
+            .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor).ok())
+            .and_then(|ray| util::ray_cube_face(ray.origin.as_dvec3(), ray.direction.as_dvec3()));
+
+        if mouse_input.just_pressed(MouseButton::Left) && cube.hovered_face.is_some() {
+            cube.drag_start = cursor;
+            cube.last_cursor = cursor;
+            cube.dragging = false;
+        }
+        let Some(drag_start) = cube.drag_start else {
+            continue;
+        };
+
+        // Keep the press from also moving the camera beneath the cube
+        if !active_cam.manual {
+            active_cam.entity = None;
+        }
+
+        if mouse_input.just_released(MouseButton::Left) {
+            // Clicking a face turns the camera both ways, so it needs orbiting to be fully allowed
+            if !cube.dragging && pan_orbit.accepts_input() && pan_orbit.orbit_mask() == DVec2::ONE {
+                if let Some(view) = cube
+                    .hovered_face
+                    .and_then(|normal| face_view(normal, pan_orbit.axis))
+                {
+                    pan_orbit.set_view(view, true);
+                }
+            }
+            cube.drag_start = None;
+            cube.last_cursor = None;
+            continue;
+        }
+
+        let (Some(cursor), Some(last_cursor)) = (cursor, cube.last_cursor) else {
+            continue;
+        };
+        if cursor.distance(drag_start) > DRAG_THRESHOLD {
+            cube.dragging = true;
+        }
+        if cube.dragging && pan_orbit.accepts_input() {
+            // Dragging across the whole cube turns it half way round
+            let delta =
+                (cursor - last_cursor).as_dvec2() / cube.size as f64 * PI * pan_orbit.orbit_mask();
+            let yaw_direction =
+                match pan_orbit.is_upside_down != util::is_left_handed(pan_orbit.axis) {
+                    true => -1.0,
+                    false => 1.0,
+                };
+            pan_orbit.target_yaw -= delta.x * yaw_direction;
+            pan_orbit.target_pitch += delta.y;
+        }
+        cube.last_cursor = Some(cursor);
+    }
+}
+
+/// The standard view that looks at the face with the given outward normal, if the face lines up
+/// with one of the camera's axes
+fn face_view(normal: DVec3, axis: [DVec3; 3]) -> Option<StandardView> {
+    let [x, y, z] = axis;
+    let views = [
+        (x, StandardView::Right, StandardView::Left),
+        (y, StandardView::Top, StandardView::Bottom),
+        (z, StandardView::Front, StandardView::Back),
+    ];
+    views.into_iter().find_map(|(axis, positive, negative)| {
+        let dot = normal.dot(axis.normalize());
+        if dot > 0.5 {
+            Some(positive)
+        } else if dot < -0.5 {
+            Some(negative)
+        } else {
+            None
+        }
+    })
+}
+
+/// Move each view cube's viewport into the corner of its target, turn it to match the target's
+/// orientation, and draw the cube
+pub fn update_view_cube(
+    mut cubes: Query<(&ViewCube, &mut Camera, &mut Transform), Without<PanOrbitCamera>>,
+    targets: Query<(&Camera, &PanOrbitCamera), Without<ViewCube>>,
+    mut gizmos: Gizmos<ViewCubeGizmoConfigGroup>,
+) {
+    for (cube, mut camera, mut transform) in cubes.iter_mut() {
+        let Ok((target_camera, pan_orbit)) = targets.get(cube.target) else {
+            camera.is_active = false;
+            continue;
+        };
+        let (Some(yaw), Some(pitch), Some(viewport), Some(scale_factor)) = (
+            pan_orbit.yaw,
+            pan_orbit.pitch,
+            target_camera.physical_viewport_rect(),
+            target_camera.target_scaling_factor(),
+        ) else {
+            camera.is_active = false;
+            continue;
+        };
+
+        let size = ((cube.size * scale_factor) as u32).min(viewport.width().min(viewport.height()));
+        let margin = (cube.margin * scale_factor) as u32;
+        let position = UVec2::new(
+            viewport
+                .max
+                .x
+                .saturating_sub(size + margin)
+                .max(viewport.min.x),
+            viewport.min.y + margin.min(viewport.height() - size),
+        );
+        camera.is_active = target_camera.is_active && size > 0;
+        camera.target = target_camera.target.clone();
+        camera.hdr = target_camera.hdr;
+        camera.viewport = Some(Viewport {
+            physical_position: position,
+            physical_size: UVec2::splat(size),
+            ..default()
+        });
+
+        // The cube shows the site's axes, so leave out the site rotation
+        let rotation = match pan_orbit.orientation {
+            Some(orientation) => {
+                let handedness = match util::is_left_handed(pan_orbit.axis) {
+                    true => -1.0,
+                    false => 1.0,
+                };
+                let site = DQuat::from_axis_angle(
+                    pan_orbit.axis[1].normalize(),
+                    handedness * pan_orbit.site_rotation,
+                );
+                site.inverse() * orientation
+            }
+            None => {
+                util::orbit_rotation(yaw, pitch, pan_orbit.axis)
+                    * DQuat::from_rotation_z(pan_orbit.roll)
+            }
+        };
+        transform.rotation = rotation.as_quat();
+        transform.translation = (rotation * DVec3::Z * 5.0).as_vec3();
+
+        // The cube is aligned with the world axes. Only draw the faces that point towards the
+        // camera, so hidden edges don't clutter the cube
+        let view_direction = transform.back().as_vec3();
+        for (index, axis) in [DVec3::X, DVec3::Y, DVec3::Z].into_iter().enumerate() {
+            for sign in [1.0, -1.0] {
+                let normal = axis * sign;
+                if normal.as_vec3().dot(view_direction) <= 0.0 {
+                    continue;
+                }
+                let rotation = Quat::from_rotation_arc(Vec3::Z, normal.as_vec3());
+                let center = normal.as_vec3() * 0.5;
+                let isometry = Isometry3d::new(center, rotation);
+                gizmos.rect(isometry, Vec2::ONE, EDGE_COLOR);
+                let color = if cube.hovered_face == Some(normal) {
+                    HOVER_COLOR
+                } else if sign > 0.0 {
+                    AXIS_COLORS[index]
+                } else {
+                    AXIS_COLORS[index].with_alpha(0.4)
+                };
+                gizmos.rect(isometry, Vec2::splat(0.7), color);
+            }
+        }
+    }
+}